@@ -0,0 +1,49 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use scapegoat::SgMap;
+
+// Benches -------------------------------------------------------------------------------------------------------------
+
+// Interleaved random inserts and removals at steady state, the workload most sensitive to per-node bookkeeping.
+// Compare runs with and without `--features fast_rebalance` (and/or `low_mem_insert`).
+macro_rules! bench_churn_n {
+    ($c:expr, $n:expr) => {
+        let mut rng = SmallRng::seed_from_u64(0x5ca9_e60a7);
+        let fill: Vec<u32> = (0..($n / 2)).map(|_| rng.gen_range(0, $n * 4)).collect();
+        let ops: Vec<(bool, u32)> = (0..($n * 4))
+            .map(|_| (rng.gen(), rng.gen_range(0, $n * 4)))
+            .collect();
+
+        $c.bench_function(&format!("sgm_churn_rand_{}", $n), |b| {
+            b.iter(|| {
+                let mut sgm = SgMap::<u32, u32, $n>::new();
+                for k in &fill {
+                    sgm.insert(*k, *k);
+                }
+
+                for (is_insert, k) in &ops {
+                    match is_insert {
+                        true if !sgm.is_full() => {
+                            sgm.insert(black_box(*k), *k);
+                        }
+                        _ => {
+                            sgm.remove(black_box(k));
+                        }
+                    }
+                }
+                black_box(sgm)
+            })
+        });
+    };
+}
+
+fn bench_churn(c: &mut Criterion) {
+    bench_churn_n!(c, 128);
+    bench_churn_n!(c, 1024);
+}
+
+// Runner --------------------------------------------------------------------------------------------------------------
+
+criterion_group!(benches, bench_churn);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use scapegoat::SgMap;
+
+// Fixed seed, so runs are comparable across builds.
+const SEED: u64 = 0x5ca9_e60a7;
+
+// Test Data -----------------------------------------------------------------------------------------------------------
+
+/// Distinct random keys, so every `entry` call on an initially empty map is vacant.
+fn get_test_data(size: usize) -> Vec<u32> {
+    let mut rng = SmallRng::seed_from_u64(SEED);
+    let mut keys = Vec::with_capacity(size);
+
+    while keys.len() < size {
+        let k: u32 = rng.gen();
+        if !keys.contains(&k) {
+            keys.push(k);
+        }
+    }
+
+    keys
+}
+
+// Benches -------------------------------------------------------------------------------------------------------------
+
+macro_rules! bench_entry_vacant_n {
+    ($c:expr, $n:expr) => {
+        let keys = get_test_data($n);
+
+        $c.bench_function(&format!("sgm_entry_vacant_{}", $n), |b| {
+            b.iter(|| {
+                let mut sgm = SgMap::<u32, u32, $n>::new();
+                for k in keys.iter() {
+                    *sgm.entry(black_box(*k)).or_insert(0) += 1;
+                }
+                black_box(sgm)
+            })
+        });
+
+        $c.bench_function(&format!("std_entry_vacant_{}", $n), |b| {
+            b.iter(|| {
+                let mut std = BTreeMap::<u32, u32>::new();
+                for k in keys.iter() {
+                    *std.entry(black_box(*k)).or_insert(0) += 1;
+                }
+                black_box(std)
+            })
+        });
+    };
+}
+
+fn bench_entry_vacant(c: &mut Criterion) {
+    bench_entry_vacant_n!(c, 16);
+    bench_entry_vacant_n!(c, 128);
+    bench_entry_vacant_n!(c, 1024);
+}
+
+// Runner --------------------------------------------------------------------------------------------------------------
+
+criterion_group!(benches, bench_entry_vacant);
+criterion_main!(benches);
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use scapegoat::SgMap;
+
+// Fixed seed, so runs are comparable across builds.
+const SEED: u64 = 0x5ca9_e60a7;
+
+// Test Data -----------------------------------------------------------------------------------------------------------
+
+type Pairs = Vec<(u32, u32)>;
+type Lookups = Vec<u32>;
+
+/// Integer pairs (keys in the lower half of `u32`'s range) and three sequences of lookup keys:
+/// all present (hits), all absent but within the stored key span, and all absent beyond it.
+fn get_test_data(size: usize) -> (Pairs, Lookups, Lookups, Lookups) {
+    let mut rng = SmallRng::seed_from_u64(SEED);
+    let mut pairs = Vec::with_capacity(size);
+
+    while pairs.len() < size {
+        let k: u32 = rng.gen_range(0, u32::MAX / 2);
+        if !pairs.iter().any(|(p, _)| *p == k) {
+            pairs.push((k, !k));
+        }
+    }
+
+    let lo = pairs.iter().map(|(k, _)| *k).min().unwrap();
+    let hi = pairs.iter().map(|(k, _)| *k).max().unwrap();
+
+    let hits = (0..size).map(|_| pairs[rng.gen_range(0, size)].0).collect();
+
+    let mut misses_in_span = Vec::with_capacity(size);
+    while misses_in_span.len() < size {
+        let k: u32 = rng.gen_range(lo, hi);
+        if !pairs.iter().any(|(p, _)| *p == k) {
+            misses_in_span.push(k);
+        }
+    }
+
+    let misses_out_of_span = (0..size).map(|_| rng.gen_range(hi + 1, u32::MAX)).collect();
+
+    (pairs, hits, misses_in_span, misses_out_of_span)
+}
+
+// Benches -------------------------------------------------------------------------------------------------------------
+
+macro_rules! bench_get_n {
+    ($c:expr, $n:expr) => {
+        let (pairs, hits, misses_in_span, misses_out_of_span) = get_test_data($n);
+        let sgm: SgMap<u32, u32, $n> = pairs.iter().copied().collect();
+        let std: BTreeMap<u32, u32> = pairs.iter().copied().collect();
+
+        for (suffix, lookups) in [
+            ("_hit", &hits),
+            ("_miss_in_span", &misses_in_span),
+            ("_miss_out_of_span", &misses_out_of_span),
+        ]
+        .iter()
+        {
+            $c.bench_function(&format!("sgm_get_{}{}", $n, suffix), |b| {
+                b.iter(|| {
+                    for k in lookups.iter() {
+                        black_box(sgm.get(black_box(k)));
+                    }
+                })
+            });
+
+            $c.bench_function(&format!("std_get_{}{}", $n, suffix), |b| {
+                b.iter(|| {
+                    for k in lookups.iter() {
+                        black_box(std.get(black_box(k)));
+                    }
+                })
+            });
+        }
+    };
+}
+
+fn bench_get(c: &mut Criterion) {
+    bench_get_n!(c, 16);
+    bench_get_n!(c, 128);
+    bench_get_n!(c, 1024);
+}
+
+// Runner --------------------------------------------------------------------------------------------------------------
+
+criterion_group!(benches, bench_get);
+criterion_main!(benches);
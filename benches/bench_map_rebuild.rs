@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use scapegoat::SgMap;
+
+// Benches -------------------------------------------------------------------------------------------------------------
+
+// Sorted inserts always extend the rightmost path, so they trigger rebuilds as often as possible.
+// The tighter `alpha` (0.5) rebuilds more often still, isolating per-rebuild overhead.
+macro_rules! bench_insert_sorted_n {
+    ($c:expr, $n:expr) => {
+        for (suffix, (alpha_num, alpha_denom)) in
+            [("", (2.0, 3.0)), ("_tight_alpha", (1.0, 2.0))].iter()
+        {
+            $c.bench_function(&format!("sgm_insert_sorted_{}{}", $n, suffix), |b| {
+                b.iter(|| {
+                    let mut sgm = SgMap::<u32, u32, $n>::new();
+                    sgm.set_rebal_param(*alpha_num, *alpha_denom).unwrap();
+                    for k in 0..($n as u32) {
+                        sgm.insert(black_box(k), k);
+                    }
+                    black_box(sgm)
+                })
+            });
+        }
+    };
+}
+
+fn bench_insert_sorted(c: &mut Criterion) {
+    bench_insert_sorted_n!(c, 128);
+    bench_insert_sorted_n!(c, 1024);
+}
+
+// Runner --------------------------------------------------------------------------------------------------------------
+
+criterion_group!(benches, bench_insert_sorted);
+criterion_main!(benches);
@@ -5,8 +5,8 @@
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::iter::FromIterator;
-use std::ops::Bound::Included;
-use std::ops::Range;
+use std::ops::Bound::{Excluded, Included};
+use std::ops::{ControlFlow, Range};
 
 use libfuzzer_sys::{
     arbitrary::{unstructured::Int, Arbitrary, Unstructured},
@@ -14,9 +14,9 @@ use libfuzzer_sys::{
 };
 
 use scapegoat::map_types::Entry as SgEntry;
-use scapegoat::SgMap;
+use scapegoat::{SgError, SgMap, SgSet};
 use std::collections::btree_map::Entry as BtEntry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 const CAPACITY: usize = 2048;
 
@@ -46,6 +46,8 @@ enum MapOccupiedEntry<V: Debug> {
     Key,
     Remove,
     RemoveEntry,
+    ReplaceEntry { val: V },
+    ReplaceKey,
 }
 
 // Vacant
@@ -54,6 +56,7 @@ enum MapVacantEntry<V: Debug> {
     Insert { val: V },
     IntoKey,
     Key,
+    TryInsertWith { val: V, fail: bool },
 }
 
 // Map -----------------------------------------------------------------------------------------------------------------
@@ -62,35 +65,65 @@ enum MapVacantEntry<V: Debug> {
 enum MapMethod<K: Ord + Debug, V: Debug> {
     // APIs ------------------------------------------------------------------------------------------------------------
     Append { other: Vec<(K, V)> },
+    AppendWith { other: Vec<(K, V)> },
     // capacity() returns a constant. Omitted, irrelevant coverage.
     Clear,
+    CmpBy { other: Vec<(K, V)> },
+    Coalesce,
+    CommonKeyCount { other: Vec<(K, V)> },
     ContainsKey { key: K },
+    ContainsRange { start: K, end: K },
+    Drain,
     Entry { key: K, entry: MapEntry<K, V> },
+    EqBy { other: Vec<(K, V)> },
+    FillEntries { buf_len: u8 },
     FirstEntry,
+    FirstEntryMut,
     FirstKey,
     FirstKeyValue,
     Get { key: K },
     GetKeyValue { key: K },
+    GetKeyValueMut { key: K },
     GetMut { key: K },
+    GetPairMut { key_a: K, key_b: K },
     Insert { key: K, val: V },
     IsEmpty,
     Iter,
+    IterFrom { key: K },
     IterMut,
+    IterUntil { key: K },
     Keys,
+    KeysAllInRange { start: K, end: K },
     LastEntry,
+    LastEntryMut,
     LastKey,
     LastKeyValue,
     Len,
     New,
+    Partition { rand_key: K },
     PopFirst,
     PopLast,
     Range { bitstream: Vec<u8> },
+    RangeCount { start: K, end: K },
     RangeMut { bitstream: Vec<u8> },
+    Rank { key: K },
     Remove { key: K },
     RemoveEntry { key: K },
+    RemoveIn { keys: Vec<K> },
+    RemoveKeys { keys: Vec<K> },
+    ReplaceEntryAtMax { key: K, val: V },
+    ReplaceEntryAtMin { key: K, val: V },
     Retain { rand_key: K },
+    RetainIn { keys: Vec<K> },
+    RetainRev { keep: usize },
+    RetainWhile { rand_key: K, stop: usize },
+    Select { rank: usize },
+    SplitBy { rand_key: K },
     SplitOff { key: K },
+    TryClone,
     TryInsertStd { key: K, val: V },
+    TryUpdateOrInsert { key: K, val: V },
+    UpdateOrInsert { key: K, val: V },
     Values,
     ValuesMut,
     // Trait Equivalence -----------------------------------------------------------------------------------------------
@@ -217,6 +250,27 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                     assert!(checked_get_len(&sg_map, &bt_map) >= len_old);
                 }
             }
+            MapMethod::AppendWith { other } => {
+                if other.len() > sg_map.capacity() {
+                    continue;
+                }
+
+                let mut sg_other = SgMap::from_iter(other.clone());
+                let bt_other = BTreeMap::from_iter(other);
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                if (len_old + sg_other.len()) <= sg_map.capacity() {
+                    sg_map.append_with(&mut sg_other, |_, a, b| a.wrapping_add(b));
+                    for (k, v) in bt_other {
+                        let bt_v = bt_map.entry(k).or_insert(0);
+                        *bt_v = bt_v.wrapping_add(v);
+                    }
+
+                    assert!(sg_other.is_empty());
+                    assert!(sg_map.iter().eq(bt_map.iter()));
+                    assert!(checked_get_len(&sg_map, &bt_map) >= len_old);
+                }
+            }
             MapMethod::Clear => {
                 sg_map.clear();
                 bt_map.clear();
@@ -227,9 +281,77 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                 assert_eq!(sg_map.len(), 0);
                 assert_eq!(bt_map.len(), 0);
             }
+            MapMethod::CmpBy { other } => {
+                if other.len() > sg_map.capacity() {
+                    continue;
+                }
+
+                let sg_other = SgMap::from_iter(other.clone());
+                let bt_other = BTreeMap::from_iter(other);
+
+                assert_eq!(
+                    sg_map.cmp_by(&sg_other, |a, b| a.cmp(&b)),
+                    bt_map.cmp(&bt_other)
+                );
+            }
+            MapMethod::Coalesce => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                // Merge overlapping `start -> end` intervals
+                let mut bt_merged: BTreeMap<usize, usize> = BTreeMap::new();
+                for (start, end) in bt_map.iter() {
+                    match bt_merged.iter_mut().next_back() {
+                        Some((_, last_end)) if *start <= *last_end => {
+                            *last_end = (*last_end).max(*end);
+                        }
+                        _ => {
+                            bt_merged.insert(*start, *end);
+                        }
+                    }
+                }
+                bt_map = bt_merged;
+
+                sg_map.coalesce(|_, end, next_start, next_end| {
+                    if *next_start <= *end {
+                        *end = (*end).max(*next_end);
+                        true
+                    } else {
+                        false
+                    }
+                });
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
+            }
+            MapMethod::CommonKeyCount { other } => {
+                if other.len() > sg_map.capacity() {
+                    continue;
+                }
+
+                let sg_other = SgMap::from_iter(other.clone());
+                let bt_other = BTreeMap::from_iter(other);
+
+                assert_eq!(
+                    sg_map.common_key_count(&sg_other),
+                    bt_map.keys().filter(|k| bt_other.contains_key(k)).count()
+                );
+            }
             MapMethod::ContainsKey { key } => {
                 assert_eq!(sg_map.contains_key(&key), bt_map.contains_key(&key));
             }
+            MapMethod::ContainsRange { start, end } => {
+                // No `start <= end` normalization: an inverted range must not panic
+                assert_eq!(
+                    sg_map.contains_range((Included(&start), Excluded(&end))),
+                    bt_map.keys().any(|k| (start <= *k) && (*k < end))
+                );
+            }
+            MapMethod::Drain => {
+                assert!(sg_map.drain().eq(core::mem::take(&mut bt_map).into_iter()));
+
+                assert!(sg_map.is_empty());
+                assert_eq!(sg_map.len(), 0);
+            }
             MapMethod::Entry { key, entry } => {
                 let sg_entry = sg_map.entry(key);
                 let bt_entry = bt_map.entry(key);
@@ -296,6 +418,14 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                                 MapOccupiedEntry::RemoveEntry => {
                                     assert_eq!(sgo.remove_entry(), bto.remove_entry());
                                 }
+                                MapOccupiedEntry::ReplaceEntry { val } => {
+                                    let key = *bto.key();
+                                    assert_eq!(sgo.replace_entry(val), (key, bto.insert(val)));
+                                }
+                                MapOccupiedEntry::ReplaceKey => {
+                                    let key = *bto.key();
+                                    assert_eq!(sgo.replace_key(key), key);
+                                }
                             }
                         }
                     }
@@ -312,16 +442,49 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                                 MapVacantEntry::Key => {
                                     assert_eq!(sgv.key(), btv.key());
                                 }
+                                MapVacantEntry::TryInsertWith { val, fail } => match fail {
+                                    true => assert_eq!(sgv.try_insert_with(|| Err(val)), Err(val)),
+                                    false => assert_eq!(
+                                        sgv.try_insert_with(|| Ok::<_, ()>(val)),
+                                        Ok(btv.insert(val))
+                                    ),
+                                },
                             }
                         }
                     }
                 }
             }
+            MapMethod::EqBy { other } => {
+                if other.len() > sg_map.capacity() {
+                    continue;
+                }
+
+                let sg_other = SgMap::from_iter(other.clone());
+                let bt_other = BTreeMap::from_iter(other);
+
+                assert_eq!(sg_map.eq_by(&sg_other, |a, b| a == b), bt_map == bt_other);
+            }
+            MapMethod::FillEntries { buf_len } => {
+                let mut buf = vec![(&0, &0); buf_len as usize];
+                let written = sg_map.fill_entries(&mut buf);
+
+                assert_eq!(written, sg_map.len().min(buf.len()));
+                assert!(buf[..written].iter().copied().eq(bt_map.iter().take(written)));
+                assert!(buf[written..].iter().all(|pair| *pair == (&0, &0)));
+            }
             MapMethod::FirstEntry => match (sg_map.first_entry(), bt_map.first_entry()) {
                 (Some(sgo), Some(bto)) => assert_eq!(sgo.key(), bto.key()),
                 (None, None) => continue,
                 _ => panic!("First entry Some-None mismatch!"),
             },
+            MapMethod::FirstEntryMut => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let bt_kv = bt_map.first_key_value().map(|(k, v)| (*k, *v));
+                assert_eq!(sg_map.first_entry_mut().map(|(k, v)| (*k, *v)), bt_kv);
+
+                assert_len_unchanged(&sg_map, &bt_map, len_old);
+            }
             MapMethod::FirstKey => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -357,6 +520,16 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
 
                 assert_len_unchanged(&sg_map, &bt_map, len_old);
             }
+            MapMethod::GetKeyValueMut { key } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                assert_eq!(
+                    sg_map.get_key_value_mut(&key),
+                    bt_map.get_mut(&key).map(|v| (&key, v))
+                );
+
+                assert_len_unchanged(&sg_map, &bt_map, len_old);
+            }
             MapMethod::GetMut { key } => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -364,6 +537,29 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
 
                 assert_len_unchanged(&sg_map, &bt_map, len_old);
             }
+            MapMethod::GetPairMut { key_a, key_b } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let expected = match (bt_map.get(&key_a), bt_map.get(&key_b)) {
+                    (Some(a), Some(b)) if key_a != key_b => Some((*a, *b)),
+                    _ => None,
+                };
+
+                // Swap, mirroring in the model
+                match sg_map.get_pair_mut(&key_a, &key_b) {
+                    Some((a, b)) => {
+                        assert_eq!(Some((*a, *b)), expected);
+                        std::mem::swap(a, b);
+                        let (a, b) = expected.unwrap();
+                        bt_map.insert(key_a, b);
+                        bt_map.insert(key_b, a);
+                    }
+                    None => assert!(expected.is_none()),
+                }
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert_len_unchanged(&sg_map, &bt_map, len_old);
+            }
             MapMethod::Insert { key, val } => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
                 if len_old < sg_map.capacity() {
@@ -378,17 +574,38 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
             MapMethod::Iter => {
                 assert!(sg_map.iter().eq(bt_map.iter()));
             }
+            MapMethod::IterFrom { key } => {
+                assert!(sg_map.iter_from(&key).eq(bt_map.range(&key..)));
+            }
             MapMethod::IterMut => {
                 assert!(sg_map.iter_mut().eq(bt_map.iter_mut()));
             }
+            MapMethod::IterUntil { key } => {
+                assert!(sg_map.iter_until(&key).eq(bt_map.range(..&key)));
+            }
             MapMethod::Keys => {
                 assert!(sg_map.keys().eq(bt_map.keys()));
             }
+            MapMethod::KeysAllInRange { start, end } => {
+                // No `start <= end` normalization: an inverted range must not panic
+                assert_eq!(
+                    sg_map.keys_all_in_range((Included(&start), Excluded(&end))),
+                    bt_map.keys().all(|k| (start <= *k) && (*k < end))
+                );
+            }
             MapMethod::LastEntry => match (sg_map.last_entry(), bt_map.last_entry()) {
                 (Some(sgo), Some(bto)) => assert_eq!(sgo.key(), bto.key()),
                 (None, None) => continue,
                 _ => panic!("Last entry Some-None mismatch!"),
             },
+            MapMethod::LastEntryMut => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let bt_kv = bt_map.last_key_value().map(|(k, v)| (*k, *v));
+                assert_eq!(sg_map.last_entry_mut().map(|(k, v)| (*k, *v)), bt_kv);
+
+                assert_len_unchanged(&sg_map, &bt_map, len_old);
+            }
             MapMethod::LastKey => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -417,6 +634,20 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                 sg_map = SgMap::new();
                 bt_map = BTreeMap::new();
             }
+            MapMethod::Partition { rand_key } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+                let pred = |k: &usize, v: &usize| (k ^ v ^ rand_key).count_ones() % 2 == 1;
+
+                let (sg_match, sg_rest) = sg_map.clone().partition(pred);
+                let (bt_match, bt_rest): (BTreeMap<_, _>, BTreeMap<_, _>) =
+                    bt_map.clone().into_iter().partition(|(k, v)| pred(k, v));
+
+                assert!(sg_match.iter().eq(bt_match.iter()));
+                assert!(sg_rest.iter().eq(bt_rest.iter()));
+                assert_eq!(sg_match.len() + sg_rest.len(), len_old);
+                assert_eq!(sg_match.verify(), Ok(()));
+                assert_eq!(sg_rest.verify(), Ok(()));
+            }
             MapMethod::PopFirst => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -438,6 +669,16 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                     assert!(sg_range_iter.eq(bt_range_iter));
                 }
             }
+            MapMethod::RangeCount { start, end } => {
+                let (lo, hi) = match start <= end {
+                    true => (start, end),
+                    false => (end, start),
+                };
+
+                assert_eq!(sg_map.range_count(lo..=hi), bt_map.range(lo..=hi).count());
+                assert_eq!(sg_map.range_count(lo..), bt_map.range(lo..).count());
+                assert_eq!(sg_map.range_count(..hi), bt_map.range(..hi).count());
+            }
             MapMethod::RangeMut { bitstream } => {
                 if let Some(range) = gen_valid_range(&sg_map, &bt_map, &bitstream) {
                     let sg_range_iter =
@@ -447,6 +688,17 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                     assert!(sg_range_iter.eq(bt_range_iter));
                 }
             }
+            MapMethod::Rank { key } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let bt_rank = match bt_map.contains_key(&key) {
+                    true => Some(bt_map.range(..&key).count()),
+                    false => None,
+                };
+                assert_eq!(sg_map.rank(&key), bt_rank);
+
+                assert_len_unchanged(&sg_map, &bt_map, len_old);
+            }
             MapMethod::Remove { key } => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -461,6 +713,61 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
 
                 assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
             }
+            MapMethod::RemoveIn { keys } => {
+                if keys.len() > CAPACITY {
+                    continue;
+                }
+
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let bt_keys = BTreeSet::from_iter(keys.iter().cloned());
+                bt_map.retain(|k, _| !bt_keys.contains(k));
+                sg_map.remove_in(&SgSet::<_, CAPACITY>::from_iter(keys));
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
+            }
+            MapMethod::RemoveKeys { keys } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let bt_cnt = keys
+                    .iter()
+                    .filter(|k| bt_map.remove(k).is_some())
+                    .count();
+                assert_eq!(sg_map.remove_keys(keys), bt_cnt);
+
+                assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
+            }
+            MapMethod::ReplaceEntryAtMax { key, val } => {
+                if let Some((prev_key, _)) = bt_map.iter().rev().nth(1) {
+                    if key <= *prev_key {
+                        assert_eq!(sg_map.replace_entry_at_max(key, val), Err((key, val)));
+                        assert!(sg_map.iter().eq(bt_map.iter()));
+                        continue;
+                    }
+                }
+
+                let bt_old = bt_map.pop_last();
+                bt_map.insert(key, val);
+                assert_eq!(sg_map.replace_entry_at_max(key, val), Ok(bt_old));
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+            }
+            MapMethod::ReplaceEntryAtMin { key, val } => {
+                if let Some((next_key, _)) = bt_map.iter().nth(1) {
+                    if key >= *next_key {
+                        assert_eq!(sg_map.replace_entry_at_min(key, val), Err((key, val)));
+                        assert!(sg_map.iter().eq(bt_map.iter()));
+                        continue;
+                    }
+                }
+
+                let bt_old = bt_map.pop_first();
+                bt_map.insert(key, val);
+                assert_eq!(sg_map.replace_entry_at_min(key, val), Ok(bt_old));
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+            }
             MapMethod::Retain { rand_key } => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -470,6 +777,89 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                 assert!(sg_map.iter().eq(bt_map.iter()));
                 assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
             }
+            MapMethod::RetainIn { keys } => {
+                if keys.len() > CAPACITY {
+                    continue;
+                }
+
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                let bt_keys = BTreeSet::from_iter(keys.iter().cloned());
+                bt_map.retain(|k, _| bt_keys.contains(k));
+                sg_map.retain_in(&SgSet::<_, CAPACITY>::from_iter(keys));
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
+            }
+            MapMethod::RetainRev { keep } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                // Keep the `keep` largest keys
+                let mut budget = keep;
+                sg_map.retain_rev(|_, _| match budget {
+                    0 => false,
+                    _ => {
+                        budget -= 1;
+                        true
+                    }
+                });
+
+                let drop_keys: Vec<usize> = bt_map.keys().rev().skip(keep).copied().collect();
+                for k in drop_keys {
+                    bt_map.remove(&k);
+                }
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
+            }
+            MapMethod::RetainWhile { rand_key, stop } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                // Drop keys failing the parity check until `stop` keys have been kept
+                sg_map.retain_while(|&k, _, kept| match kept >= stop {
+                    true => ControlFlow::Break(()),
+                    false => ControlFlow::Continue(k.wrapping_add(rand_key) % 2 == 0),
+                });
+
+                let mut kept = 0;
+                let mut remove_keys = Vec::new();
+                for &k in bt_map.keys() {
+                    if kept >= stop {
+                        break;
+                    } else if k.wrapping_add(rand_key) % 2 == 0 {
+                        kept += 1;
+                    } else {
+                        remove_keys.push(k);
+                    }
+                }
+                for k in remove_keys {
+                    bt_map.remove(&k);
+                }
+
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
+            }
+            MapMethod::Select { rank } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+
+                assert_eq!(sg_map.select(rank), bt_map.iter().nth(rank));
+
+                assert_len_unchanged(&sg_map, &bt_map, len_old);
+            }
+            MapMethod::SplitBy { rand_key } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+                let pred = |k: &usize, v: &usize| (k ^ v ^ rand_key).count_ones() % 2 == 1;
+
+                let sg_removed = sg_map.split_by(|k, v| pred(k, v));
+                let (bt_removed, bt_rest): (BTreeMap<_, _>, BTreeMap<_, _>) =
+                    bt_map.into_iter().partition(|(k, v)| pred(k, v));
+                bt_map = bt_rest;
+
+                assert!(sg_removed.iter().eq(bt_removed.iter()));
+                assert!(sg_map.iter().eq(bt_map.iter()));
+                assert_eq!(sg_removed.len() + checked_get_len(&sg_map, &bt_map), len_old);
+                assert_eq!(sg_removed.verify(), Ok(()));
+            }
             MapMethod::SplitOff { key } => {
                 let len_old = checked_get_len(&sg_map, &bt_map);
 
@@ -481,6 +871,9 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                 assert!(sg_map.iter().eq(bt_map.iter()));
                 assert!(checked_get_len(&sg_map, &bt_map) <= len_old);
             }
+            MapMethod::TryClone => {
+                assert!(sg_map.try_clone().unwrap().iter().eq(bt_map.iter()));
+            }
             MapMethod::TryInsertStd { key, val } => {
                 assert_eq!(
                     sg_map
@@ -491,6 +884,32 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                         .map_err(|oe| (*oe.entry.key(), oe.value))
                 );
             }
+            MapMethod::TryUpdateOrInsert { key, val } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+                match sg_map.try_update_or_insert(key, |v| *v ^= val, || val) {
+                    Ok(sg_val) => {
+                        let bt_val = bt_map.entry(key).and_modify(|v| *v ^= val).or_insert(val);
+                        assert_eq!(sg_val, bt_val);
+                    }
+                    Err(err) => {
+                        assert_eq!(err, SgError::StackCapacityExceeded);
+                        assert!(!bt_map.contains_key(&key));
+                        assert_eq!(len_old, sg_map.capacity());
+                    }
+                }
+
+                assert!(checked_get_len(&sg_map, &bt_map) >= len_old);
+            }
+            MapMethod::UpdateOrInsert { key, val } => {
+                let len_old = checked_get_len(&sg_map, &bt_map);
+                if (len_old < sg_map.capacity()) || bt_map.contains_key(&key) {
+                    let sg_val = sg_map.update_or_insert(key, |v| *v ^= val, || val);
+                    let bt_val = bt_map.entry(key).and_modify(|v| *v ^= val).or_insert(val);
+                    assert_eq!(sg_val, bt_val);
+
+                    assert!(checked_get_len(&sg_map, &bt_map) >= len_old);
+                }
+            }
             // Trait Equivalence ---------------------------------------------------------------------------------------
             MapMethod::Clone => {
                 assert!(sg_map.clone().iter().eq(bt_map.clone().iter()));
@@ -526,4 +945,8 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
             }
         }
     }
+
+    // Final contents and internal bookkeeping (e.g. subtree sizes with `fast_rebalance`) must both hold
+    assert!(sg_map.iter().eq(bt_map.iter()));
+    assert_eq!(sg_map.verify(), Ok(()));
 });
@@ -4,7 +4,7 @@
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::iter::FromIterator;
-use std::ops::Bound::Included;
+use std::ops::Bound::{Excluded, Included};
 use std::ops::Range;
 
 use libfuzzer_sys::{
@@ -12,7 +12,7 @@ use libfuzzer_sys::{
     fuzz_target,
 };
 
-use scapegoat::SgSet;
+use scapegoat::{SgError, SgSet};
 use std::collections::BTreeSet;
 
 const CAPACITY: usize = 2048;
@@ -20,39 +20,55 @@ const CAPACITY: usize = 2048;
 #[derive(Arbitrary, Debug)]
 enum SetMethod<T: Ord + Debug> {
     // APIs ------------------------------------------------------------------------------------------------------------
+    AllInRange { start: T, end: T },
+    AnyInRange { start: T, end: T },
     Append { other: Vec<T> },
+    ApplyDiff { other: Vec<T> },
     // capacity() returns a constant. Omitted, irrelevant coverage.
     Clear,
     Contains { value: T },
     Difference { other: Vec<T> },
+    Drain,
     First,
     Get { value: T },
     Insert { value: T },
+    InsertRef { value: T },
+    InsertSlice { values: Vec<T> },
     Intersection { other: Vec<T> },
     IsDisjoint { other: Vec<T> },
     IsEmpty,
     IsSubset { other: Vec<T> },
     IsSuperset { other: Vec<T> },
     Iter,
+    IterFrom { value: T },
+    IterUntil { value: T },
     Last,
     Len,
     New,
+    Partition { rand_value: T },
     PopFirst,
     PopLast,
     Range { bitstream: Vec<u8> },
+    RangeCount { start: T, end: T },
     Remove { value: T },
     Replace { value: T },
     Retain { rand_value: T },
+    Runs { max_gap: T },
     SplitOff { value: T },
+    Subtract { other: Vec<T> },
     SymmetricDifference { other: Vec<T> },
     Take { value: T },
     Union { other: Vec<T> },
     // Trait Equivalence -----------------------------------------------------------------------------------------------
+    BitAndAssign { other: Vec<T> },
+    BitOrAssign { other: Vec<T> },
+    BitXorAssign { other: Vec<T> },
     Clone,
     Debug,
     Extend { other: Vec<T> },
     // FromIterator already tested in several of the below
     Ord { other: Vec<T> },
+    SubAssign { other: Vec<T> },
 }
 
 // Harness Helpers -----------------------------------------------------------------------------------------------------
@@ -119,6 +135,19 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
     for m in methods {
         match m {
             // API Equivalence -----------------------------------------------------------------------------------------
+            SetMethod::AllInRange { start, end } => {
+                // No `start <= end` normalization: an inverted range must not panic
+                assert_eq!(
+                    sg_set.all_in_range((Included(&start), Excluded(&end))),
+                    bt_set.iter().all(|v| (start <= *v) && (*v < end))
+                );
+            }
+            SetMethod::AnyInRange { start, end } => {
+                assert_eq!(
+                    sg_set.any_in_range((Included(&start), Excluded(&end))),
+                    bt_set.iter().any(|v| (start <= *v) && (*v < end))
+                );
+            }
             SetMethod::Append { other } => {
                 if other.len() > sg_set.capacity() {
                     continue;
@@ -139,6 +168,23 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                     assert!(checked_get_len(&sg_set, &bt_set) >= len_old);
                 }
             }
+            SetMethod::ApplyDiff { other } => {
+                if other.len() > sg_set.capacity() {
+                    continue;
+                }
+
+                let sg_target = SgSet::from_iter(other.clone());
+                let bt_target = BTreeSet::from_iter(other);
+
+                let removed_cnt = bt_set.difference(&bt_target).count();
+                let added_cnt = bt_target.difference(&bt_set).count();
+
+                assert_eq!(sg_set.apply_diff(&sg_target), (removed_cnt, added_cnt));
+                bt_set = bt_target;
+
+                assert!(sg_set == sg_target);
+                assert!(sg_set.iter().eq(bt_set.iter()));
+            }
             SetMethod::Clear => {
                 sg_set.clear();
                 bt_set.clear();
@@ -170,6 +216,12 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                 assert_eq!(sg_diff, bt_diff);
                 assert!(sg_diff.len() <= sg_set.len());
             }
+            SetMethod::Drain => {
+                assert!(sg_set.drain().eq(core::mem::take(&mut bt_set).into_iter()));
+
+                assert!(sg_set.is_empty());
+                assert_eq!(sg_set.len(), 0);
+            }
             SetMethod::First => {
                 let len_old = checked_get_len(&sg_set, &bt_set);
 
@@ -192,6 +244,34 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                     assert!(checked_get_len(&sg_set, &bt_set) >= len_old);
                 }
             }
+            SetMethod::InsertRef { value } => {
+                let len_old = checked_get_len(&sg_set, &bt_set);
+                if len_old < sg_set.capacity() {
+                    bt_set.insert(value);
+                    assert_eq!(*sg_set.insert_ref(value), value);
+
+                    assert!(checked_get_len(&sg_set, &bt_set) >= len_old);
+                }
+            }
+            SetMethod::InsertSlice { values } => {
+                let len_old = checked_get_len(&sg_set, &bt_set);
+
+                let mut bt_res = Ok(0);
+                for v in &values {
+                    if (bt_set.len() == sg_set.capacity()) && !bt_set.contains(v) {
+                        bt_res = Err(SgError::StackCapacityExceeded);
+                        break;
+                    }
+                    if bt_set.insert(*v) {
+                        bt_res = bt_res.map(|cnt| cnt + 1);
+                    }
+                }
+
+                assert_eq!(sg_set.try_insert_slice(&values), bt_res);
+
+                assert!(sg_set.iter().eq(bt_set.iter()));
+                assert!(checked_get_len(&sg_set, &bt_set) >= len_old);
+            }
             SetMethod::Intersection { other } => {
                 if other.len() > sg_set.capacity() {
                     continue;
@@ -236,6 +316,12 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
             SetMethod::Iter => {
                 assert!(sg_set.iter().eq(bt_set.iter()));
             }
+            SetMethod::IterFrom { value } => {
+                assert!(sg_set.iter_from(&value).eq(bt_set.range(&value..)));
+            }
+            SetMethod::IterUntil { value } => {
+                assert!(sg_set.iter_until(&value).eq(bt_set.range(..&value)));
+            }
             SetMethod::IsSuperset { other } => {
                 if other.len() > sg_set.capacity() {
                     continue;
@@ -260,6 +346,18 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                 sg_set = SgSet::new();
                 bt_set = BTreeSet::new();
             }
+            SetMethod::Partition { rand_value } => {
+                let len_old = checked_get_len(&sg_set, &bt_set);
+                let pred = |v: &usize| (v ^ rand_value).count_ones() % 2 == 1;
+
+                let (sg_match, sg_rest) = sg_set.clone().partition(pred);
+                let (bt_match, bt_rest): (BTreeSet<_>, BTreeSet<_>) =
+                    bt_set.clone().into_iter().partition(pred);
+
+                assert!(sg_match.iter().eq(bt_match.iter()));
+                assert!(sg_rest.iter().eq(bt_rest.iter()));
+                assert_eq!(sg_match.len() + sg_rest.len(), len_old);
+            }
             SetMethod::PopFirst => {
                 let len_old = checked_get_len(&sg_set, &bt_set);
 
@@ -281,6 +379,16 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                     assert!(sg_range.eq(bt_range));
                 }
             }
+            SetMethod::RangeCount { start, end } => {
+                let (lo, hi) = match start <= end {
+                    true => (start, end),
+                    false => (end, start),
+                };
+
+                assert_eq!(sg_set.range_count(lo..=hi), bt_set.range(lo..=hi).count());
+                assert_eq!(sg_set.range_count(lo..), bt_set.range(lo..).count());
+                assert_eq!(sg_set.range_count(..hi), bt_set.range(..hi).count());
+            }
             SetMethod::Remove { value } => {
                 let len_old = checked_get_len(&sg_set, &bt_set);
 
@@ -305,6 +413,19 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                 assert!(sg_set.iter().eq(bt_set.iter()));
                 assert!(checked_get_len(&sg_set, &bt_set) <= len_old);
             }
+            SetMethod::Runs { max_gap } => {
+                let adjacent = |a: &usize, b: &usize| (b - a) <= max_gap;
+
+                let mut bt_runs = Vec::new();
+                for v in bt_set.iter() {
+                    match bt_runs.last_mut() {
+                        Some((_, last)) if adjacent(*last, v) => *last = v,
+                        _ => bt_runs.push((v, v)),
+                    }
+                }
+
+                assert!(sg_set.runs(adjacent).eq(bt_runs.into_iter()));
+            }
             SetMethod::SplitOff { value } => {
                 let len_old = checked_get_len(&sg_set, &bt_set);
 
@@ -316,6 +437,19 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                 assert!(sg_set.iter().eq(bt_set.iter()));
                 assert!(checked_get_len(&sg_set, &bt_set) <= len_old);
             }
+            SetMethod::Subtract { other } => {
+                if other.len() > sg_set.capacity() {
+                    continue;
+                }
+
+                let bt_other = BTreeSet::from_iter(other.clone());
+                let removed_cnt = bt_set.intersection(&bt_other).count();
+
+                assert_eq!(sg_set.subtract(&SgSet::from_iter(other)), removed_cnt);
+                bt_set = &bt_set - &bt_other;
+
+                assert!(sg_set.iter().eq(bt_set.iter()));
+            }
             SetMethod::SymmetricDifference { other } => {
                 if other.len() > sg_set.capacity() {
                     continue;
@@ -356,6 +490,46 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
                 assert!(sg_union.len() >= sg_set.len());
             }
             // Trait Equivalence ---------------------------------------------------------------------------------------
+            SetMethod::BitAndAssign { other } => {
+                if other.len() > sg_set.capacity() {
+                    continue;
+                }
+
+                sg_set &= &SgSet::from_iter(other.clone());
+                bt_set = &bt_set & &BTreeSet::from_iter(other);
+
+                assert!(sg_set.iter().eq(bt_set.iter()));
+            }
+            SetMethod::BitOrAssign { other } => {
+                if other.len() > sg_set.capacity() {
+                    continue;
+                }
+
+                let bt_other = BTreeSet::from_iter(other.clone());
+                if bt_set.union(&bt_other).count() > sg_set.capacity() {
+                    continue;
+                }
+
+                sg_set |= &SgSet::from_iter(other);
+                bt_set = &bt_set | &bt_other;
+
+                assert!(sg_set.iter().eq(bt_set.iter()));
+            }
+            SetMethod::BitXorAssign { other } => {
+                if other.len() > sg_set.capacity() {
+                    continue;
+                }
+
+                let bt_other = BTreeSet::from_iter(other.clone());
+                if bt_set.symmetric_difference(&bt_other).count() > sg_set.capacity() {
+                    continue;
+                }
+
+                sg_set ^= &SgSet::from_iter(other);
+                bt_set = &bt_set ^ &bt_other;
+
+                assert!(sg_set.iter().eq(bt_set.iter()));
+            }
             SetMethod::Clone => {
                 assert!(sg_set.clone().iter().eq(bt_set.clone().iter()));
             }
@@ -383,6 +557,20 @@ fuzz_target!(|methods: Vec<SetMethod<usize>>| {
 
                 assert_eq!(sg_set.cmp(&sg_set_new), bt_set.cmp(&bt_set_new),);
             }
+            SetMethod::SubAssign { other } => {
+                if other.len() > sg_set.capacity() {
+                    continue;
+                }
+
+                sg_set -= &SgSet::from_iter(other.clone());
+                bt_set = &bt_set - &BTreeSet::from_iter(other);
+
+                assert!(sg_set.iter().eq(bt_set.iter()));
+            }
         }
     }
+
+    // Final contents and internal bookkeeping (e.g. subtree sizes with `fast_rebalance`) must both hold
+    assert!(sg_set.iter().eq(bt_set.iter()));
+    assert_eq!(sg_set.verify(), Ok(()));
 });
@@ -0,0 +1,91 @@
+#![no_main]
+
+use std::collections::BTreeSet;
+
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+
+use scapegoat::{Arena, SgMap, SmallNode};
+
+const CAPACITY: usize = 256;
+
+// Must match the library's internal index type
+type Idx = u16;
+
+// Narrow index types make valid (and almost-valid) links common, so `verify()` is reached past its cheap checks
+#[derive(Arbitrary, Debug)]
+struct RawNode {
+    key: usize,
+    left_idx: Option<u8>,
+    right_idx: Option<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct RawTree {
+    slots: Vec<Option<RawNode>>,
+    opt_root_idx: Option<u8>,
+    min_idx: u8,
+    max_idx: u8,
+    curr_size: u8,
+}
+
+fuzz_target!(|raw: RawTree| {
+    let mut arena = Arena::<usize, usize, Idx, CAPACITY>::new();
+    let slots: Vec<_> = raw.slots.into_iter().take(CAPACITY).collect();
+
+    // Allocate every slot, then free the empty ones so the free list stays well-formed
+    for (idx, opt_raw_node) in slots.iter().enumerate() {
+        let key = opt_raw_node.as_ref().map_or(0, |n| n.key);
+        assert_eq!(arena.add(key, idx), idx);
+    }
+
+    for (idx, opt_raw_node) in slots.iter().enumerate() {
+        match opt_raw_node {
+            Some(raw_node) => {
+                arena[idx].set_left_idx(raw_node.left_idx.map(usize::from));
+                arena[idx].set_right_idx(raw_node.right_idx.map(usize::from));
+            }
+            None => {
+                let _ = arena.hard_remove(idx);
+            }
+        }
+    }
+
+    let mut sg_map = SgMap::from_raw_parts(
+        arena,
+        raw.opt_root_idx.map(usize::from),
+        usize::from(raw.min_idx),
+        usize::from(raw.max_idx),
+        usize::from(raw.curr_size),
+    );
+
+    // Must never panic, regardless of input
+    if sg_map.verify().is_err() {
+        return;
+    }
+
+    // A verified tree must be safe to use
+    let keys: Vec<usize> = sg_map.iter().map(|(k, _)| *k).collect();
+    assert_eq!(keys.len(), sg_map.len());
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(sg_map.first_key(), keys.first());
+    assert_eq!(sg_map.last_key(), keys.last());
+
+    for k in &keys {
+        assert!(sg_map.contains_key(k));
+    }
+
+    let mut key_set: BTreeSet<usize> = keys.iter().copied().collect();
+    for k in keys.iter().step_by(2) {
+        assert!(sg_map.remove(k).is_some());
+        key_set.remove(k);
+        assert_eq!(sg_map.verify(), Ok(()));
+    }
+
+    for k in keys.iter().step_by(2) {
+        sg_map.insert(*k, 0);
+        key_set.insert(*k);
+        assert_eq!(sg_map.verify(), Ok(()));
+    }
+
+    assert!(sg_map.iter().map(|(k, _)| k).eq(key_set.iter()));
+});
@@ -31,7 +31,7 @@ Strives for three properties:
 
 Other features:
 
-* **Generic:** map keys and set elements can be any type that implements traits [`Ord`](https://doc.rust-lang.org/std/cmp/trait.Ord.html) and [`Default`](https://doc.rust-lang.org/std/default/trait.Default.html).
+* **Generic:** map keys and set elements can be any type that implements trait [`Ord`](https://doc.rust-lang.org/std/cmp/trait.Ord.html).
 * **Arbitrarily mutable:** elements can be inserted and removed, map values can be mutated. Safely.
 
 ### Usage
@@ -115,6 +115,7 @@ let big_map: SgMap<u64, u64, 2_048> = SgMap::new(); // 2,048 item capacity
 #[cfg(target_pointer_width = "64")]
 #[cfg(not(feature = "low_mem_insert"))]
 #[cfg(not(feature = "fast_rebalance"))]
+#[cfg(not(feature = "profiling"))]
 {
     assert_eq!(size_of_val(&small_map), 2_680); // 2.7 KB
     assert_eq!(size_of_val(&big_map), 53_328);  // 53.3 KB
@@ -204,9 +205,13 @@ Licensed under the [MIT license](https://github.com/tnballo/scapegoat/blob/maste
 )]
 #![deny(missing_docs)]
 
+// Opt-in `std` interop, crate remains `no_std` otherwise
+#[cfg(all(feature = "std", not(any(test, fuzzing))))]
+extern crate std;
+
 // Only expose arena internals for fuzzing harness
 #[cfg(fuzzing)]
-pub use crate::tree::{Arena, Node, NodeGetHelper, NodeRebuildHelper};
+pub use crate::tree::{Arena, Node, NodeGetHelper, NodeRebuildHelper, SmallNode};
 
 mod tree;
 pub use crate::tree::SgError;
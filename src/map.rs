@@ -1,37 +1,71 @@
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
 use core::iter::FromIterator;
-use core::ops::{Index, RangeBounds};
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::{ControlFlow, Index, RangeBounds};
 
 use crate::map_types::{
-    Entry, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, OccupiedEntry, OccupiedError,
-    Range, RangeMut, VacantEntry, Values, ValuesMut,
+    ArenaStats, Drain, Entry, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, MemoryReport,
+    OccupiedEntry, OccupiedError, Range, RangeMut, RangeValuesMut, VacantEntry, Values, ValuesMut,
 };
+use crate::set::SgSet;
 use crate::tree::{node::NodeGetHelper, Idx, SgError, SgTree};
 
+use tinyvec::ArrayVec;
+
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
 /// Safe, fallible, embedded-friendly ordered map.
 ///
 /// ### Fallible APIs
 ///
 /// * [`try_insert`][crate::map::SgMap::try_insert]
+/// * [`try_insert_multi`][crate::map::SgMap::try_insert_multi]
 /// * [`try_append`][crate::map::SgMap::try_append]
+/// * [`try_append_iter`][crate::map::SgMap::try_append_iter]
 /// * [`try_extend`][crate::map::SgMap::try_extend]
 /// * [`try_from_iter`][crate::map::SgMap::try_from_iter]
 ///
 /// [`TryFrom`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) isn't implemented because it would collide with the blanket implementation.
 /// See [this open GitHub issue](https://github.com/rust-lang/rust/issues/50133#issuecomment-64690839) from 2018,
 /// this is a known Rust limitation that should be fixed via specialization in the future.
+/// To move into a different capacity, use [`resize`][crate::map::SgMap::resize].
+///
+/// ### Read-only APIs
+///
+/// Methods taking `&self` (e.g. `get`, `contains_key`, `first_key_value`, `iter`) never rebalance the tree or physically reorder its backing arena.
+/// Only mutating methods, like [`iter_mut`][crate::map::SgMap::iter_mut] or [`retain`][crate::map::SgMap::retain], may re-sort the arena.
 ///
 /// ### Attribution Note
 ///
 /// The majority of API examples and descriptions are adapted or directly copied from the standard library's [`BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html).
 /// The goal is to offer embedded developers familiar, ergonomic APIs on resource constrained systems that otherwise don't get the luxury of dynamic collections.
-#[derive(Default, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
-pub struct SgMap<K: Ord + Default, V: Default, const N: usize> {
+#[derive(Clone, PartialEq, Eq, Ord, PartialOrd)]
+pub struct SgMap<K: Ord, V, const N: usize> {
     pub(crate) bst: SgTree<K, V, N>,
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
+impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
+    /// Total capacity, e.g. maximum number of map pairs, as an associated constant.
+    /// Usable in const contexts, unlike the equivalent [`capacity`][SgMap::capacity] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// const NEEDED: usize = 8;
+    /// const SLOTS: [Option<u32>; SgMap::<u32, u32, 10>::CAPACITY] = [None; SgMap::<u32, u32, 10>::CAPACITY];
+    /// assert!(SLOTS.len() >= NEEDED);
+    ///
+    /// let map = SgMap::<u32, u32, 10>::new();
+    /// assert_eq!(SgMap::<u32, u32, 10>::CAPACITY, map.capacity());
+    /// ```
+    pub const CAPACITY: usize = N;
+
     /// Makes a new, empty `SgMap`.
     ///
     /// # Examples
@@ -57,6 +91,9 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// * As `a` approaches `1.0`, the tree will rebalance less often. This means quicker insertions, but slower lookups and deletions.
     ///     * If `a` reached `1.0`, it'd mean a tree that never rebalances.
     ///
+    /// The new `a` only affects future operations: it's checked on subsequent insertions, and subtrees already built
+    /// under the old `a` aren't restructured. Use [`rebalance_now`][SgMap::rebalance_now] to apply it retroactively.
+    ///
     /// Returns `Err` if `0.5 <= alpha_num / alpha_denom < 1.0` isn't `true` (invalid `a`, out of range).
     ///
     /// # Examples
@@ -97,217 +134,225 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.rebal_param()
     }
 
-    /// Total capacity, e.g. maximum number of map pairs.
+    /// Rebuild the entire map into a perfectly balanced shape, in `O(n)` time.
+    /// Useful for applying a stricter [rebalance parameter][SgMap::set_rebal_param] to already-inserted entries.
+    /// Counts as a single rebalance.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<usize, &str, 10>::new();
+    /// let mut map = SgMap::<usize, usize, 100>::new();
+    /// assert!(map.set_rebal_param(99.0, 100.0).is_ok());
+    /// for i in 0..100 {
+    ///     map.insert(i, i);
+    /// }
     ///
-    /// assert!(map.capacity() == 10);
+    /// // Lax alpha, sequential inserts never triggered a rebuild
+    /// assert_eq!(map.rebal_cnt(), 0);
+    ///
+    /// assert!(map.set_rebal_param(1.0, 2.0).is_ok());
+    /// map.rebalance_now();
+    /// assert_eq!(map.rebal_cnt(), 1);
+    /// assert!(map.iter().map(|(k, _)| *k).eq(0..100));
     /// ```
-    pub fn capacity(&self) -> usize {
-        self.bst.capacity()
+    pub fn rebalance_now(&mut self) {
+        self.bst.rebalance_now();
     }
 
-    /// Gets an iterator over the keys of the map, in sorted order.
+    /// Get the number of times this map rebalanced itself, since creation or the last
+    /// [`reset_rebal_cnt`][SgMap::reset_rebal_cnt] call. Wraps if `usize::MAX` is exceeded.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(2, "b");
-    /// a.insert(1, "a");
+    /// let mut map = SgMap::<usize, usize, 100>::new();
+    /// for i in 0..100 {
+    ///     map.insert(i, i);
+    /// }
     ///
-    /// let keys: Vec<_> = a.keys().cloned().collect();
-    /// assert_eq!(keys, [1, 2]);
+    /// // Sequential inserts skew the tree, forcing rebuilds
+    /// assert!(map.rebal_cnt() > 0);
     /// ```
-    pub fn keys(&self) -> Keys<'_, K, V, N> {
-        Keys { inner: self.iter() }
+    pub fn rebal_cnt(&self) -> usize {
+        self.bst.rebal_cnt()
     }
 
-    /// Creates a consuming iterator visiting all the keys, in sorted order.
-    /// The map cannot be used after calling this.
-    /// The iterator element type is `K`.
+    /// Reset the rebalance count to zero, e.g. at the start of a measurement window.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(2, "b");
-    /// a.insert(1, "a");
+    /// let mut map = SgMap::<usize, usize, 100>::new();
+    /// for i in 0..100 {
+    ///     map.insert(i, i);
+    /// }
     ///
-    /// let keys: Vec<i32> = a.into_keys().collect();
-    /// assert_eq!(keys, [1, 2]);
+    /// map.reset_rebal_cnt();
+    /// assert_eq!(map.rebal_cnt(), 0);
     /// ```
-    pub fn into_keys(self) -> IntoKeys<K, V, N> {
-        IntoKeys {
-            inner: self.into_iter(),
-        }
+    pub fn reset_rebal_cnt(&mut self) {
+        self.bst.reset_rebal_cnt();
     }
 
-    /// Gets an iterator over the values of the map, in order by key.
+    /// Register a callback invoked after every rebuild, passed the number of elements in the rebuilt subtree.
+    /// Pass `None` to remove a registered hook.
+    /// The hook survives [`clear`][SgMap::clear] and `clone`, but isn't carried over by `resize`.
+    ///
+    /// Requires the `profiling` feature.
     ///
     /// # Examples
     ///
     /// ```
+    /// use core::sync::atomic::{AtomicUsize, Ordering};
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "hello");
-    /// a.insert(2, "goodbye");
+    /// static MAX_REBUILD: AtomicUsize = AtomicUsize::new(0);
     ///
-    /// let values: Vec<&str> = a.values().cloned().collect();
-    /// assert_eq!(values, ["hello", "goodbye"]);
+    /// let mut map = SgMap::<usize, usize, 100>::new();
+    /// map.set_rebuild_hook(Some(|size| {
+    ///     MAX_REBUILD.fetch_max(size, Ordering::Relaxed);
+    /// }));
+    ///
+    /// for i in 0..100 {
+    ///     map.insert(i, i);
+    /// }
+    ///
+    /// assert!(MAX_REBUILD.load(Ordering::Relaxed) > 1);
     /// ```
-    pub fn values(&self) -> Values<'_, K, V, N> {
-        Values { inner: self.iter() }
+    #[cfg(feature = "profiling")]
+    pub fn set_rebuild_hook(&mut self, hook: Option<fn(usize)>) {
+        self.bst.set_rebuild_hook(hook);
     }
 
-    /// Creates a consuming iterator visiting all the values, in order by key.
-    /// The map cannot be used after calling this.
-    /// The iterator element type is `V`.
+    /// Total capacity, e.g. maximum number of map pairs.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "hello");
-    /// a.insert(2, "goodbye");
+    /// let mut map = SgMap::<usize, &str, 10>::new();
     ///
-    /// let values: Vec<&str> = a.into_values().collect();
-    /// assert_eq!(values, ["hello", "goodbye"]);
+    /// assert!(map.capacity() == 10);
     /// ```
-    pub fn into_values(self) -> IntoValues<K, V, N> {
-        IntoValues {
-            inner: self.into_iter(),
-        }
+    pub fn capacity(&self) -> usize {
+        self.bst.capacity()
     }
 
-    /// Gets a mutable iterator over the values of the map, in order by key.
+    /// Returns occupancy statistics for the map's backing arena, in `O(1)` time.
+    /// Removals leave vacated slots behind, so `free_slots` grows with churn until later insertions reuse them.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::ArenaStats;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, String::from("hello"));
-    /// a.insert(2, String::from("goodbye"));
+    /// let mut map: SgMap<usize, usize, 10> = (0..6).map(|i| (i, i)).collect();
+    /// map.remove(&1);
+    /// map.remove(&4);
     ///
-    /// for value in a.values_mut() {
-    ///     value.push_str("!");
-    /// }
+    /// assert_eq!(
+    ///     map.arena_stats(),
+    ///     ArenaStats {
+    ///         physical_len: 6,
+    ///         live_nodes: 4,
+    ///         free_slots: 2,
+    ///     }
+    /// );
     ///
-    /// let values: Vec<String> = a.values().cloned().collect();
-    /// assert_eq!(values, [String::from("hello!"),
-    ///                     String::from("goodbye!")]);
+    /// // Vacated slots are reused first
+    /// map.insert(10, 10);
+    /// assert_eq!(map.arena_stats().free_slots, 1);
+    /// assert_eq!(map.arena_stats().physical_len, 6);
     /// ```
-    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V, N> {
-        ValuesMut {
-            inner: self.iter_mut(),
+    pub fn arena_stats(&self) -> ArenaStats {
+        let physical_len = self.bst.arena.len();
+        let live_nodes = self.len();
+        debug_assert!(live_nodes <= physical_len);
+
+        ArenaStats {
+            physical_len,
+            live_nodes,
+            free_slots: physical_len - live_nodes,
         }
     }
 
-    /// Moves all elements from `other` into `self`, leaving `other` empty.
+    /// Returns the map's memory footprint, in `O(1)` time.
+    ///
+    /// `stack_bytes` covers the whole map, not just its nodes. All storage is inline, so it doesn't change with `used`.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "a");
-    /// a.insert(2, "b");
-    /// a.insert(3, "c");
-    ///
-    /// let mut b = SgMap::<_, _, 10>::new();
-    /// b.insert(3, "d");
-    /// b.insert(4, "e");
-    /// b.insert(5, "f");
-    ///
-    /// a.append(&mut b);
-    ///
-    /// assert_eq!(a.len(), 5);
-    /// assert_eq!(b.len(), 0);
+    /// let mut map = SgMap::<u32, u32, 100>::new();
+    /// map.insert(1, 1);
     ///
-    /// assert_eq!(a[&1], "a");
-    /// assert_eq!(a[&2], "b");
-    /// assert_eq!(a[&3], "d");
-    /// assert_eq!(a[&4], "e");
-    /// assert_eq!(a[&5], "f");
+    /// let report = map.memory_report();
+    /// assert_eq!(report.stack_bytes, core::mem::size_of::<SgMap<u32, u32, 100>>());
+    /// assert!(report.node_bytes * report.capacity <= report.stack_bytes);
+    /// assert_eq!((report.capacity, report.used), (100, 1));
     /// ```
-    pub fn append(&mut self, other: &mut SgMap<K, V, N>) {
-        self.bst.append(&mut other.bst);
+    pub fn memory_report(&self) -> MemoryReport {
+        MemoryReport {
+            stack_bytes: core::mem::size_of::<Self>(),
+            node_bytes: self.bst.node_size(),
+            capacity: self.capacity(),
+            used: self.len(),
+        }
     }
 
-    /// Attempts to move all elements from `other` into `self`, leaving `other` empty.
+    /// Returns `true` if `additional` more map pairs are guaranteed to fit, e.g. `len() + additional <= N`.
+    ///
+    /// Capacity is fixed by `N`, so unlike `Vec::try_reserve` nothing is allocated.
+    /// This is a pre-insertion guard.
     ///
     /// # Examples
     ///
     /// ```
-    /// use core::iter::FromIterator;
-    /// use scapegoat::{SgMap, SgError};
-    ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.try_insert(1, "a").is_ok();
-    /// a.try_insert(2, "b").is_ok();
-    /// a.try_insert(3, "c").is_ok();
-    ///
-    /// let mut b = SgMap::<_, _, 10>::new();
-    /// b.try_insert(3, "d").is_ok(); // Overwrite previous
-    /// b.try_insert(4, "e").is_ok();
-    /// b.try_insert(5, "f").is_ok();
-    ///
-    /// // Successful append
-    /// assert!(a.try_append(&mut b).is_ok());
-    ///
-    /// // Elements moved
-    /// assert_eq!(a.len(), 5);
-    /// assert_eq!(b.len(), 0);
+    /// use scapegoat::SgMap;
     ///
-    /// assert_eq!(a[&1], "a");
-    /// assert_eq!(a[&2], "b");
-    /// assert_eq!(a[&3], "d");
-    /// assert_eq!(a[&4], "e");
-    /// assert_eq!(a[&5], "f");
+    /// let mut map = SgMap::<usize, &str, 3>::new();
+    /// map.insert(1, "a");
     ///
-    /// // Fill remaining capacity
-    /// let mut key = 6;
-    /// while a.len() < a.capacity() {
-    ///     assert!(a.try_insert(key, "filler").is_ok());
-    ///     key += 1;
-    /// }
+    /// assert!(map.can_insert(2));
+    /// assert!(!map.can_insert(3));
+    /// ```
+    pub fn can_insert(&self, additional: usize) -> bool {
+        self.bst.can_insert(additional)
+    }
+
+    /// Returns `Err(SgError::StackCapacityExceeded)` unless `additional` more map pairs are guaranteed to fit.
     ///
-    /// // Full
-    /// assert!(a.is_full());
+    /// # Examples
     ///
-    /// // More data
-    /// let mut c = SgMap::<_, _, 10>::from_iter([(11, "k"), (12, "l")]);
-    /// let mut d = SgMap::<_, _, 10>::from_iter([(1, "a2"), (2, "b2")]);
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
     ///
-    /// // Cannot append new pairs
-    /// assert_eq!(a.try_append(&mut c), Err(SgError::StackCapacityExceeded));
+    /// let mut map = SgMap::<usize, &str, 3>::new();
+    /// map.insert(1, "a");
     ///
-    /// // Can still replace existing pairs
-    /// assert!(a.try_append(&mut d).is_ok());
+    /// assert_eq!(map.ensure_capacity(2), Ok(()));
+    /// assert_eq!(map.ensure_capacity(3), Err(SgError::StackCapacityExceeded));
     /// ```
-    pub fn try_append(&mut self, other: &mut SgMap<K, V, N>) -> Result<(), SgError> {
-        self.bst.try_append(&mut other.bst)
+    pub fn ensure_capacity(&self, additional: usize) -> Result<(), SgError> {
+        self.bst.ensure_capacity(additional)
     }
 
-    /// Insert a key-value pair into the map.
-    /// If the map did not have this key present, `None` is returned.
-    /// If the map did have this key present, the value is updated, the old value is returned,
-    /// and the key is updated. This accommodates types that can be `==` without being identical.
+    /// Fallible counterpart of [`clone`][Clone::clone].
+    ///
+    /// The backing store is a fixed-capacity array, so this currently always returns `Ok`.
+    /// The `Result` is part of the contract: should a future backing store be able to fail allocation,
+    /// that failure will be reported here as an `Err` instead of a panic.
+    /// Code that must not panic can adopt this now and stay correct later.
     ///
     /// # Examples
     ///
@@ -315,169 +360,1455 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// use scapegoat::SgMap;
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
-    /// assert_eq!(map.insert(37, "a"), None);
-    /// assert_eq!(map.is_empty(), false);
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
     ///
-    /// map.insert(37, "b");
-    /// assert_eq!(map.insert(37, "c"), Some("b"));
-    /// assert_eq!(map[&37], "c");
+    /// let copy = map.try_clone().unwrap();
+    /// assert_eq!(copy, map);
     /// ```
-    pub fn insert(&mut self, key: K, val: V) -> Option<V>
+    pub fn try_clone(&self) -> Result<Self, SgError>
     where
-        K: Ord,
+        K: Clone,
+        V: Clone,
     {
-        self.bst.insert(key, val)
+        Ok(self.clone())
     }
 
-    /// Insert a key-value pair into the map.
-    /// Returns `Err` if the operation can't be completed, else the `Ok` contains:
-    /// * `None` if the map did not have this key present.
-    /// * The old value if the map did have this key present (both the value and key are updated,
-    /// this accommodates types that can be `==` without being identical).
-    ///
-    /// ### Warning
+    /// Clones the map into a new map of capacity `M`, e.g. to reclaim memory after a transient high-water mark.
+    /// The rebalance parameter is preserved.
     ///
-    /// Unlike other APIs in this crate, the semantics and return type of this API are *NOT* the same as `BTreeMap`'s nightly [`try_insert`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.try_insert).
-    /// For an equivalent, use [`try_insert_std`][`SgMap::try_insert_std`].
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the map's length,
+    /// or `Err(SgError::MaximumCapacityExceeded)` if `M` exceeds `u16::MAX`.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::{SgMap, SgError};
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    ///
-    /// // Add a new pair
-    /// assert_eq!(map.try_insert(37, "a"), Ok(None));
-    /// assert_eq!(map.is_empty(), false);
-    ///
-    /// // Replace existing pair
-    /// map.insert(37, "b");
-    /// assert_eq!(map.try_insert(37, "c"), Ok(Some("b")));
-    /// assert_eq!(map[&37], "c");
-    ///
-    /// // Fill remaining capacity
-    /// let mut key = 38;
-    /// while map.len() < map.capacity() {
-    ///     assert!(map.try_insert(key, "filler").is_ok());
-    ///     key += 1;
-    /// }
-    ///
-    /// // Full
-    /// assert!(map.is_full());
+    /// let mut big = SgMap::<_, _, 100>::new();
+    /// big.insert(1, "a");
+    /// big.insert(2, "b");
     ///
-    /// // Cannot insert new pair
-    /// assert_eq!(map.try_insert(key, "out of bounds"), Err(SgError::StackCapacityExceeded));
+    /// let small = big.clone_resize::<2>().unwrap();
+    /// assert_eq!(small.capacity(), 2);
+    /// assert!(small.iter().eq(big.iter()));
     ///
-    /// // Can still replace existing pair
-    /// assert_eq!(map.try_insert(key - 1, "overwrite filler"), Ok(Some("filler")));
+    /// assert_eq!(big.clone_resize::<1>().unwrap_err(), SgError::StackCapacityExceeded);
     /// ```
-    ///
-    pub fn try_insert(&mut self, key: K, val: V) -> Result<Option<V>, SgError>
+    pub fn clone_resize<const M: usize>(&self) -> Result<SgMap<K, V, M>, SgError>
     where
-        K: Ord,
+        K: Clone,
+        V: Clone,
     {
-        self.bst.try_insert(key, val)
+        self.bst.clone_resize().map(|bst| SgMap { bst })
     }
 
-    /// Tries to insert a key-value pair into the map, and returns
-    /// a mutable reference to the value in the entry.
+    /// Moves the map into a new map of capacity `M`, consuming it.
+    /// Unlike [`clone_resize`][SgMap::clone_resize], no `Clone` bound is required.
+    /// The rebalance parameter is preserved.
     ///
-    /// If the map already had this key present, nothing is updated, and
-    /// an error containing the occupied entry and the value is returned.
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the map's length,
+    /// or `Err(SgError::MaximumCapacityExceeded)` if `M` exceeds `u16::MAX`.
     ///
-    /// ### Warning
+    /// # Examples
     ///
-    /// The semantics and return type of this API match `BTreeMap`'s nightly [`try_insert`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.try_insert), *NOT* the other `try_*` APIs in this crate.
-    /// For a fallible insert, use [`try_insert`][`SgMap::try_insert`].
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
     ///
-    /// # Examples
+    /// let mut big = SgMap::<_, _, 100>::new();
+    /// big.insert(1, "a".to_string());
+    /// big.insert(2, "b".to_string());
     ///
-    /// Basic usage:
+    /// let small = big.resize::<2>().unwrap();
+    /// assert_eq!(small.capacity(), 2);
+    /// assert!(small.into_iter().eq(vec![(1, "a".to_string()), (2, "b".to_string())]));
     ///
+    /// let big: SgMap<_, _, 100> = (0..3).map(|x| (x, x)).collect();
+    /// assert_eq!(big.resize::<2>().unwrap_err(), SgError::StackCapacityExceeded);
     /// ```
-    /// use scapegoat::SgMap;
+    pub fn resize<const M: usize>(self) -> Result<SgMap<K, V, M>, SgError> {
+        self.bst.resize().map(|bst| SgMap { bst })
+    }
+
+    /// Clones the map's pairs, in order by key, into a stack-allocated `ArrayVec` of capacity `M`.
+    ///
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the map's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
-    /// assert_eq!(map.try_insert_std(37, "a").unwrap(), &"a");
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
     ///
-    /// let err = map.try_insert_std(37, "b").unwrap_err();
-    /// assert_eq!(err.entry.key(), &37);
-    /// assert_eq!(err.entry.get(), &"a");
-    /// assert_eq!(err.value, "b");
+    /// let pairs = map.to_array_vec::<4>().unwrap();
+    /// assert_eq!(pairs.as_slice(), [(1, "a"), (2, "b")]);
+    ///
+    /// assert_eq!(map.to_array_vec::<1>().unwrap_err(), SgError::StackCapacityExceeded);
     /// ```
-    pub fn try_insert_std(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, N>>
+    pub fn to_array_vec<const M: usize>(&self) -> Result<ArrayVec<[(K, V); M]>, SgError>
     where
-        K: Ord,
+        K: Clone + Default,
+        V: Clone + Default,
     {
-        match self.entry(key) {
-            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
-            Entry::Vacant(entry) => Ok(entry.insert(value)),
+        if self.len() > M {
+            return Err(SgError::StackCapacityExceeded);
         }
+
+        Ok(self.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
     }
 
-    /// Attempt to extend a collection with the contents of an iterator.
+    /// Clones the map's keys, in sorted order, into a stack-allocated `ArrayVec` of capacity `M`.
+    ///
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the map's length.
     ///
     /// # Examples
     ///
     /// ```
-    /// use core::iter::FromIterator;
     /// use scapegoat::{SgMap, SgError};
     ///
-    /// let mut a = SgMap::<_, _, 2>::new();
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// let keys = map.keys_to_array_vec::<2>().unwrap();
+    /// assert_eq!(keys.as_slice(), [1, 2]);
+    ///
+    /// assert_eq!(map.keys_to_array_vec::<1>().unwrap_err(), SgError::StackCapacityExceeded);
+    /// ```
+    pub fn keys_to_array_vec<const M: usize>(&self) -> Result<ArrayVec<[K; M]>, SgError>
+    where
+        K: Clone + Default,
+    {
+        if self.len() > M {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        Ok(self.keys().cloned().collect())
+    }
+
+    /// Clones the map's values, in order by key, into a stack-allocated `ArrayVec` of capacity `M`.
+    ///
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the map's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// let values = map.values_to_array_vec::<2>().unwrap();
+    /// assert_eq!(values.as_slice(), ["a", "b"]);
+    ///
+    /// assert_eq!(map.values_to_array_vec::<1>().unwrap_err(), SgError::StackCapacityExceeded);
+    /// ```
+    pub fn values_to_array_vec<const M: usize>(&self) -> Result<ArrayVec<[V; M]>, SgError>
+    where
+        V: Clone + Default,
+    {
+        if self.len() > M {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        Ok(self.values().cloned().collect())
+    }
+
+    /// Writes references to the map's keys, in ascending order, into a caller-provided buffer.
+    /// Returns the number of references written: at most `out.len()`, the remainder of `out` is left untouched.
+    ///
+    /// Useful when a buffer (e.g. `[&K; N]`) is preallocated and only the sorted references are needed.
+    /// Like other `&self` methods, this doesn't reorder the backing arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = vec![(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    ///
+    /// let mut keys = [&0; 10];
+    /// let len = map.fill_keys(&mut keys);
+    /// assert_eq!(keys[..len], [&1, &2, &3]);
+    ///
+    /// let mut first_two = [&0; 2];
+    /// assert_eq!(map.fill_keys(&mut first_two), 2);
+    /// assert_eq!(first_two, [&1, &2]);
+    /// ```
+    pub fn fill_keys<'a>(&'a self, out: &mut [&'a K]) -> usize {
+        out.iter_mut()
+            .zip(self.keys())
+            .map(|(slot, key)| *slot = key)
+            .count()
+    }
+
+    /// Writes references to the map's values, in order by key, into a caller-provided buffer.
+    /// Returns the number of references written: at most `out.len()`, the remainder of `out` is left untouched.
+    ///
+    /// Like other `&self` methods, this doesn't reorder the backing arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = vec![(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    ///
+    /// let mut values = [&' '; 10];
+    /// let len = map.fill_values(&mut values);
+    /// assert_eq!(values[..len], [&'a', &'b', &'c']);
+    /// ```
+    pub fn fill_values<'a>(&'a self, out: &mut [&'a V]) -> usize {
+        out.iter_mut()
+            .zip(self.values())
+            .map(|(slot, val)| *slot = val)
+            .count()
+    }
+
+    /// Writes references to the map's key-value pairs, in ascending key order, into a caller-provided buffer.
+    /// Returns the number of pairs written: at most `out.len()`, the remainder of `out` is left untouched.
+    ///
+    /// Like other `&self` methods, this doesn't reorder the backing arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = vec![(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    ///
+    /// let mut entries = [(&0, &' '); 10];
+    /// let len = map.fill_entries(&mut entries);
+    /// assert_eq!(entries[..len], [(&1, &'a'), (&2, &'b'), (&3, &'c')]);
+    /// ```
+    pub fn fill_entries<'a>(&'a self, out: &mut [(&'a K, &'a V)]) -> usize {
+        out.iter_mut()
+            .zip(self.iter())
+            .map(|(slot, pair)| *slot = pair)
+            .count()
+    }
+
+    /// Moves the map's pairs, in order by key, into a fixed-size stack array, consuming the map.
+    /// Returns the array and the number of pairs moved into it.
+    ///
+    /// The first `len` array slots hold the map's pairs, sorted by key.
+    /// The unused tail, slots `len..N`, is padded with `(K::default(), V::default())`.
+    /// Nothing is cloned, this is the consuming counterpart to [`to_array_vec`][SgMap::to_array_vec].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 4>::new();
+    /// map.insert(2, "b".to_string());
+    /// map.insert(1, "a".to_string());
+    ///
+    /// let (pairs, len) = map.into_sorted_array();
+    /// assert_eq!(len, 2);
+    /// assert_eq!(pairs[..len], [(1, "a".to_string()), (2, "b".to_string())]);
+    ///
+    /// // Padding
+    /// assert_eq!(pairs[len..], [(0, String::new()), (0, String::new())]);
+    /// ```
+    pub fn into_sorted_array(self) -> ([(K, V); N], usize)
+    where
+        K: Default,
+        V: Default,
+    {
+        let pairs: ArrayVec<[(K, V); N]> = self.into_iter().collect();
+        let len = pairs.len();
+        (pairs.into_inner(), len)
+    }
+
+    /// Returns a new map with the same keys and each value transformed by `f`.
+    /// `f` is called once per entry, but in physical (not key) order.
+    ///
+    /// The new map is a structural copy: no insertions or rebalancing are performed,
+    /// and the rebalance parameter is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+    /// let doubled: SgMap<_, String, 10> = map.map_values(|v| (v * 2).to_string());
+    ///
+    /// assert!(doubled.into_iter().eq(vec![
+    ///     ("a", "2".to_string()),
+    ///     ("b", "4".to_string()),
+    ///     ("c", "6".to_string())
+    /// ]));
+    /// ```
+    pub fn map_values<B, F>(&self, f: F) -> SgMap<K, B, N>
+    where
+        K: Clone,
+        F: FnMut(&V) -> B,
+    {
+        SgMap {
+            bst: self.bst.map_values(f),
+        }
+    }
+
+    /// Consumes the map, returning one with each key transformed by `f` and the same values.
+    /// `f` is called once per entry, in ascending key order.
+    ///
+    /// `f` must be strictly monotonic (e.g. adding a constant offset), so that key order is preserved.
+    /// The new map is then a structural copy: no sorting, insertions, or rebalancing are performed,
+    /// and the rebalance parameter is preserved.
+    ///
+    /// # Panics
+    ///
+    /// If `debug_assertions` are enabled, panics if `f` isn't strictly monotonic.
+    /// Otherwise a non-monotonic `f` leaves the returned map in an unspecified (but memory-safe) state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<u32, &str, 10> = vec![(1, "a"), (2, "b"), (5, "c")].into_iter().collect();
+    /// let shifted: SgMap<u64, &str, 10> = map.map_keys_monotonic(|k| u64::from(k) + 100);
+    ///
+    /// assert!(shifted.into_iter().eq(vec![(101, "a"), (102, "b"), (105, "c")]));
+    /// ```
+    pub fn map_keys_monotonic<B, F>(self, f: F) -> SgMap<B, V, N>
+    where
+        K: Ord,
+        B: Ord,
+        F: FnMut(K) -> B,
+    {
+        SgMap {
+            bst: self.bst.map_keys_monotonic(f),
+        }
+    }
+
+    /// Panics if internal invariants don't hold: key ordering, a single parent per node,
+    /// and cached min/max pointing at the extremes.
+    /// A correctness aid for downstream tests, compiled out if `debug_assertions` are disabled.
+    ///
+    /// Requires the `debug_checks` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// map.debug_assert_valid();
+    /// ```
+    #[cfg(feature = "debug_checks")]
+    pub fn debug_assert_valid(&self) {
+        self.bst.debug_assert_valid();
+    }
+
+    /// Checks internal invariants without panicking: every node link is in-bounds and occupied,
+    /// every node is reachable exactly once (no cycles), keys are ordered, and the cached length and min/max are correct.
+    /// Unlike `debug_assert_valid`, this check is always available and never compiled out.
+    ///
+    /// Returns [`SgError::CorruptState`] on failure.
+    /// Any map built through this API should always pass, this check is a defense for state of unknown provenance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert!(map.verify().is_ok());
+    /// ```
+    pub fn verify(&self) -> Result<(), SgError> {
+        self.bst.verify()
+    }
+
+    /// Assemble a map from raw, unvalidated parts. Only exposed to the fuzzing harness.
+    #[cfg(fuzzing)]
+    pub fn from_raw_parts(
+        arena: crate::tree::Arena<K, V, Idx, N>,
+        opt_root_idx: Option<usize>,
+        min_idx: usize,
+        max_idx: usize,
+        curr_size: usize,
+    ) -> Self {
+        SgMap {
+            bst: SgTree::from_raw_parts(arena, opt_root_idx, min_idx, max_idx, curr_size),
+        }
+    }
+
+    /// Gets an iterator over the keys of the map, in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(2, "b");
+    /// a.insert(1, "a");
+    ///
+    /// let keys: Vec<_> = a.keys().cloned().collect();
+    /// assert_eq!(keys, [1, 2]);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V, N> {
+        Keys { inner: self.iter() }
+    }
+
+    /// Creates a consuming iterator visiting all the keys, in sorted order.
+    /// The map cannot be used after calling this.
+    /// The iterator element type is `K`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(2, "b");
+    /// a.insert(1, "a");
+    ///
+    /// let keys: Vec<i32> = a.into_keys().collect();
+    /// assert_eq!(keys, [1, 2]);
+    /// ```
+    pub fn into_keys(self) -> IntoKeys<K, V, N> {
+        IntoKeys {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// Gets an iterator over the values of the map, in order by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "hello");
+    /// a.insert(2, "goodbye");
+    ///
+    /// let values: Vec<&str> = a.values().cloned().collect();
+    /// assert_eq!(values, ["hello", "goodbye"]);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V, N> {
+        Values { inner: self.iter() }
+    }
+
+    /// Creates a consuming iterator visiting all the values, in order by key.
+    /// The map cannot be used after calling this.
+    /// The iterator element type is `V`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "hello");
+    /// a.insert(2, "goodbye");
+    ///
+    /// let values: Vec<&str> = a.into_values().collect();
+    /// assert_eq!(values, ["hello", "goodbye"]);
+    /// ```
+    pub fn into_values(self) -> IntoValues<K, V, N> {
+        IntoValues {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// Gets a mutable iterator over the values of the map, in order by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, String::from("hello"));
+    /// a.insert(2, String::from("goodbye"));
+    ///
+    /// for value in a.values_mut() {
+    ///     value.push_str("!");
+    /// }
+    ///
+    /// let values: Vec<String> = a.values().cloned().collect();
+    /// assert_eq!(values, [String::from("hello!"),
+    ///                     String::from("goodbye!")]);
+    /// ```
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V, N> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+
+    /// Returns the number of keys present in both `self` and `other`, values are ignored.
+    /// Computed in a single merge pass, `O(n + m)`, without allocation.
+    ///
+    /// Useful for checking if an [`append`][SgMap::append] will fit in a given capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.insert(2, "b");
+    /// a.insert(3, "c");
+    ///
+    /// let mut b = SgMap::<_, _, 10>::new();
+    /// b.insert(3, "d");
+    /// b.insert(4, "e");
+    ///
+    /// assert_eq!(a.common_key_count(&b), 1);
+    /// assert!(a.len() + b.len() - a.common_key_count(&b) <= a.capacity());
+    /// ```
+    pub fn common_key_count(&self, other: &SgMap<K, V, N>) -> usize {
+        self.bst.intersect_cnt(&other.bst)
+    }
+
+    /// Moves all elements from `other` into `self`, leaving `other` empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.insert(2, "b");
+    /// a.insert(3, "c");
+    ///
+    /// let mut b = SgMap::<_, _, 10>::new();
+    /// b.insert(3, "d");
+    /// b.insert(4, "e");
+    /// b.insert(5, "f");
+    ///
+    /// a.append(&mut b);
+    ///
+    /// assert_eq!(a.len(), 5);
+    /// assert_eq!(b.len(), 0);
+    ///
+    /// assert_eq!(a[&1], "a");
+    /// assert_eq!(a[&2], "b");
+    /// assert_eq!(a[&3], "d");
+    /// assert_eq!(a[&4], "e");
+    /// assert_eq!(a[&5], "f");
+    /// ```
+    pub fn append(&mut self, other: &mut SgMap<K, V, N>) {
+        self.bst.append(&mut other.bst);
+    }
+
+    /// Moves all elements from `other` into `self`, leaving `other` empty.
+    /// Unlike [`append`][SgMap::append], where `other`'s value wins, duplicate keys are merged:
+    /// the stored value becomes `resolve(&key, self_val, other_val)` and `self`'s key is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if stack capacity is exceeded.
+    /// If `resolve` panics, the conflicting key is dropped from both maps. Both stay valid, with any elements not
+    /// yet moved left in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a: SgMap<_, _, 10> = vec![("apple", 2), ("pear", 1)].into_iter().collect();
+    /// let mut b: SgMap<_, _, 10> = vec![("pear", 4), ("plum", 3)].into_iter().collect();
+    ///
+    /// // Sum counters
+    /// a.append_with(&mut b, |_, a_cnt, b_cnt| a_cnt + b_cnt);
+    ///
+    /// assert!(b.is_empty());
+    /// assert!(a.into_iter().eq(vec![("apple", 2), ("pear", 5), ("plum", 3)]));
+    /// ```
+    pub fn append_with<F>(&mut self, other: &mut SgMap<K, V, N>, resolve: F)
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        self.bst.append_with(&mut other.bst, resolve);
+    }
+
+    /// Attempts to move all elements from `other` into `self`, leaving `other` empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::iter::FromIterator;
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.try_insert(1, "a").is_ok();
+    /// a.try_insert(2, "b").is_ok();
+    /// a.try_insert(3, "c").is_ok();
+    ///
+    /// let mut b = SgMap::<_, _, 10>::new();
+    /// b.try_insert(3, "d").is_ok(); // Overwrite previous
+    /// b.try_insert(4, "e").is_ok();
+    /// b.try_insert(5, "f").is_ok();
+    ///
+    /// // Successful append
+    /// assert!(a.try_append(&mut b).is_ok());
+    ///
+    /// // Elements moved
+    /// assert_eq!(a.len(), 5);
+    /// assert_eq!(b.len(), 0);
+    ///
+    /// assert_eq!(a[&1], "a");
+    /// assert_eq!(a[&2], "b");
+    /// assert_eq!(a[&3], "d");
+    /// assert_eq!(a[&4], "e");
+    /// assert_eq!(a[&5], "f");
+    ///
+    /// // Fill remaining capacity
+    /// let mut key = 6;
+    /// while a.len() < a.capacity() {
+    ///     assert!(a.try_insert(key, "filler").is_ok());
+    ///     key += 1;
+    /// }
+    ///
+    /// // Full
+    /// assert!(a.is_full());
+    ///
+    /// // More data
+    /// let mut c = SgMap::<_, _, 10>::from_iter([(11, "k"), (12, "l")]);
+    /// let mut d = SgMap::<_, _, 10>::from_iter([(1, "a2"), (2, "b2")]);
+    ///
+    /// // Cannot append new pairs
+    /// assert_eq!(a.try_append(&mut c), Err(SgError::StackCapacityExceeded));
+    ///
+    /// // Can still replace existing pairs
+    /// assert!(a.try_append(&mut d).is_ok());
+    /// ```
+    pub fn try_append(&mut self, other: &mut SgMap<K, V, N>) -> Result<(), SgError> {
+        self.bst.try_append(&mut other.bst)
+    }
+
+    /// Moves all pairs from an iterator into `self`.
+    /// If a key is already present, or repeats within the iterator, the last value wins.
+    /// Unlike [`append`][SgMap::append], doesn't require a second map instance (and its stack space).
+    ///
+    /// # Panics
+    ///
+    /// Panics if stack capacity is exceeded. See [`try_append_iter`][SgMap::try_append_iter] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::iter::FromIterator;
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b"), (3, "c")]);
+    ///
+    /// a.append_iter([(3, "d"), (4, "e"), (4, "f")]);
+    ///
+    /// assert_eq!(a.len(), 4);
+    /// assert_eq!(a[&3], "d");
+    /// assert_eq!(a[&4], "f");
+    /// ```
+    pub fn append_iter<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.bst.append_iter(iter);
+    }
+
+    /// Attempts to move all pairs from an iterator into `self`.
+    /// If a key is already present, or repeats within the iterator, the last value wins.
+    ///
+    /// Returns `Err` at the first pair that can't fit. Since the iterator is consumed as it's merged,
+    /// pairs preceding the failure remain in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::iter::FromIterator;
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut a = SgMap::<_, _, 3>::from_iter([(1, "a"), (2, "b")]);
+    ///
+    /// // Replacing existing pairs always fits
+    /// assert!(a.try_append_iter([(1, "c"), (2, "d")]).is_ok());
+    ///
+    /// // Only one new pair fits
+    /// assert_eq!(
+    ///     a.try_append_iter([(3, "e"), (4, "f")]),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// assert_eq!(a.len(), 3);
+    /// assert_eq!(a[&1], "c");
+    /// assert_eq!(a[&3], "e");
+    /// ```
+    pub fn try_append_iter<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), SgError> {
+        self.bst.try_append_iter(iter)
+    }
+
+    /// Insert a key-value pair into the map.
+    /// If the map did not have this key present, `None` is returned.
+    /// If the map did have this key present, the value is updated, the old value is returned,
+    /// and the key is updated. This accommodates types that can be `==` without being identical.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a new key is inserted into a full map. See [`try_insert`][SgMap::try_insert] for a fallible alternative,
+    /// available in all configurations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.is_empty(), false);
+    ///
+    /// map.insert(37, "b");
+    /// assert_eq!(map.insert(37, "c"), Some("b"));
+    /// assert_eq!(map[&37], "c");
+    /// ```
+    pub fn insert(&mut self, key: K, val: V) -> Option<V>
+    where
+        K: Ord,
+    {
+        self.bst.insert(key, val)
+    }
+
+    /// Insert a key-value pair into the map.
+    /// Returns `Err` if the operation can't be completed, else the `Ok` contains:
+    /// * `None` if the map did not have this key present.
+    /// * The old value if the map did have this key present (both the value and key are updated,
+    /// this accommodates types that can be `==` without being identical).
+    ///
+    /// ### Warning
+    ///
+    /// Unlike other APIs in this crate, the semantics and return type of this API are *NOT* the same as `BTreeMap`'s nightly [`try_insert`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.try_insert).
+    /// For an equivalent, use [`try_insert_std`][`SgMap::try_insert_std`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    ///
+    /// // Add a new pair
+    /// assert_eq!(map.try_insert(37, "a"), Ok(None));
+    /// assert_eq!(map.is_empty(), false);
+    ///
+    /// // Replace existing pair
+    /// map.insert(37, "b");
+    /// assert_eq!(map.try_insert(37, "c"), Ok(Some("b")));
+    /// assert_eq!(map[&37], "c");
+    ///
+    /// // Fill remaining capacity
+    /// let mut key = 38;
+    /// while map.len() < map.capacity() {
+    ///     assert!(map.try_insert(key, "filler").is_ok());
+    ///     key += 1;
+    /// }
+    ///
+    /// // Full
+    /// assert!(map.is_full());
+    ///
+    /// // Cannot insert new pair
+    /// assert_eq!(map.try_insert(key, "out of bounds"), Err(SgError::StackCapacityExceeded));
+    ///
+    /// // Can still replace existing pair
+    /// assert_eq!(map.try_insert(key - 1, "overwrite filler"), Ok(Some("filler")));
+    /// ```
+    ///
+    pub fn try_insert(&mut self, key: K, val: V) -> Result<Option<V>, SgError>
+    where
+        K: Ord,
+    {
+        self.bst.try_insert(key, val)
+    }
+
+    /// Tries to insert a key-value pair into the map, and returns
+    /// a mutable reference to the value in the entry.
+    ///
+    /// If the map already had this key present, nothing is updated, and
+    /// an error containing the occupied entry and the value is returned.
+    ///
+    /// ### Warning
+    ///
+    /// The semantics and return type of this API match `BTreeMap`'s nightly [`try_insert`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.try_insert), *NOT* the other `try_*` APIs in this crate.
+    /// For a fallible insert, use [`try_insert`][`SgMap::try_insert`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.try_insert_std(37, "a").unwrap(), &"a");
+    ///
+    /// let err = map.try_insert_std(37, "b").unwrap_err();
+    /// assert_eq!(err.entry.key(), &37);
+    /// assert_eq!(err.entry.get(), &"a");
+    /// assert_eq!(err.value, "b");
+    ///
+    /// // Rejected value handed back, stored pair untouched
+    /// assert!(map.try_insert_std(37, "c").is_err());
+    /// assert_eq!(map[&37], "a");
+    ///
+    /// // Caller can still choose to overwrite via the returned entry
+    /// let err = map.try_insert_std(37, "c").unwrap_err();
+    /// *err.entry.into_mut() = err.value;
+    /// assert_eq!(map[&37], "c");
+    /// ```
+    #[doc(alias = "try_insert_vacant")]
+    pub fn try_insert_std(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, N>>
+    where
+        K: Ord,
+    {
+        match self.entry(key) {
+            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
+        }
+    }
+
+    /// Inserts each key-value pair, in array order, returning the previous value for each.
+    ///
+    /// Semantics match calling [`insert`][crate::map::SgMap::insert] in a loop:
+    /// the last pair wins for duplicate keys, and the key is updated too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(2, "x");
+    ///
+    /// let prev = map.insert_multi([(1, "a"), (2, "b"), (1, "c")]);
+    /// assert_eq!(prev, [None, Some("x"), Some("a")]);
+    /// assert_eq!(map[&1], "c");
+    /// assert_eq!(map[&2], "b");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a new key is inserted into a full map.
+    pub fn insert_multi<const M: usize>(&mut self, entries: [(K, V); M]) -> [Option<V>; M]
+    where
+        K: Ord,
+    {
+        entries.map(|(k, v)| self.insert(k, v))
+    }
+
+    /// Attempts to insert each key-value pair, in array order, returning the previous value for each.
+    ///
+    /// Stops at the first pair that doesn't fit, returning its array index alongside the error.
+    /// Pairs before that index remain inserted, pairs from that index onward are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 2>::new();
+    /// assert_eq!(map.try_insert_multi([(1, "a"), (1, "b")]), Ok([None, Some("a")]));
+    /// assert_eq!(
+    ///     map.try_insert_multi([(2, "c"), (3, "d"), (4, "e")]),
+    ///     Err((1, SgError::StackCapacityExceeded))
+    /// );
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&2], "c");
+    /// ```
+    pub fn try_insert_multi<const M: usize>(
+        &mut self,
+        entries: [(K, V); M],
+    ) -> Result<[Option<V>; M], (usize, SgError)>
+    where
+        K: Ord,
+    {
+        let mut idx = 0;
+        let mut opt_err = None;
+
+        let prev_vals = entries.map(|(k, v)| {
+            if opt_err.is_none() {
+                match self.try_insert(k, v) {
+                    Ok(opt_val) => {
+                        idx += 1;
+                        return opt_val;
+                    }
+                    Err(err) => opt_err = Some((idx, err)),
+                }
+            }
+            None
+        });
+
+        match opt_err {
+            Some(err) => Err(err),
+            None => Ok(prev_vals),
+        }
+    }
+
+    /// Attempt to extend a collection with the contents of an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::iter::FromIterator;
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut a = SgMap::<_, _, 2>::new();
     /// let mut b = SgMap::<_, _, 3>::from_iter([(1, "a"), (2, "b"), (3, "c")]);
     /// let mut c = SgMap::<_, _, 2>::from_iter([(1, "a"), (2, "b")]);
     ///
-    /// // Too big
-    /// assert_eq!(a.try_extend(b.into_iter()), Err(SgError::StackCapacityExceeded));
+    /// // Too big
+    /// assert_eq!(a.try_extend(b.into_iter()), Err(SgError::StackCapacityExceeded));
+    ///
+    /// // Fits
+    /// assert!(a.try_extend(c.into_iter()).is_ok());
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// There is no `TryExtend` trait in `core`/`std`.
+    pub fn try_extend<I: ExactSizeIterator + IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), SgError> {
+        self.bst.try_extend(iter)
+    }
+
+    /// Attempt conversion from an iterator.
+    /// Will fail if iterator length exceeds `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// const CAPACITY_1: usize = 1_000;
+    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_1).map(|n|(n, n)).collect();
+    /// assert!(SgMap::<usize, usize, CAPACITY_1>::try_from_iter(vec.into_iter()).is_ok());
+    ///
+    /// const CAPACITY_2: usize = (u16::MAX as usize) + 1;
+    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_2).map(|n|(n, n)).collect();
+    /// assert_eq!(
+    ///     SgMap::<usize, usize, CAPACITY_2>::try_from_iter(vec.into_iter()),
+    ///     Err(SgError::MaximumCapacityExceeded)
+    /// );
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// There is no `TryFromIterator` trait in `core`/`std`.
+    pub fn try_from_iter<I: ExactSizeIterator + IntoIterator<Item = (K, V)>>(
+        iter: I,
+    ) -> Result<Self, SgError> {
+        match iter.len() <= SgTree::<K, V, N>::max_capacity() {
+            true => Ok(SgMap::from_iter(iter)),
+            false => Err(SgError::MaximumCapacityExceeded),
+        }
+    }
+
+    /// Lossy conversion from an iterator: fills the map to capacity, never panics.
+    /// Returns the map and the number of items dropped because the map was full.
+    ///
+    /// Unlike [`try_from_iter`][crate::map::SgMap::try_from_iter], overflow isn't an error.
+    /// Items whose key is already present still update the map once it's full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let (map, dropped) = SgMap::<_, _, 3>::from_iter_capped((0..5).map(|n| (n, n * 10)));
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(dropped, 2);
+    /// assert_eq!(map.last_key_value(), Some((&2, &20)));
+    ///
+    /// let (map, dropped) = SgMap::<_, _, 3>::from_iter_capped([(1, "a"), (2, "b")]);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(dropped, 0);
+    /// ```
+    pub fn from_iter_capped<I: IntoIterator<Item = (K, V)>>(iter: I) -> (Self, usize) {
+        let (bst, dropped_cnt) = SgTree::from_iter_capped(iter);
+        (SgMap { bst }, dropped_cnt)
+    }
+
+    /// Gets an iterator over the entries of the map, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(3, "c");
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// for (key, value) in map.iter() {
+    ///     println!("{}: {}", key, value);
+    /// }
+    ///
+    /// let (first_key, first_value) = map.iter().next().unwrap();
+    /// assert_eq!((*first_key, *first_value), (1, "a"));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V, N> {
+        Iter::new(self)
+    }
+
+    /// Gets a mutable iterator over the entries of the map, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// map.insert("c", 3);
+    ///
+    /// // Add 10 to the value if the key isn't "a"
+    /// for (key, value) in map.iter_mut() {
+    ///     if key != &"a" {
+    ///         *value += 10;
+    ///     }
+    /// }
+    ///
+    /// let (second_key, second_value) = map.iter().skip(1).next().unwrap();
+    /// assert_eq!((*second_key, *second_value), ("b", 12));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V, N> {
+        IterMut::new(self)
+    }
+
+    /// Walks the map once in ascending key order, threading state through `f` and allowing it to mutate values.
+    /// Returns the final state.
+    ///
+    /// Each entry is visited exactly once, after every entry with a smaller key,
+    /// so `f` can carry ordered accumulations (e.g. running totals) forward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::from([(1, 3), (2, 1), (3, 4), (4, 1), (5, 5)]);
+    ///
+    /// // In-place prefix sum
+    /// let total = map.scan_mut(0, |sum, _, v| {
+    ///     *sum += *v;
+    ///     *v = *sum;
+    /// });
+    ///
+    /// assert_eq!(total, 14);
+    /// assert!(map.into_values().eq([3, 4, 8, 9, 14]));
+    /// ```
+    pub fn scan_mut<S, F>(&mut self, init: S, mut f: F) -> S
+    where
+        F: FnMut(&mut S, &K, &mut V),
+    {
+        let mut state = init;
+        for (k, v) in self.iter_mut() {
+            f(&mut state, k, v);
+        }
+        state
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the key
+    /// was previously in the map.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// assert_eq!(map.remove_entry(&1), None);
+    /// ```
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove_entry(key)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
+    /// The elements are visited in ascending key order.
+    /// Kept elements are never moved or copied, so mutations the predicate makes to their values persist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Keep only the elements with even-numbered keys.
+    /// map.retain(|&k, _| k % 2 == 0);
+    /// assert!(map.iter().eq(vec![(&0, &0), (&2, &20), (&4, &40), (&6, &60)]));
+    ///
+    /// // Increment every value, keeping all elements.
+    /// map.retain(|_, v| {
+    ///     *v += 1;
+    ///     true
+    /// });
+    /// assert!(map.into_iter().eq(vec![(0, 1), (2, 21), (4, 41), (6, 61)]));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.bst.retain(|k, v| f(k, v));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to each value.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
+    /// The elements are visited in ascending key order.
+    /// Mutations made to the values of retained elements persist, so the predicate can transform
+    /// a value in-place and then decide removal based on the transformed value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Halve every value, keep only the results divisible by 10.
+    /// map.retain_mut(|_, v| {
+    ///     *v /= 2;
+    ///     *v % 10 == 0
+    /// });
+    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 10), (4, 20), (6, 30)]));
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.bst.retain(f);
+    }
+
+    /// Retains only the elements specified by the predicate, which also receives each element's position.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(i, &k, &mut v)` returns `false`,
+    /// where `i` is the pair's 0-based position in ascending key order (counting removed pairs too).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x * 2, x)).collect();
+    /// // Keep even-positioned entries
+    /// map.retain_indexed(|i, _, _| i % 2 == 0);
+    /// assert!(map.into_iter().eq(vec![(0, 0), (4, 2), (8, 4), (12, 6)]));
+    /// ```
+    pub fn retain_indexed<F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(usize, &K, &mut V) -> bool,
+    {
+        let mut pos = 0;
+        self.bst.retain(|k, v| {
+            let keep = f(pos, k, v);
+            pos += 1;
+            keep
+        });
+    }
+
+    /// Retains only the elements specified by the predicate, visiting them in *descending* key order.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
+    /// Useful when the predicate is stateful and larger keys should be evaluated first,
+    /// e.g. to keep only the largest entries. [`retain`][SgMap::retain] visits in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Keep the 3 largest keys
+    /// let mut budget = 3;
+    /// map.retain_rev(|_, _| {
+    ///     budget -= 1;
+    ///     budget >= 0
+    /// });
+    /// assert!(map.into_iter().eq(vec![(5, 50), (6, 60), (7, 70)]));
+    /// ```
+    pub fn retain_rev<F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.bst.retain_rev(f);
+    }
+
+    /// Retains only the elements specified by the predicate, stopping early once it breaks.
+    ///
+    /// Elements are visited in ascending key order. For each, `f` receives the key, a mutable reference
+    /// to the value, and the number of elements kept so far. Returning `ControlFlow::Continue(true)` keeps
+    /// the element, `ControlFlow::Continue(false)` removes it, and `ControlFlow::Break(())` stops the scan.
+    /// The element `f` breaks on, and every element after it, is kept without being visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Drop odd keys, but only until three even keys have been kept.
+    /// map.retain_while(|&k, _, kept| {
+    ///     if kept == 3 {
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(k % 2 == 0)
+    ///     }
+    /// });
+    /// assert!(map.into_keys().eq(vec![0, 2, 4, 5, 6, 7]));
+    /// ```
+    pub fn retain_while<F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V, usize) -> ControlFlow<(), bool>,
+    {
+        self.bst.retain_while(f);
+    }
+
+    /// Retains only the entries whose keys are present in `keys`.
+    ///
+    /// Walks the map and the set together in sorted order, so membership is resolved in `O(n + m)`
+    /// comparisons rather than a per-entry lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgSet};
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// let allow: SgSet<i32, 5> = vec![1, 3, 5, 9].into_iter().collect();
+    ///
+    /// map.retain_in(&allow);
+    /// assert!(map.into_iter().eq(vec![(1, 10), (3, 30), (5, 50)]));
+    /// ```
+    pub fn retain_in<const M: usize>(&mut self, keys: &SgSet<K, M>)
+    where
+        K: Ord,
+    {
+        let mut keys_iter = keys.iter().peekable();
+        self.bst.retain(|k, _| {
+            while keys_iter.next_if(|other| *other < k).is_some() {}
+            keys_iter.peek() == Some(&k)
+        });
+    }
+
+    /// Removes all entries whose keys are present in `keys`.
+    ///
+    /// Walks the map and the set together in sorted order, so membership is resolved in `O(n + m)`
+    /// comparisons rather than a per-entry lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgSet};
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// let deny: SgSet<i32, 5> = vec![1, 3, 5, 9].into_iter().collect();
+    ///
+    /// map.remove_in(&deny);
+    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60), (7, 70)]));
+    /// ```
+    pub fn remove_in<const M: usize>(&mut self, keys: &SgSet<K, M>)
+    where
+        K: Ord,
+    {
+        let mut keys_iter = keys.iter().peekable();
+        self.bst.retain(|k, _| {
+            while keys_iter.next_if(|other| *other < k).is_some() {}
+            keys_iter.peek() != Some(&k)
+        });
+    }
+
+    /// Merges adjacent entries, visiting them in ascending key order.
+    ///
+    /// For each adjacent pair, `f(&k1, &mut v1, &k2, &v2)` is called. If it returns `true`,
+    /// `f` is expected to have folded the second entry into the first's value,
+    /// and the second entry is removed. The surviving entry is then compared against the next one,
+    /// so runs of mergeable entries collapse into their first entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// // Intervals stored as `start -> end`
+    /// let mut intervals: SgMap<u32, u32, 10> =
+    ///     vec![(1, 3), (2, 6), (8, 10), (10, 12), (15, 18)].into_iter().collect();
+    ///
+    /// // Merge overlapping or touching intervals
+    /// intervals.coalesce(|_, end, next_start, next_end| {
+    ///     if *next_start <= *end {
+    ///         *end = (*end).max(*next_end);
+    ///         true
+    ///     } else {
+    ///         false
+    ///     }
+    /// });
+    ///
+    /// assert!(intervals.into_iter().eq(vec![(1, 6), (8, 12), (15, 18)]));
+    /// ```
+    pub fn coalesce<F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V, &K, &V) -> bool,
+    {
+        self.bst.coalesce(f);
+    }
+
+    /// Splits the collection into two at the given key. Returns everything after the given key,
+    /// including the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.insert(2, "b");
+    /// a.insert(3, "c");
+    /// a.insert(17, "d");
+    /// a.insert(41, "e");
+    ///
+    /// let b = a.split_off(&3);
+    ///
+    /// assert_eq!(a.len(), 2);
+    /// assert_eq!(b.len(), 3);
+    ///
+    /// assert_eq!(a[&1], "a");
+    /// assert_eq!(a[&2], "b");
+    ///
+    /// assert_eq!(b[&3], "c");
+    /// assert_eq!(b[&17], "d");
+    /// assert_eq!(b[&41], "e");
+    /// ```
+    pub fn split_off<Q>(&mut self, key: &Q) -> SgMap<K, V, N>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        SgMap {
+            bst: self.bst.split_off(key),
+        }
+    }
+
+    /// Consumes the map, splitting it into two at the given key.
+    /// Returns everything before the given key, and everything after it (including the key).
+    ///
+    /// # Examples
     ///
-    /// // Fits
-    /// assert!(a.try_extend(c.into_iter()).is_ok());
     /// ```
+    /// use scapegoat::SgMap;
     ///
-    /// ### Note
+    /// let map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    /// let len = map.len();
     ///
-    /// There is no `TryExtend` trait in `core`/`std`.
-    pub fn try_extend<I: ExactSizeIterator + IntoIterator<Item = (K, V)>>(
-        &mut self,
-        iter: I,
-    ) -> Result<(), SgError> {
-        self.bst.try_extend(iter)
+    /// let (lo, hi) = map.split_at_key(&3);
+    ///
+    /// assert_eq!(lo.len() + hi.len(), len);
+    /// assert!(lo.keys().eq([0, 1, 2].iter()));
+    /// assert!(hi.keys().eq([3, 4, 5, 6, 7].iter()));
+    /// ```
+    pub fn split_at_key<Q>(mut self, key: &Q) -> (SgMap<K, V, N>, SgMap<K, V, N>)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let hi = self.split_off(key);
+        (self, hi)
     }
 
-    /// Attempt conversion from an iterator.
-    /// Will fail if iterator length exceeds `u16::MAX`.
+    /// Removes all entries for which `f` returns `true`, returning them as a new map.
+    /// Entries for which `f` returns `false` remain in `self`. The rebalance parameter is preserved.
+    ///
+    /// Like [`partition`][SgMap::partition], but in-place on `self`.
+    /// Unlike a lazy `drain_filter`/`extract_if` iterator, this is eager: `f` is called on every entry,
+    /// in ascending key order, before the method returns, even if the returned map is dropped unused.
     ///
     /// # Examples
     ///
     /// ```
-    /// use scapegoat::{SgMap, SgError};
+    /// use scapegoat::SgMap;
     ///
-    /// const CAPACITY_1: usize = 1_000;
-    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_1).map(|n|(n, n)).collect();
-    /// assert!(SgMap::<usize, usize, CAPACITY_1>::try_from_iter(vec.into_iter()).is_ok());
+    /// let mut map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
     ///
-    /// const CAPACITY_2: usize = (u16::MAX as usize) + 1;
-    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_2).map(|n|(n, n)).collect();
-    /// assert_eq!(
-    ///     SgMap::<usize, usize, CAPACITY_2>::try_from_iter(vec.into_iter()),
-    ///     Err(SgError::MaximumCapacityExceeded)
-    /// );
+    /// let odd = map.split_by(|k, _| k % 2 == 1);
+    ///
+    /// assert!(map.keys().eq([0, 2, 4, 6].iter()));
+    /// assert!(odd.values().eq([10, 30, 50, 70].iter()));
     /// ```
+    pub fn split_by<F>(&mut self, f: F) -> SgMap<K, V, N>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        SgMap {
+            bst: self.bst.split_by(f),
+        }
+    }
+
+    /// Consumes the map, splitting it into two: entries for which `f` returns `true`, and the rest.
+    /// Like [`Iterator::partition`], but each half is a map of capacity `N`, in key order and balanced.
+    /// The rebalance parameter is preserved.
     ///
-    /// ### Note
+    /// Unlike [`retain`][SgMap::retain], both halves are kept.
     ///
-    /// There is no `TryFromIterator` trait in `core`/`std`.
-    pub fn try_from_iter<I: ExactSizeIterator + IntoIterator<Item = (K, V)>>(
-        iter: I,
-    ) -> Result<Self, SgError> {
-        match iter.len() <= SgTree::<K, V, N>::max_capacity() {
-            true => Ok(SgMap::from_iter(iter)),
-            false => Err(SgError::MaximumCapacityExceeded),
-        }
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    ///
+    /// let (even, odd) = map.partition(|k, _| k % 2 == 0);
+    ///
+    /// assert!(even.keys().eq([0, 2, 4, 6].iter()));
+    /// assert!(odd.values().eq([10, 30, 50, 70].iter()));
+    /// ```
+    pub fn partition<F>(self, f: F) -> (SgMap<K, V, N>, SgMap<K, V, N>)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let (matching, rest) = self.bst.partition(f);
+        (SgMap { bst: matching }, SgMap { bst: rest })
     }
 
-    /// Gets an iterator over the entries of the map, sorted by key.
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
     ///
     /// # Examples
     ///
@@ -485,22 +1816,142 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// use scapegoat::SgMap;
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(3, "c");
-    /// map.insert(2, "b");
     /// map.insert(1, "a");
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove(key)
+    }
+
+    /// Removes all pairs with keys in the given range, returning the number of pairs removed.
+    /// Only in-range pairs are visited, unlike an equivalent [`retain`][SgMap::retain].
     ///
-    /// for (key, value) in map.iter() {
-    ///     println!("{}: {}", key, value);
-    /// }
+    /// The range may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
     ///
-    /// let (first_key, first_value) = map.iter().next().unwrap();
-    /// assert_eq!((*first_key, *first_value), (1, "a"));
     /// ```
-    pub fn iter(&self) -> Iter<'_, K, V, N> {
-        Iter::new(self)
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    ///
+    /// assert_eq!(map.remove_range(2..5), 3);
+    /// assert_eq!(map.remove_range(2..5), 0);
+    /// assert!(map.keys().eq([0, 1, 5, 6, 7].iter()));
+    ///
+    /// assert_eq!(map.remove_range(6..), 2);
+    /// assert!(map.keys().eq([0, 1, 5].iter()));
+    /// ```
+    pub fn remove_range<T, R>(&mut self, range: R) -> usize
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        self.bst.remove_range(range)
     }
 
-    /// Gets a mutable iterator over the entries of the map, sorted by key.
+    /// Removes all pairs with keys yielded by the given iterator, returning the number of pairs removed.
+    /// Keys may be in any order, keys not in the map are ignored.
+    /// Unlike calling [`remove`][SgMap::remove] in a loop, the map is rebuilt at most once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    ///
+    /// assert_eq!(map.remove_keys([6, 1, 3, 1, 42]), 3);
+    /// assert!(map.keys().eq([0, 2, 4, 5, 7].iter()));
+    ///
+    /// assert_eq!(map.remove_keys(Vec::new()), 0);
+    /// assert_eq!(map.len(), 5);
+    /// ```
+    pub fn remove_keys<I>(&mut self, keys: I) -> usize
+    where
+        I: IntoIterator<Item = K>,
+    {
+        // Only keys present in the map are kept, so the scratch tree can't overflow
+        let mut sorted_keys = SgTree::<K, (), N>::new();
+        for key in keys {
+            if self.bst.contains_key(&key) {
+                sorted_keys.insert(key, ());
+            }
+        }
+
+        self.bst.bulk_remove(sorted_keys.iter().map(|(k, _)| k))
+    }
+
+    /// Removes the lowest-scoring entries until `self.len() <= target_len`, returning the number evicted.
+    /// Makes a fixed-capacity map usable as a bounded cache, e.g. evict down to a watermark once full.
+    ///
+    /// `score` is called exactly once per entry, in ascending key order. Ties are broken by key order,
+    /// so among equally-scored entries the smaller keys are evicted first.
+    /// Evicted entries are removed in a single pass, with at most one rebalance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// // Key -> hit count
+    /// let mut cache: SgMap<u32, u32, 6> = vec![(1, 5), (2, 0), (3, 9), (4, 2), (5, 7), (6, 2)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(cache.evict_until(3, |_, hits| *hits), 3);
+    /// assert!(cache.into_iter().eq(vec![(1, 5), (3, 9), (5, 7)]));
+    /// ```
+    #[doc(alias = "evict")]
+    pub fn evict_until<F>(&mut self, target_len: usize, mut score: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&K, &V) -> u32,
+    {
+        let evict_cnt = self.len().saturating_sub(target_len);
+        if evict_cnt == 0 {
+            return 0;
+        }
+
+        // Score each entry once, tagged with its in-order position
+        let mut scores: ArrayVec<[(u32, usize); N]> = self
+            .iter()
+            .enumerate()
+            .map(|(pos, (k, v))| (score(k, v), pos))
+            .collect();
+        scores.sort_unstable();
+
+        let mut evict_positions: ArrayVec<[usize; N]> =
+            scores.iter().take(evict_cnt).map(|(_, pos)| *pos).collect();
+        evict_positions.sort_unstable();
+
+        let mut evict_iter = evict_positions.into_iter().peekable();
+        let mut pos = 0;
+        self.bst.retain(|_, _| {
+            let keep = evict_iter.next_if_eq(&pos).is_none();
+            pos += 1;
+            keep
+        });
+
+        evict_cnt
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    ///
+    /// The supplied key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
     ///
     /// # Examples
     ///
@@ -508,26 +1959,42 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// use scapegoat::SgMap;
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert("a", 1);
-    /// map.insert("b", 2);
-    /// map.insert("c", 3);
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get_key_value(&1), Some((&1, &"a")));
+    /// assert_eq!(map.get_key_value(&2), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_key_value(key)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
     ///
-    /// // Add 10 to the value if the key isn't "a"
-    /// for (key, value) in map.iter_mut() {
-    ///     if key != &"a" {
-    ///         *value += 10;
-    ///     }
-    /// }
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
     ///
-    /// let (second_key, second_value) = map.iter().skip(1).next().unwrap();
-    /// assert_eq!((*second_key, *second_value), ("b", 12));
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, K, V, N> {
-        IterMut::new(self)
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get(key)
     }
 
-    /// Removes a key from the map, returning the stored key and value if the key
-    /// was previously in the map.
+    // Returns a mutable reference to the value corresponding to the key.
     ///
     /// The key may be any borrowed form of the map's key type, but the ordering
     /// on the borrowed form *must* match the ordering on the key type.
@@ -539,42 +2006,99 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
     /// map.insert(1, "a");
-    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
-    /// assert_eq!(map.remove_entry(&1), None);
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
     /// ```
-    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
     where
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
-        self.bst.remove_entry(key)
+        self.bst.get_mut(key)
     }
 
-    /// Retains only the elements specified by the predicate.
+    /// Returns the stored key and a mutable reference to the value corresponding to the supplied key.
+    /// Useful when the stored key is equal to, but distinguishable from, the lookup key.
     ///
-    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
-    /// The elements are visited in ascending key order.
+    /// The supplied key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
-    /// // Keep only the elements with even-numbered keys.
-    /// map.retain(|&k, _| k % 2 == 0);
-    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// if let Some((k, v)) = map.get_key_value_mut(&1) {
+    ///     assert_eq!(*k, 1);
+    ///     *v = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// assert_eq!(map.get_key_value_mut(&2), None);
     /// ```
-    pub fn retain<F>(&mut self, mut f: F)
+    pub fn get_key_value_mut<Q>(&mut self, key: &Q) -> Option<(&K, &mut V)>
     where
-        K: Ord,
-        F: FnMut(&K, &mut V) -> bool,
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
     {
-        self.bst.retain(|k, v| f(k, v));
+        self.bst.get_key_value_mut(key)
     }
 
-    /// Splits the collection into two at the given key. Returns everything after the given key,
-    /// including the key.
+    /// Returns mutable references to the values corresponding to two distinct keys, e.g. to swap or relate them.
+    /// Returns `None` if either key is absent, or if both keys are equal.
+    ///
+    /// The supplied keys may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    ///
+    /// if let Some((a, b)) = map.get_pair_mut("a", "b") {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(map["a"], 2);
+    /// assert_eq!(map["b"], 1);
+    ///
+    /// assert_eq!(map.get_pair_mut("a", "a"), None);
+    /// assert_eq!(map.get_pair_mut("a", "c"), None);
+    /// ```
+    pub fn get_pair_mut<Q>(&mut self, key_a: &Q, key_b: &Q) -> Option<(&mut V, &mut V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_pair_mut(key_a, key_b)
+    }
+
+    /// Clears the map, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.clear();
+    /// assert!(a.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.bst.clear()
+    }
+
+    /// Clears the map, returning all key-value pairs as an iterator, in ascending key order.
+    /// The map is left empty, ready for reuse, even if the iterator is dropped before being fully consumed.
+    ///
+    /// Unlike [`into_iter`][crate::map::SgMap::into_iter], the map itself isn't consumed.
     ///
     /// # Examples
     ///
@@ -582,40 +2106,24 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// use scapegoat::SgMap;
     ///
     /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "a");
     /// a.insert(2, "b");
-    /// a.insert(3, "c");
-    /// a.insert(17, "d");
-    /// a.insert(41, "e");
-    ///
-    /// let b = a.split_off(&3);
-    ///
-    /// assert_eq!(a.len(), 2);
-    /// assert_eq!(b.len(), 3);
+    /// a.insert(1, "a");
     ///
-    /// assert_eq!(a[&1], "a");
-    /// assert_eq!(a[&2], "b");
+    /// let drained: Vec<(usize, &str)> = a.drain().collect();
+    /// assert_eq!(drained, [(1, "a"), (2, "b")]);
+    /// assert!(a.is_empty());
     ///
-    /// assert_eq!(b[&3], "c");
-    /// assert_eq!(b[&17], "d");
-    /// assert_eq!(b[&41], "e");
+    /// a.insert(3, "c");
+    /// assert_eq!(a.len(), 1);
     /// ```
-    pub fn split_off<Q>(&mut self, key: &Q) -> SgMap<K, V, N>
-    where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
-    {
-        SgMap {
-            bst: self.bst.split_off(key),
-        }
+    pub fn drain(&mut self) -> Drain<'_, K, V, N> {
+        Drain::new(self)
     }
 
-    /// Removes a key from the map, returning the value at the key if the key
-    /// was previously in the map.
+    /// Returns `true` if the map contains a value for the specified key.
     ///
     /// The key may be any borrowed form of the map's key type, but the ordering
     /// on the borrowed form *must* match the ordering on the key type.
-    ///
     /// # Examples
     ///
     /// ```
@@ -623,21 +2131,21 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
     /// map.insert(1, "a");
-    /// assert_eq!(map.remove(&1), Some("a"));
-    /// assert_eq!(map.remove(&1), None);
+    /// assert_eq!(map.contains_key(&1), true);
+    /// assert_eq!(map.contains_key(&2), false);
     /// ```
-    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
-        self.bst.remove(key)
+        self.bst.contains_key(key)
     }
 
-    /// Returns the key-value pair corresponding to the supplied key.
+    /// Returns `true` if any entry in the map has a value equal to `val`.
     ///
-    /// The supplied key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// Unlike [`contains_key`][crate::map::SgMap::contains_key], this is a linear scan: `O(n)`.
+    /// The map is ordered by key, not by value, so there's no faster search.
     ///
     /// # Examples
     ///
@@ -646,21 +2154,20 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
     /// map.insert(1, "a");
-    /// assert_eq!(map.get_key_value(&1), Some((&1, &"a")));
-    /// assert_eq!(map.get_key_value(&2), None);
+    /// map.insert(2, "b");
+    /// assert_eq!(map.contains_value(&"b"), true);
+    /// assert_eq!(map.contains_value(&"c"), false);
     /// ```
-    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    pub fn contains_value(&self, val: &V) -> bool
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        V: PartialEq,
     {
-        self.bst.get_key_value(key)
+        self.values().any(|v| v == val)
     }
 
-    /// Returns a reference to the value corresponding to the key.
+    /// Returns the first entry, in ascending key order, whose value satisfies the predicate.
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// This is a linear scan: `O(n)`.
     ///
     /// # Examples
     ///
@@ -668,79 +2175,90 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// use scapegoat::SgMap;
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.get(&1), Some(&"a"));
-    /// assert_eq!(map.get(&2), None);
+    /// map.insert(3, 30);
+    /// map.insert(1, 10);
+    /// map.insert(2, 20);
+    /// assert_eq!(map.find_value(|&v| v > 15), Some((&2, &20)));
+    /// assert_eq!(map.find_value(|&v| v > 30), None);
     /// ```
-    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    pub fn find_value<F>(&self, mut f: F) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        F: FnMut(&V) -> bool,
     {
-        self.bst.get(key)
+        self.iter().find(|(_, v)| f(v))
     }
 
-    // Returns a mutable reference to the value corresponding to the key.
+    /// Returns `true` if both maps have the same length and every pair of entries, walked together in
+    /// ascending key order, satisfies the equivalence `f`.
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// Useful where the derived `PartialEq` is too strict, e.g. floating point values.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// if let Some(x) = map.get_mut(&1) {
-    ///     *x = "b";
-    /// }
-    /// assert_eq!(map[&1], "b");
+    /// let a: SgMap<u32, f32, 10> = [(1, 0.1), (2, 0.2)].iter().copied().collect();
+    /// let b: SgMap<u32, f32, 10> = [(1, 0.1 + 1e-7), (2, 0.2)].iter().copied().collect();
+    ///
+    /// let approx_eq = |(k1, v1): (&u32, &f32), (k2, v2): (&u32, &f32)| {
+    ///     k1 == k2 && (v1 - v2).abs() < 1e-6
+    /// };
+    ///
+    /// assert!(a.eq_by(&b, approx_eq));
+    /// assert!(!a.eq_by(&SgMap::new(), approx_eq));
     /// ```
-    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    pub fn eq_by<F>(&self, other: &Self, mut f: F) -> bool
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        F: FnMut((&K, &V), (&K, &V)) -> bool,
     {
-        self.bst.get_mut(key)
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| f(a, b))
     }
 
-    /// Clears the map, removing all elements.
+    /// Lexicographically compares the entries of both maps, walked together in ascending key order, using
+    /// the comparison function `f`.
+    ///
+    /// The first pair of entries `f` doesn't consider equal decides the result.
+    /// If one map is a prefix of the other, the shorter map is [`Ordering::Less`].
     ///
     /// # Examples
     ///
     /// ```
+    /// use core::cmp::Ordering;
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "a");
-    /// a.clear();
-    /// assert!(a.is_empty());
-    /// ```
-    pub fn clear(&mut self) {
-        self.bst.clear()
-    }
-
-    /// Returns `true` if the map contains a value for the specified key.
-    ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
-    /// # Examples
+    /// let a: SgMap<u32, f32, 10> = [(1, 0.1), (2, 0.2)].iter().copied().collect();
+    /// let b: SgMap<u32, f32, 10> = [(1, 0.1 + 1e-7), (2, 0.3)].iter().copied().collect();
     ///
-    /// ```
-    /// use scapegoat::SgMap;
+    /// let approx_cmp = |(k1, v1): (&u32, &f32), (k2, v2): (&u32, &f32)| {
+    ///     k1.cmp(k2).then(match (v1 - v2).abs() < 1e-6 {
+    ///         true => Ordering::Equal,
+    ///         false => v1.partial_cmp(v2).unwrap(),
+    ///     })
+    /// };
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.contains_key(&1), true);
-    /// assert_eq!(map.contains_key(&2), false);
+    /// assert_eq!(a.cmp_by(&b, approx_cmp), Ordering::Less);
+    /// assert_eq!(a.cmp_by(&a, approx_cmp), Ordering::Equal);
+    /// assert_eq!(a.cmp_by(&SgMap::new(), approx_cmp), Ordering::Greater);
     /// ```
-    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    pub fn cmp_by<F>(&self, other: &Self, mut f: F) -> Ordering
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        F: FnMut((&K, &V), (&K, &V)) -> Ordering,
     {
-        self.bst.contains_key(key)
+        let mut self_iter = self.iter();
+        let mut other_iter = other.iter();
+
+        loop {
+            match (self_iter.next(), other_iter.next()) {
+                (Some(a), Some(b)) => match f(a, b) {
+                    Ordering::Equal => continue,
+                    non_eq => return non_eq,
+                },
+                (Some(_), None) => return Ordering::Greater,
+                (None, Some(_)) => return Ordering::Less,
+                (None, None) => return Ordering::Equal,
+            }
+        }
     }
 
     /// Returns `true` if the map contains no elements.
@@ -881,6 +2399,60 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.last_key()
     }
 
+    /// Replaces the first/minimum entry's key and value in-place, returning the old pair.
+    /// The node is swapped via the cached location of the minimum, so no rebalance is performed.
+    /// The only traversal is an `O(log n)` descent to the minimum's successor, for the ordering check below.
+    /// If the map is empty, the pair is inserted and `Ok(None)` is returned.
+    ///
+    /// The new key must remain the minimum: it must be strictly less than every other key in the map.
+    /// Otherwise the map is left unchanged and the rejected pair is returned in `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<_, _, 10> = vec![(10, "a"), (20, "b"), (30, "c")].into_iter().collect();
+    ///
+    /// assert_eq!(map.replace_entry_at_min(5, "z"), Ok(Some((10, "a"))));
+    /// assert_eq!(map.replace_entry_at_min(20, "y"), Err((20, "y")));
+    /// assert_eq!(map.first_key_value(), Some((&5, &"z")));
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn replace_entry_at_min(&mut self, key: K, val: V) -> Result<Option<(K, V)>, (K, V)>
+    where
+        K: Ord,
+    {
+        self.bst.replace_entry_at_min(key, val)
+    }
+
+    /// Replaces the last/maximum entry's key and value in-place, returning the old pair.
+    /// The node is swapped via the cached location of the maximum, so no rebalance is performed.
+    /// The only traversal is an `O(log n)` descent to the maximum's predecessor, for the ordering check below.
+    /// If the map is empty, the pair is inserted and `Ok(None)` is returned.
+    ///
+    /// The new key must remain the maximum: it must be strictly greater than every other key in the map.
+    /// Otherwise the map is left unchanged and the rejected pair is returned in `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<_, _, 10> = vec![(10, "a"), (20, "b"), (30, "c")].into_iter().collect();
+    ///
+    /// assert_eq!(map.replace_entry_at_max(35, "z"), Ok(Some((30, "c"))));
+    /// assert_eq!(map.replace_entry_at_max(20, "y"), Err((20, "y")));
+    /// assert_eq!(map.last_key_value(), Some((&35, &"z")));
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn replace_entry_at_max(&mut self, key: K, val: V) -> Result<Option<(K, V)>, (K, V)>
+    where
+        K: Ord,
+    {
+        self.bst.replace_entry_at_max(key, val)
+    }
+
     /// Removes and returns the last element in the map.
     /// The key of this element is the maximum key that was in the map.
     ///
@@ -897,57 +2469,308 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// while let Some((key, _val)) = map.pop_last() {
     ///     assert!((&map).into_iter().all(|(k, _v)| *k < key));
     /// }
-    /// assert!(map.is_empty());
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.bst.pop_last()
+    }
+
+    /// Returns the key-value pair at the given in-order position, e.g. the `rank`-th smallest key's pair.
+    /// Returns `None` if `rank >= self.len()`. Equivalent to `self.iter().nth(rank)`.
+    ///
+    /// ### Complexity
+    ///
+    /// `O(log n)` if the [`fast_rebalance` feature](https://github.com/tnballo/scapegoat/blob/master/CONFIG.md) is enabled,
+    /// since per-node subtree sizes are maintained. Otherwise `O(rank)`, via in-order iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map = SgMap::from([(3, "c"), (1, "a"), (2, "b")]);
+    ///
+    /// assert_eq!(map.select(0), Some((&1, &"a")));
+    /// assert_eq!(map.select(2), Some((&3, &"c")));
+    /// assert_eq!(map.select(3), None);
+    /// ```
+    #[doc(alias = "nth")]
+    #[doc(alias = "nth_key_value")]
+    #[doc(alias = "get_index")]
+    pub fn select(&self, rank: usize) -> Option<(&K, &V)> {
+        self.bst.select(rank)
+    }
+
+    /// Returns the in-order position of the given key, if present.
+    /// E.g. the number of keys in the map strictly less than it.
+    /// The inverse of [`select`][SgMap::select].
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// ### Complexity
+    ///
+    /// `O(log n)` if the [`fast_rebalance` feature](https://github.com/tnballo/scapegoat/blob/master/CONFIG.md) is enabled,
+    /// since per-node subtree sizes are maintained. Otherwise `O(rank)`, via in-order iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map = SgMap::from([(30, "c"), (10, "a"), (20, "b")]);
+    ///
+    /// assert_eq!(map.rank(&10), Some(0));
+    /// assert_eq!(map.rank(&30), Some(2));
+    /// assert_eq!(map.rank(&25), None);
+    ///
+    /// assert_eq!(map.select(map.rank(&20).unwrap()), Some((&20, &"b")));
+    /// ```
+    #[doc(alias = "position")]
+    #[doc(alias = "position_of")]
+    #[doc(alias = "index_of")]
+    pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.rank(key)
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// assert_eq!(a.len(), 0);
+    /// a.insert(1, "a");
+    /// assert_eq!(a.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.bst.len()
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut count = SgMap::<&str, usize, 10>::new();
+    ///
+    /// // count the number of occurrences of letters in the vec
+    /// for x in vec!["a", "b", "a", "c", "a", "b"] {
+    ///     *count.entry(x).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(count["a"], 3);
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N> {
+        // Single traversal: a vacant entry keeps the search path for its insert
+        let mut path = ArrayVec::<[Idx; N]>::new();
+        let ngh: NodeGetHelper<Idx> = self.bst.internal_entry_search(&mut path, &key);
+        match ngh.node_idx() {
+            Some(node_idx) => Entry::Occupied(OccupiedEntry {
+                node_idx,
+                table: self,
+                opt_key: Some(key),
+            }),
+            None => Entry::Vacant(VacantEntry {
+                key,
+                table: self,
+                path,
+                ngh,
+            }),
+        }
+    }
+
+    /// Resolves the entry for each key, in iteration order, and passes it to `f`.
+    ///
+    /// Entries are handed out strictly one at a time: each is dropped before the next key is resolved,
+    /// so no two entries alias and changes made through earlier entries are visible to later ones
+    /// (e.g. a repeated key sees the value inserted by its first occurrence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut count = SgMap::<&str, usize, 10>::new();
+    /// count.insert("a", 10);
+    ///
+    /// count.for_each_entry(["a", "b", "a", "c", "a", "b"], |entry| {
+    ///     *entry.or_insert(0) += 1;
+    /// });
+    ///
+    /// assert!(count.into_iter().eq([("a", 13), ("b", 2), ("c", 1)]));
+    /// ```
+    pub fn for_each_entry<I, F>(&mut self, keys: I, mut f: F)
+    where
+        I: IntoIterator<Item = K>,
+        F: FnMut(Entry<'_, K, V, N>),
+    {
+        for key in keys {
+            f(self.entry(key));
+        }
+    }
+
+    /// Applies `update` to the value of `key` if present, else inserts the value returned by `default`.
+    /// Returns a mutable reference to the updated or inserted value.
+    ///
+    /// The imperative counterpart of [`entry`][SgMap::entry] for the update-or-initialize pattern, in a single traversal.
+    /// `update` isn't applied to a newly inserted value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` isn't present and the map is already at capacity.
+    /// See [`try_update_or_insert`][SgMap::try_update_or_insert] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// // Histogram of word lengths
+    /// let mut hist = SgMap::<usize, usize, 10>::new();
+    /// for word in "the quick brown fox jumps over the lazy dog".split(' ') {
+    ///     hist.update_or_insert(word.len(), |cnt| *cnt += 1, || 1);
+    /// }
+    ///
+    /// assert!(hist.into_iter().eq(vec![(3, 4), (4, 2), (5, 3)]));
+    /// ```
+    pub fn update_or_insert<U, D>(&mut self, key: K, update: U, default: D) -> &mut V
+    where
+        U: FnOnce(&mut V),
+        D: FnOnce() -> V,
+    {
+        match self.entry(key) {
+            Entry::Occupied(entry) => {
+                let val = entry.into_mut();
+                update(val);
+                val
+            }
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Fallible [`update_or_insert`][SgMap::update_or_insert].
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `key` isn't present and the map is already at capacity,
+    /// in which case neither closure is called and the map is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgMap, SgError};
+    ///
+    /// let mut count = SgMap::<&str, usize, 2>::new();
+    /// for x in vec!["a", "b", "a"] {
+    ///     assert!(count.try_update_or_insert(x, |cnt| *cnt += 1, || 1).is_ok());
+    /// }
+    ///
+    /// // Full: existing keys can still be updated
+    /// assert_eq!(count.try_update_or_insert("b", |cnt| *cnt += 1, || 1), Ok(&mut 2));
+    /// assert_eq!(
+    ///     count.try_update_or_insert("c", |cnt| *cnt += 1, || 1),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// assert!(count.into_iter().eq(vec![("a", 2), ("b", 2)]));
     /// ```
-    pub fn pop_last(&mut self) -> Option<(K, V)>
+    pub fn try_update_or_insert<U, D>(
+        &mut self,
+        key: K,
+        update: U,
+        default: D,
+    ) -> Result<&mut V, SgError>
     where
-        K: Ord,
+        U: FnOnce(&mut V),
+        D: FnOnce() -> V,
     {
-        self.bst.pop_last()
+        match self.entry(key) {
+            Entry::Occupied(entry) => {
+                let val = entry.into_mut();
+                update(val);
+                Ok(val)
+            }
+            Entry::Vacant(entry) => {
+                entry.table.bst.ensure_capacity(1)?;
+                Ok(entry.insert(default()))
+            }
+        }
     }
 
-    /// Returns the number of elements in the map.
+    /// Returns a mutable reference to the value of `key`, inserting `V::default()` first if absent.
+    ///
+    /// Shorthand for `entry(key).or_default()`, in a single traversal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` isn't present and the map is already at capacity.
+    /// See [`try_get_or_insert_default`][SgMap::try_get_or_insert_default] for a fallible alternative.
     ///
     /// # Examples
     ///
     /// ```
     /// use scapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// assert_eq!(a.len(), 0);
-    /// a.insert(1, "a");
-    /// assert_eq!(a.len(), 1);
+    /// // Word frequency
+    /// let mut freq = SgMap::<&str, usize, 10>::new();
+    /// for word in "the cat saw the other cat near the mat".split(' ') {
+    ///     *freq.get_or_insert_default(word) += 1;
+    /// }
+    ///
+    /// assert_eq!(freq.get("the"), Some(&3));
+    /// assert_eq!(freq.get("cat"), Some(&2));
+    /// assert_eq!(freq.get("mat"), Some(&1));
+    /// assert_eq!(freq.len(), 6);
     /// ```
-    pub fn len(&self) -> usize {
-        self.bst.len()
+    pub fn get_or_insert_default(&mut self, key: K) -> &mut V
+    where
+        V: Default,
+    {
+        self.entry(key).or_default()
     }
 
-    /// Gets the given key's corresponding entry in the map for in-place manipulation.
+    /// Fallible [`get_or_insert_default`][SgMap::get_or_insert_default].
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `key` isn't present and the map is already at capacity,
+    /// in which case the map is left unchanged.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
-    /// use scapegoat::SgMap;
-    ///
-    /// let mut count = SgMap::<&str, usize, 10>::new();
+    /// use scapegoat::{SgMap, SgError};
     ///
-    /// // count the number of occurrences of letters in the vec
-    /// for x in vec!["a", "b", "a", "c", "a", "b"] {
-    ///     *count.entry(x).or_insert(0) += 1;
+    /// let mut freq = SgMap::<&str, usize, 2>::new();
+    /// for word in vec!["a", "b", "a"] {
+    ///     *freq.try_get_or_insert_default(word).unwrap() += 1;
     /// }
     ///
-    /// assert_eq!(count["a"], 3);
+    /// // Full: existing keys can still be accessed
+    /// assert_eq!(freq.try_get_or_insert_default("a"), Ok(&mut 2));
+    /// assert_eq!(
+    ///     freq.try_get_or_insert_default("c"),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// assert!(freq.into_iter().eq(vec![("a", 2), ("b", 1)]));
     /// ```
-    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N> {
-        let ngh: NodeGetHelper<Idx> = self.bst.internal_get(None, &key);
-        match ngh.node_idx() {
-            Some(node_idx) => Entry::Occupied(OccupiedEntry {
-                node_idx,
-                table: self,
-            }),
-            None => Entry::Vacant(VacantEntry { key, table: self }),
+    pub fn try_get_or_insert_default(&mut self, key: K) -> Result<&mut V, SgError>
+    where
+        V: Default,
+    {
+        match self.entry(key) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                entry.table.bst.ensure_capacity(1)?;
+                Ok(entry.insert(V::default()))
+            }
         }
     }
 
@@ -979,9 +2802,35 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         Some(OccupiedEntry {
             node_idx,
             table: self,
+            opt_key: None,
         })
     }
 
+    /// Returns the first key and a mutable reference to its value, if any.
+    /// The key is the minimum key in the map.
+    ///
+    /// A lighter alternative to [`first_entry`][SgMap::first_entry] when only the boundary references are needed,
+    /// e.g. no removal. Resolved via the cached location of the minimum, no traversal is performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// if let Some((key, val)) = map.first_entry_mut() {
+    ///     assert_eq!(*key, 1);
+    ///     *val = "first";
+    /// }
+    /// assert_eq!(map[&1], "first");
+    /// assert_eq!(map[&2], "b");
+    /// ```
+    pub fn first_entry_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.bst.first_key_value_mut()
+    }
+
     /// Returns the last entry in the map for in-place manipulation.
     /// The key of this entry is the maximum key in the map.
     ///
@@ -1010,9 +2859,35 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         Some(OccupiedEntry {
             node_idx,
             table: self,
+            opt_key: None,
         })
     }
 
+    /// Returns the last key and a mutable reference to its value, if any.
+    /// The key is the maximum key in the map.
+    ///
+    /// A lighter alternative to [`last_entry`][SgMap::last_entry] when only the boundary references are needed,
+    /// e.g. no removal. Resolved via the cached location of the maximum, no traversal is performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// if let Some((key, val)) = map.last_entry_mut() {
+    ///     assert_eq!(*key, 2);
+    ///     *val = "last";
+    /// }
+    /// assert_eq!(map[&2], "last");
+    /// assert_eq!(map[&1], "a");
+    /// ```
+    pub fn last_entry_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.bst.last_key_value_mut()
+    }
+
     /// Constructs a double-ended iterator over a sub-range of elements in the map.
     /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
     /// yield elements from min (inclusive) to max (exclusive).
@@ -1042,6 +2917,23 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// }
     /// assert_eq!(Some((&5, &"b")), map.range(4..).next());
     /// ```
+    ///
+    /// Bounds may be any borrowed form of the key type, e.g. `&str` bounds over `String` keys:
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use core::ops::Bound::{Excluded, Included};
+    ///
+    /// let map: SgMap<String, usize, 10> = ["apple", "banana", "cherry", "date"]
+    ///     .iter()
+    ///     .map(|s| (s.to_string(), s.len()))
+    ///     .collect();
+    ///
+    /// let mut fruits = map.range::<str, _>((Included("b"), Excluded("d")));
+    /// assert_eq!(fruits.next(), Some((&"banana".to_string(), &6)));
+    /// assert_eq!(fruits.next(), Some((&"cherry".to_string(), &6)));
+    /// assert_eq!(fruits.next(), None);
+    /// ```
     pub fn range<T, R>(&self, range: R) -> Range<'_, K, V, N>
     where
         T: Ord + ?Sized,
@@ -1055,6 +2947,173 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Constructs a double-ended iterator over entries with keys greater than or equal to `start`.
+    /// Equivalent to `range(start..)`, without needing the range syntax.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    ///
+    /// assert!(map.iter_from(&5).map(|(k, _)| *k).eq([5, 6, 7]));
+    /// assert!(map.iter_from(&5).eq(map.range(5..)));
+    /// ```
+    pub fn iter_from<Q>(&self, start: &Q) -> Range<'_, K, V, N>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.range((Included(start), Unbounded))
+    }
+
+    /// Constructs a double-ended iterator over entries with keys strictly less than `end`.
+    /// Equivalent to `range(..end)`, without needing the range syntax.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    ///
+    /// assert!(map.iter_until(&3).map(|(k, _)| *k).eq([0, 1, 2]));
+    /// assert!(map.iter_until(&3).eq(map.range(..3)));
+    /// ```
+    pub fn iter_until<Q>(&self, end: &Q) -> Range<'_, K, V, N>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.range((Unbounded, Excluded(end)))
+    }
+
+    /// Returns the number of entries within a range, without iterating it.
+    /// Useful for deciding whether a potentially expensive scan is worthwhile.
+    ///
+    /// Runs in `O(log n)` if the `fast_rebalance` feature is enabled (uses cached subtree sizes),
+    /// else `O(log n + k)` for `k` entries in range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = (0..8).map(|x| (x, x * 10)).collect();
+    ///
+    /// assert_eq!(map.range_count(2..5), 3);
+    /// assert_eq!(map.range_count(6..), 2);
+    /// assert_eq!(map.range_count(10..20), 0);
+    /// ```
+    pub fn range_count<Q, R>(&self, range: R) -> usize
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+    {
+        SgTree::<K, V, N>::assert_valid_range(&range);
+        self.bst.range_count(&range)
+    }
+
+    /// Returns `true` if every key in the map is within a range, e.g. to validate against an allowed interval.
+    /// An empty map is vacuously within any range.
+    ///
+    /// Runs in `O(1)`: only the cached first and last keys are checked, nothing is iterated.
+    /// Unlike [`range`][SgMap::range], doesn't panic for `start > end` (no key is within such a range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = vec![(2, 'a'), (4, 'b'), (6, 'c')].into_iter().collect();
+    ///
+    /// assert!(map.keys_all_in_range(2..=6));
+    /// assert!(!map.keys_all_in_range(3..));
+    /// ```
+    pub fn keys_all_in_range<Q, R>(&self, range: R) -> bool
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+    {
+        self.bst.keys_all_in_range(&range)
+    }
+
+    /// Returns `true` if at least one key in the map is within a range, e.g. to check a window before scanning it.
+    /// Equivalent to `range(range).next().is_some()`, without collecting the range.
+    ///
+    /// Runs in `O(log n)`: a single descent, returning on the first key found in range.
+    /// Unlike [`range`][SgMap::range], doesn't panic for `start > end` (no key is within such a range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = vec![(2, 'a'), (4, 'b'), (6, 'c')].into_iter().collect();
+    ///
+    /// assert!(map.contains_range(3..5));
+    /// assert!(!map.contains_range(3..4));
+    /// assert!(!map.contains_range(7..));
+    /// ```
+    #[doc(alias = "keys_any_in_range")]
+    pub fn contains_range<Q, R>(&self, range: R) -> bool
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+    {
+        self.bst.keys_any_in_range(&range)
+    }
+
+    /// Constructs a double-ended iterator over all entries whose byte-string key starts with the given prefix,
+    /// in order by key.
+    ///
+    /// Equivalent to `range(prefix..upper)`, where `upper` is `prefix` truncated after its last non-`0xFF` byte,
+    /// with that byte incremented. If `prefix` is empty or all `0xFF` bytes there is no such bound,
+    /// and the range extends to the end of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<[u8; 2], &str, 10>::new();
+    /// map.insert([0x01, 0xFF], "a");
+    /// map.insert([0x02, 0x00], "b");
+    /// map.insert([0x02, 0xFF], "c");
+    /// map.insert([0x03, 0x00], "d");
+    /// map.insert([0xFF, 0x01], "e");
+    ///
+    /// assert!(map.prefix_range(&[0x02]).map(|(_, v)| *v).eq(["b", "c"]));
+    /// assert!(map.prefix_range(&[0xFF]).map(|(_, v)| *v).eq(["e"]));
+    /// assert_eq!(map.prefix_range(&[0x04]).count(), 0);
+    /// assert_eq!(map.prefix_range(&[]).count(), map.len());
+    /// ```
+    pub fn prefix_range<P>(&self, prefix: &P) -> Range<'_, K, V, N>
+    where
+        K: Borrow<[u8]>,
+        P: AsRef<[u8]> + ?Sized,
+    {
+        Range {
+            table: self,
+            node_idx_iter: self.bst.prefix_search(prefix.as_ref()).into_iter(),
+        }
+    }
+
     /// Constructs a mutable single-ended iterator over a sub-range of elements in the map.
     /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
     /// yield elements from min (inclusive) to max (exclusive).
@@ -1088,6 +3147,25 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// assert_eq!(map["Alice"], 0);
     /// assert_eq!(map["Bob"], 100);
     /// ```
+    ///
+    /// Bounds may be any borrowed form of the key type, e.g. `&str` bounds over `String` keys:
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use core::ops::Bound::{Included, Unbounded};
+    ///
+    /// let mut map: SgMap<String, usize, 10> = ["Alice", "Bob", "Carol"]
+    ///     .iter()
+    ///     .map(|s| (s.to_string(), 0))
+    ///     .collect();
+    ///
+    /// for (_, balance) in map.range_mut::<str, _>((Included("B"), Unbounded)) {
+    ///     *balance += 100;
+    /// }
+    ///
+    /// assert_eq!(map["Alice"], 0);
+    /// assert_eq!(map["Carol"], 100);
+    /// ```
     pub fn range_mut<T, R>(&mut self, range: R) -> RangeMut<'_, K, V, N>
     where
         T: Ord + ?Sized,
@@ -1097,12 +3175,55 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         SgTree::<K, V, N>::assert_valid_range(&range);
         RangeMut::new(self, &range)
     }
+
+    /// Constructs a mutable double-ended iterator over the values of a sub-range of entries in the map,
+    /// in ascending key order. Shorthand for `range_mut(range).map(|(_, v)| v)`, with a known length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<_, _, 10> = (0..8).map(|k| (k, 0)).collect();
+    ///
+    /// let band = map.range_values_mut(2..5);
+    /// assert_eq!(band.len(), 3);
+    /// for v in band {
+    ///     *v += 10;
+    /// }
+    ///
+    /// assert!(map.values().eq([0, 0, 10, 10, 10, 0, 0, 0].iter()));
+    /// ```
+    pub fn range_values_mut<T, R>(&mut self, range: R) -> RangeValuesMut<'_, K, V, N>
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        RangeValuesMut {
+            inner: self.range_mut(range),
+        }
+    }
 }
 
 // Convenience Traits --------------------------------------------------------------------------------------------------
 
+// Default
+impl<K: Ord, V, const N: usize> Default for SgMap<K, V, N> {
+    /// Equivalent to [`SgMap::new`]: an empty map of capacity `N` with the default rebalance parameter.
+    /// Storage is inline, nothing is allocated.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Debug
-impl<K: Default, V: Default, const N: usize> Debug for SgMap<K, V, N>
+impl<K, V, const N: usize> Debug for SgMap<K, V, N>
 where
     K: Ord + Debug,
     V: Debug,
@@ -1112,8 +3233,78 @@ where
     }
 }
 
+// Hash
+impl<K, V, const N: usize> Hash for SgMap<K, V, N>
+where
+    K: Ord + Hash,
+    V: Hash,
+{
+    /// Hashes the length, then every pair in order by key, like `BTreeMap`.
+    /// Independent of capacity `N`, insertion order, and internal arena layout.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for entry in self.iter() {
+            entry.hash(state);
+        }
+    }
+}
+
+// defmt
+#[cfg(feature = "defmt")]
+impl<K, V, const N: usize> defmt::Format for SgMap<K, V, N>
+where
+    K: Ord + defmt::Format,
+    V: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{{");
+        for (i, (k, v)) in self.bst.iter().enumerate() {
+            if i > 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{}: {}", k, v);
+        }
+        defmt::write!(f, "}}");
+    }
+}
+
+// Comparison with std's map
+#[cfg(feature = "std")]
+impl<K, V, const N: usize> PartialEq<BTreeMap<K, V>> for SgMap<K, V, N>
+where
+    K: Ord,
+    V: PartialEq,
+{
+    /// Compares entries in key order.
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let sgm = SgMap::from([(2, "b"), (1, "a")]);
+    /// let btm = vec![(1, "a"), (2, "b")].into_iter().collect::<BTreeMap<_, _>>();
+    /// assert_eq!(sgm, btm);
+    /// assert_eq!(btm, sgm);
+    /// ```
+    fn eq(&self, other: &BTreeMap<K, V>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+// Comparison with std's map, reversed
+#[cfg(feature = "std")]
+impl<K, V, const N: usize> PartialEq<SgMap<K, V, N>> for BTreeMap<K, V>
+where
+    K: Ord,
+    V: PartialEq,
+{
+    fn eq(&self, other: &SgMap<K, V, N>) -> bool {
+        other == self
+    }
+}
+
 // From array.
-impl<K: Default, V: Default, const N: usize> From<[(K, V); N]> for SgMap<K, V, N>
+impl<K, V, const N: usize> From<[(K, V); N]> for SgMap<K, V, N>
 where
     K: Ord,
 {
@@ -1139,7 +3330,7 @@ where
 }
 
 // Indexing
-impl<K: Default, V: Default, Q, const N: usize> Index<&Q> for SgMap<K, V, N>
+impl<K, V, Q, const N: usize> Index<&Q> for SgMap<K, V, N>
 where
     K: Borrow<Q> + Ord,
     Q: Ord + ?Sized,
@@ -1157,7 +3348,7 @@ where
 }
 
 // Construct from iterator.
-impl<K: Default, V: Default, const N: usize> FromIterator<(K, V)> for SgMap<K, V, N>
+impl<K, V, const N: usize> FromIterator<(K, V)> for SgMap<K, V, N>
 where
     K: Ord,
 {
@@ -1169,7 +3360,7 @@ where
 }
 
 // Extension from iterator.
-impl<K: Default, V: Default, const N: usize> Extend<(K, V)> for SgMap<K, V, N>
+impl<K, V, const N: usize> Extend<(K, V)> for SgMap<K, V, N>
 where
     K: Ord,
 {
@@ -1179,7 +3370,7 @@ where
 }
 
 // Extension from reference iterator.
-impl<'a, K: Default, V: Default, const N: usize> Extend<(&'a K, &'a V)> for SgMap<K, V, N>
+impl<'a, K, V, const N: usize> Extend<(&'a K, &'a V)> for SgMap<K, V, N>
 where
     K: Ord + Copy,
     V: Copy,
@@ -1192,7 +3383,7 @@ where
 // General Iterators ---------------------------------------------------------------------------------------------------
 
 // Reference iterator
-impl<'a, K: Ord + Default, V: Default, const N: usize> IntoIterator for &'a SgMap<K, V, N> {
+impl<'a, K: Ord, V, const N: usize> IntoIterator for &'a SgMap<K, V, N> {
     type Item = (&'a K, &'a V);
     type IntoIter = Iter<'a, K, V, N>;
 
@@ -1202,7 +3393,7 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> IntoIterator for &'a SgMa
 }
 
 // Consuming iterator
-impl<K: Ord + Default, V: Default, const N: usize> IntoIterator for SgMap<K, V, N> {
+impl<K: Ord, V, const N: usize> IntoIterator for SgMap<K, V, N> {
     type Item = (K, V);
     type IntoIter = IntoIter<K, V, N>;
 
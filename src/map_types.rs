@@ -6,8 +6,10 @@ use core::ops::RangeBounds;
 use tinyvec::ArrayVec;
 
 use crate::map::SgMap;
+use crate::tree::node::NodeGetHelper;
 use crate::tree::{
-    Idx, IntoIter as TreeIntoIter, Iter as TreeIter, IterMut as TreeIterMut, SmallNode,
+    clone_idx_iter, Drain as TreeDrain, Idx, IntoIter as TreeIntoIter, Iter as TreeIter,
+    IterMut as TreeIterMut, SmallNode,
 };
 
 // General Iterators ---------------------------------------------------------------------------------------------------
@@ -17,11 +19,11 @@ use crate::tree::{
 /// This `struct` is created by the [`iter`][crate::map::SgMap::iter] method on [`SgMap`][crate::map::SgMap].
 /// documentation for more.
 ///
-pub struct Iter<'a, T: Ord + Default, V: Default, const N: usize> {
+pub struct Iter<'a, T: Ord, V, const N: usize> {
     ref_iter: TreeIter<'a, T, V, N>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iter<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iter<'a, K, V, N> {
     /// Construct reference iterator.
     pub(crate) fn new(map: &'a SgMap<K, V, N>) -> Self {
         Iter {
@@ -30,31 +32,43 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iter<'a, K, V, N> {
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Iter<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for Iter<'a, K, V, N> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
         self.ref_iter.next()
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.ref_iter.nth(n)
+    }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
     fn len(&self) -> usize {
         self.ref_iter.len()
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for Iter<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Iter<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> Clone for Iter<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
 
 /// An owning iterator over the entries of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_iter`][crate::map::SgMap::into_iter] method on [`SgMap`][crate::map::SgMap].
 /// documentation for more.
-pub struct IntoIter<K: Ord + Default, V: Default, const N: usize> {
+pub struct IntoIter<K: Ord, V, const N: usize> {
     cons_iter: TreeIntoIter<K, V, N>,
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> IntoIter<K, V, N> {
+impl<K: Ord, V, const N: usize> IntoIter<K, V, N> {
     /// Construct owning iterator.
     pub(crate) fn new(map: SgMap<K, V, N>) -> Self {
         IntoIter {
@@ -63,31 +77,35 @@ impl<K: Ord + Default, V: Default, const N: usize> IntoIter<K, V, N> {
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoIter<K, V, N> {
+impl<K: Ord, V, const N: usize> Iterator for IntoIter<K, V, N> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
         self.cons_iter.next()
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cons_iter.nth(n)
+    }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
+impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
     fn len(&self) -> usize {
         self.cons_iter.len()
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+impl<K: Ord, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
 
 /// An mutable iterator over the entries of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`iter_mut`][crate::map::SgMap::iter_mut] method on [`SgMap`][crate::map::SgMap].
 /// documentation for more.
-pub struct IterMut<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct IterMut<'a, K: Ord, V, const N: usize> {
     mut_iter: TreeIterMut<'a, K, V, N>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> IterMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> IterMut<'a, K, V, N> {
     /// Construct owning iterator.
     pub(crate) fn new(map: &'a mut SgMap<K, V, N>) -> Self {
         IterMut {
@@ -96,7 +114,7 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> IterMut<'a, K, V, N> {
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for IterMut<'a, K, V, N> {
     type Item = (&'a K, &'a mut V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -104,13 +122,52 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a,
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for IterMut<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.mut_iter.next_back()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
     fn len(&self) -> usize {
         self.mut_iter.len()
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for IterMut<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for IterMut<'a, K, V, N> {}
+
+/// A draining iterator over the entries of a [`SgMap`][crate::map::SgMap].
+///
+/// This `struct` is created by the [`drain`][crate::map::SgMap::drain] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct Drain<'a, K: Ord, V, const N: usize> {
+    drain_iter: TreeDrain<'a, K, V, N>,
+}
+
+impl<'a, K: Ord, V, const N: usize> Drain<'a, K, V, N> {
+    /// Construct draining iterator.
+    pub(crate) fn new(map: &'a mut SgMap<K, V, N>) -> Self {
+        Drain {
+            drain_iter: TreeDrain::new(&mut map.bst),
+        }
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> Iterator for Drain<'a, K, V, N> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain_iter.next()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Drain<'a, K, V, N> {
+    fn len(&self) -> usize {
+        self.drain_iter.len()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Drain<'a, K, V, N> {}
 
 // Key Iterators -------------------------------------------------------------------------------------------------------
 
@@ -120,49 +177,65 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for IterMut
 ///
 /// This `struct` is created by the [`keys`][crate::map::SgMap::keys] method on [`SgMap`][crate::map::SgMap].
 /// See its documentation for more.
-pub struct Keys<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct Keys<'a, K: Ord, V, const N: usize> {
     pub(crate) inner: Iter<'a, K, V, N>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Keys<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for Keys<'a, K, V, N> {
     type Item = &'a K;
 
     fn next(&mut self) -> Option<&'a K> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn nth(&mut self, n: usize) -> Option<&'a K> {
+        self.inner.nth(n).map(|(k, _)| k)
+    }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Keys<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Keys<'a, K, V, N> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for Keys<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Keys<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> Clone for Keys<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Keys {
+            inner: self.inner.clone(),
+        }
+    }
+}
 
 /// An owning iterator over the keys of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_keys`][crate::map::SgMap::into_keys] method on [`SgMap`][crate::map::SgMap].
 /// See its documentation for more.
-pub struct IntoKeys<K: Ord + Default, V: Default, const N: usize> {
+pub struct IntoKeys<K: Ord, V, const N: usize> {
     pub(crate) inner: IntoIter<K, V, N>,
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoKeys<K, V, N> {
+impl<K: Ord, V, const N: usize> Iterator for IntoKeys<K, V, N> {
     type Item = K;
 
     fn next(&mut self) -> Option<K> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn nth(&mut self, n: usize) -> Option<K> {
+        self.inner.nth(n).map(|(k, _)| k)
+    }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoKeys<K, V, N> {
+impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoKeys<K, V, N> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> FusedIterator for IntoKeys<K, V, N> {}
+impl<K: Ord, V, const N: usize> FusedIterator for IntoKeys<K, V, N> {}
 
 // Value Iterators -----------------------------------------------------------------------------------------------------
 
@@ -172,59 +245,75 @@ impl<K: Ord + Default, V: Default, const N: usize> FusedIterator for IntoKeys<K,
 ///
 /// This `struct` is created by the [`values`][crate::map::SgMap::values] method on [`SgMap`][crate::map::SgMap].
 /// See its documentation for more.
-pub struct Values<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct Values<'a, K: Ord, V, const N: usize> {
     pub(crate) inner: Iter<'a, K, V, N>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Values<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for Values<'a, K, V, N> {
     type Item = &'a V;
 
     fn next(&mut self) -> Option<&'a V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn nth(&mut self, n: usize) -> Option<&'a V> {
+        self.inner.nth(n).map(|(_, v)| v)
+    }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Values<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Values<'a, K, V, N> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for Values<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Values<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> Clone for Values<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Values {
+            inner: self.inner.clone(),
+        }
+    }
+}
 
 /// An owning iterator over the values of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_values`][crate::map::SgMap::into_values] method on [`SgMap`][crate::map::SgMap].
 /// See its documentation for more.
-pub struct IntoValues<K: Ord + Default, V: Default, const N: usize> {
+pub struct IntoValues<K: Ord, V, const N: usize> {
     pub(crate) inner: IntoIter<K, V, N>,
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoValues<K, V, N> {
+impl<K: Ord, V, const N: usize> Iterator for IntoValues<K, V, N> {
     type Item = V;
 
     fn next(&mut self) -> Option<V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn nth(&mut self, n: usize) -> Option<V> {
+        self.inner.nth(n).map(|(_, v)| v)
+    }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoValues<K, V, N> {
+impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoValues<K, V, N> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> FusedIterator for IntoValues<K, V, N> {}
+impl<K: Ord, V, const N: usize> FusedIterator for IntoValues<K, V, N> {}
 
 /// A mutable iterator over the values of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`values_mut`][crate::map::SgMap::values_mut] method on [`SgMap`][crate::map::SgMap].
 /// See its documentation for more.
-pub struct ValuesMut<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct ValuesMut<'a, K: Ord, V, const N: usize> {
     pub(crate) inner: IterMut<'a, K, V, N>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for ValuesMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for ValuesMut<'a, K, V, N> {
     type Item = &'a mut V;
 
     fn next(&mut self) -> Option<&'a mut V> {
@@ -232,29 +321,33 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for ValuesMut<'a
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator
-    for ValuesMut<'a, K, V, N>
-{
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for ValuesMut<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<&'a mut V> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for ValuesMut<'a, K, V, N> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for ValuesMut<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for ValuesMut<'a, K, V, N> {}
 
 // Entry APIs ----------------------------------------------------------------------------------------------------------
 
 /// A view into a single entry in a map, which may either be vacant or occupied.
 ///
 /// This `enum` is constructed from the [`SgMap::entry`] method on [`SgMap`].
-pub enum Entry<'a, K: Ord + Default, V: Default, const N: usize> {
+pub enum Entry<'a, K: Ord, V, const N: usize> {
     /// A vacant entry.
     Vacant(VacantEntry<'a, K, V, N>),
     /// An occupied entry.
     Occupied(OccupiedEntry<'a, K, V, N>),
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Entry<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Entry<'a, K, V, N> {
     /// Ensures a value is in the entry by inserting the default if empty, and returns a mutable
     /// reference to the value in the entry.
     ///
@@ -373,6 +466,7 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Entry<'a, K, V, N> {
 
     /// Ensures a value is in the entry by inserting the default value if empty,
     /// and returns a mutable reference to the value in the entry.
+    /// Only `V: Default` is required, `V` need not be `Clone`.
     ///
     /// # Examples
     ///
@@ -384,7 +478,24 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Entry<'a, K, V, N> {
     ///
     /// assert_eq!(map["poneyland"], None);
     /// ```
-    pub fn or_default(self) -> &'a mut V {
+    ///
+    /// Counting occurrences:
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut count = SgMap::<char, usize, 10>::new();
+    ///
+    /// for c in "abracadabra".chars() {
+    ///     *count.entry(c).or_default() += 1;
+    /// }
+    ///
+    /// assert!(count.into_iter().eq(vec![('a', 5), ('b', 2), ('c', 1), ('d', 1), ('r', 2)]));
+    /// ```
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
         match self {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => entry.insert(Default::default()),
@@ -394,12 +505,14 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Entry<'a, K, V, N> {
 
 /// A view into a vacant entry in a [`SgMap`][crate::map::SgMap].
 /// It is part of the [`Entry`] enum.
-pub struct VacantEntry<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct VacantEntry<'a, K: Ord, V, const N: usize> {
     pub(super) key: K,
     pub(super) table: &'a mut SgMap<K, V, N>,
+    pub(super) path: ArrayVec<[Idx; N]>,
+    pub(super) ngh: NodeGetHelper<Idx>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> VacantEntry<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> VacantEntry<'a, K, V, N> {
     /// Gets a reference to the key that would be used when inserting a value
     /// through the [`VacantEntry`][crate::map_types::VacantEntry].
     ///
@@ -415,7 +528,7 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> VacantEntry<'a, K, V, N>
         &self.key
     }
 
-    /// Take ownership of the key.
+    /// Take ownership of the key, dropping the entry without inserting.
     ///
     /// # Examples
     ///
@@ -429,6 +542,27 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> VacantEntry<'a, K, V, N>
     ///     v.into_key();
     /// }
     /// ```
+    ///
+    /// Recovering an expensive key, to reuse it elsewhere rather than re-allocating:
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<String, usize, 2>::new();
+    /// let mut rejected = Vec::new();
+    ///
+    /// let key = "poneyland".to_string();
+    /// if let Entry::Vacant(v) = map.entry(key) {
+    ///     // Peek, decide not to insert, and take back the owned key
+    ///     if v.key().starts_with("poney") {
+    ///         rejected.push(v.into_key());
+    ///     }
+    /// }
+    ///
+    /// assert!(map.is_empty());
+    /// assert_eq!(rejected, ["poneyland".to_string()]);
+    /// ```
     pub fn into_key(self) -> K {
         self.key
     }
@@ -450,23 +584,53 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> VacantEntry<'a, K, V, N>
     /// assert_eq!(map["poneyland"], 37);
     /// ```
     pub fn insert(self, value: V) -> &'a mut V {
-        let (_, new_node_idx) = self
+        // Links at the position found by `SgMap::entry`, no second traversal
+        let new_node_idx = self
             .table
             .bst
-            .internal_balancing_insert::<Idx>(self.key, value);
+            .internal_vacant_insert(&self.path, &self.ngh, self.key, value);
 
         self.table.bst.arena[new_node_idx].get_mut().1
     }
+
+    /// Sets the value of the entry to the result of a fallible closure, and returns a mutable reference to it.
+    /// If the closure returns `Err`, that error is returned and the map is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<&str, u32, 2>::new();
+    ///
+    /// if let Entry::Vacant(v) = map.entry("poneyland") {
+    ///     assert_eq!(v.try_insert_with(|| "37".parse::<u32>()), Ok(&mut 37));
+    /// }
+    /// assert_eq!(map["poneyland"], 37);
+    ///
+    /// if let Entry::Vacant(v) = map.entry("horseland") {
+    ///     assert!(v.try_insert_with(|| "n/a".parse::<u32>()).is_err());
+    /// }
+    /// assert!(!map.contains_key("horseland"));
+    /// ```
+    pub fn try_insert_with<F, E>(self, f: F) -> Result<&'a mut V, E>
+    where
+        F: FnOnce() -> Result<V, E>,
+    {
+        f().map(|value| self.insert(value))
+    }
 }
 
 /// A view into an occupied entry in a [`SgMap`][crate::map::SgMap].
 /// It is part of the [`Entry`] enum.
-pub struct OccupiedEntry<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct OccupiedEntry<'a, K: Ord, V, const N: usize> {
     pub(super) node_idx: usize,
     pub(super) table: &'a mut SgMap<K, V, N>,
+    pub(super) opt_key: Option<K>, // Key passed to `SgMap::entry`, if any
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> OccupiedEntry<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> OccupiedEntry<'a, K, V, N> {
     /// Gets a reference to the key in the entry.
     ///
     /// # Examples
@@ -556,6 +720,87 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> OccupiedEntry<'a, K, V, N
         self.table.bst.arena[self.node_idx].get_mut().1
     }
 
+    /// Gets references to the stored key and the value in the entry.
+    /// The stored key may differ from the one passed to [`SgMap::entry`][crate::map::SgMap::entry],
+    /// for key types that can be `==` without being identical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<&str, usize, 2>::new();
+    /// map.entry("poneyland").or_insert(12);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("poneyland") {
+    ///     assert_eq!(o.get_key_value(), (&"poneyland", &12));
+    /// }
+    /// ```
+    pub fn get_key_value(&self) -> (&K, &V) {
+        let node = &self.table.bst.arena[self.node_idx];
+        (node.key(), node.val())
+    }
+
+    /// Converts the entry into a reference to its stored key and a mutable reference to its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<&str, usize, 2>::new();
+    /// map.entry("poneyland").or_insert(12);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("poneyland") {
+    ///     let (k, v) = o.into_key_value();
+    ///     assert_eq!(k, &"poneyland");
+    ///     *v += 10;
+    /// }
+    /// assert_eq!(map["poneyland"], 22);
+    /// ```
+    pub fn into_key_value(self) -> (&'a K, &'a mut V) {
+        self.table.bst.arena[self.node_idx].get_mut()
+    }
+
+    /// Replaces the entry's value with the result of `f`, which takes ownership of the old value.
+    /// Returns a reference to the new value with a lifetime bound to the map itself.
+    ///
+    /// Unlike [`get_mut`][OccupiedEntry::get_mut], this works for transforms that consume the value,
+    /// without requiring `V: Clone` or `V: Default`.
+    ///
+    /// Not done in place: the entry is removed while `f` runs, then re-inserted. That's two `O(log n)` operations
+    /// and, like any removal or insert, may trigger a rebuild (counted by [`SgMap::rebal_cnt`]).
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the old value has already been moved into it: the entry is removed from the map,
+    /// which otherwise stays valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<&str, String, 2>::new();
+    /// map.insert("poneyland", String::from("pony"));
+    ///
+    /// if let Entry::Occupied(o) = map.entry("poneyland") {
+    ///     o.replace_with(|mut s| {
+    ///         s.push_str("land");
+    ///         s
+    ///     });
+    /// }
+    /// assert_eq!(map["poneyland"], "ponyland");
+    /// ```
+    pub fn replace_with<F: FnOnce(V) -> V>(self, f: F) -> &'a mut V {
+        let bst = &mut self.table.bst;
+        let node_idx = bst.map_val_by_idx(self.node_idx, f);
+        bst.arena[node_idx].get_mut().1
+    }
+
     /// Sets the value of the entry with the `OccupiedEntry`'s key,
     /// and returns the entry's old value.
     ///
@@ -577,6 +822,76 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> OccupiedEntry<'a, K, V, N
         core::mem::replace(self.get_mut(), value)
     }
 
+    /// Replaces the entry, returning the old key and value.
+    /// The new key in the map will be the key used to create this entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entry wasn't created by [`SgMap::entry`] (e.g. it came from
+    /// [`first_entry`][crate::map::SgMap::first_entry] or [`last_entry`][crate::map::SgMap::last_entry]),
+    /// as there is no key to replace with. Use [`replace_key`][OccupiedEntry::replace_key] and
+    /// [`insert`][OccupiedEntry::insert] on such entries instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    /// use scapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<String, usize, 2>::new();
+    /// map.insert("poneyland".to_string(), 15);
+    ///
+    /// if let Entry::Occupied(o) = map.entry("poneyland".to_string()) {
+    ///     let (old_key, old_value): (String, usize) = o.replace_entry(16);
+    ///     assert_eq!(old_key, "poneyland");
+    ///     assert_eq!(old_value, 15);
+    /// }
+    ///
+    /// assert_eq!(map.get("poneyland"), Some(&16));
+    /// ```
+    pub fn replace_entry(mut self, value: V) -> (K, V) {
+        let key = self
+            .opt_key
+            .take()
+            .expect("API misuse: `replace_entry` requires an entry created by `SgMap::entry`!");
+
+        let node = &mut self.table.bst.arena[self.node_idx];
+        (node.replace_key(key), node.replace_val(value))
+    }
+
+    /// Replaces the key of the entry, returning the old key.
+    /// Useful for canonicalizing keys that compare equal but aren't identical (e.g. interned keys).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` doesn't compare equal to the entry's current key,
+    /// because that would violate the map's ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 2>::new();
+    /// map.insert("poneyland".to_string(), 15);
+    ///
+    /// if let Some(o) = map.first_entry() {
+    ///     let old_key: String = o.replace_key("poneyland".to_string());
+    ///     assert_eq!(old_key, "poneyland");
+    /// }
+    ///
+    /// assert_eq!(map.get("poneyland"), Some(&15));
+    /// ```
+    pub fn replace_key(self, key: K) -> K {
+        let node = &mut self.table.bst.arena[self.node_idx];
+        assert!(
+            key == *node.key(),
+            "API misuse: replacement key must compare equal to the entry's key!"
+        );
+
+        node.replace_key(key)
+    }
+
     /// Take ownership of the key and value from the map.
     ///
     /// # Examples
@@ -597,10 +912,13 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> OccupiedEntry<'a, K, V, N
     /// // println!("{}", map["poneyland"]);
     /// ```
     pub fn remove_entry(self) -> (K, V) {
-        self.table
-            .bst
+        let bst = &mut self.table.bst;
+        let kv = bst
             .priv_remove_by_idx(self.node_idx)
-            .expect("Must be occupied")
+            .expect("Must be occupied");
+
+        bst.priv_sparse_rebuild();
+        kv
     }
 
     /// Takes the value of the entry out of the map, and returns it.
@@ -628,16 +946,14 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> OccupiedEntry<'a, K, V, N
 /// The error returned by [`try_insert_std`](SgMap::try_insert_std) when the key already exists.
 ///
 /// Contains the occupied entry, and the value that was not inserted.
-pub struct OccupiedError<'a, K: 'a + Ord + Default, V: 'a + Default, const N: usize> {
+pub struct OccupiedError<'a, K: 'a + Ord, V: 'a, const N: usize> {
     /// The entry in the map that was already occupied.
     pub entry: OccupiedEntry<'a, K, V, N>,
     /// The value which was not inserted, because the entry was already occupied.
     pub value: V,
 }
 
-impl<K: fmt::Debug + Ord + Default, V: fmt::Debug + Default, const N: usize> fmt::Debug
-    for OccupiedError<'_, K, V, N>
-{
+impl<K: fmt::Debug + Ord, V: fmt::Debug, const N: usize> fmt::Debug for OccupiedError<'_, K, V, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("OccupiedError")
             .field("key", self.entry.key())
@@ -647,7 +963,7 @@ impl<K: fmt::Debug + Ord + Default, V: fmt::Debug + Default, const N: usize> fmt
     }
 }
 
-impl<'a, K: fmt::Debug + Ord + Default, V: fmt::Debug + Default, const N: usize> fmt::Display
+impl<'a, K: fmt::Debug + Ord, V: fmt::Debug, const N: usize> fmt::Display
     for OccupiedError<'a, K, V, N>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -667,35 +983,49 @@ impl<'a, K: fmt::Debug + Ord + Default, V: fmt::Debug + Default, const N: usize>
 ///
 /// This `struct` is created by the [`range`][`crate::map::SgMap::range`] method on [`SgMap`][crate::map::SgMap]. See its
 /// documentation for more.
-pub struct Range<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct Range<'a, K: Ord, V, const N: usize> {
     pub(crate) table: &'a SgMap<K, V, N>,
     pub(crate) node_idx_iter: <ArrayVec<[usize; N]> as IntoIterator>::IntoIter,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Range<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Range<'a, K, V, N> {
     fn to_node_ref(&self, idx: usize) -> (&'a K, &'a V) {
         let node = &self.table.bst.arena[idx];
         (node.key(), node.val())
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Range<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for Range<'a, K, V, N> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
         let node_idx = self.node_idx_iter.next()?;
         Some(self.to_node_ref(node_idx))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let node_idx = self.node_idx_iter.nth(n)?;
+        Some(self.to_node_ref(node_idx))
+    }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator for Range<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for Range<'a, K, V, N> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let node_idx = self.node_idx_iter.next_back()?;
         Some(self.to_node_ref(node_idx))
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for Range<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Range<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> Clone for Range<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Range {
+            table: self.table,
+            node_idx_iter: clone_idx_iter(&self.node_idx_iter),
+        }
+    }
+}
 
 /// A mutable iterator over a sub-range of entries in a [`SgMap`].
 ///
@@ -703,7 +1033,7 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for Range<'
 /// documentation for more.
 ///
 /// [`range_mut`]: SgMap::range_mut
-pub struct RangeMut<'a, K: Ord + Default, V: Default, const N: usize> {
+pub struct RangeMut<'a, K: Ord, V, const N: usize> {
     inner: RangeMutPeekable<'a, K, V, N>,
     last: Option<RangeMutLast<'a, K, V, N>>,
     total_cnt: usize,
@@ -717,14 +1047,13 @@ type RangeMutPeekable<'a, K, V, const N: usize> = Peekable<TreeIterMut<'a, K, V,
 
 impl<'a, K, V, const N: usize> RangeMut<'a, K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     // Constructor
     pub(crate) fn new<T, R>(map: &'a mut SgMap<K, V, N>, range: &R) -> Self
     where
         T: Ord + ?Sized,
-        K: Borrow<T> + Ord + Default,
+        K: Borrow<T> + Ord,
         R: RangeBounds<T>,
     {
         let len = RangeMut::compute_len(map, range);
@@ -742,7 +1071,7 @@ where
     fn compute_len<T, R>(map: &SgMap<K, V, N>, range: &R) -> usize
     where
         T: Ord + ?Sized,
-        K: Borrow<T> + Ord + Default,
+        K: Borrow<T> + Ord,
         R: RangeBounds<T>,
     {
         let mut peekable = map.bst.iter().peekable();
@@ -779,7 +1108,7 @@ where
     )
     where
         T: Ord + ?Sized,
-        K: Borrow<T> + Ord + Default,
+        K: Borrow<T> + Ord,
         R: RangeBounds<T>,
     {
         let mut peekable = map.bst.iter_mut().peekable();
@@ -807,8 +1136,7 @@ where
 
 impl<'a, K, V, const N: usize> Iterator for RangeMut<'a, K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     type Item = (&'a K, &'a mut V);
 
@@ -827,8 +1155,7 @@ where
 
 impl<'a, K, V, const N: usize> DoubleEndedIterator for RangeMut<'a, K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.spent_cnt < self.total_cnt {
@@ -843,14 +1170,46 @@ where
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for RangeMut<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for RangeMut<'a, K, V, N> {}
+
+/// A mutable iterator over the values of a sub-range of entries in a [`SgMap`].
+///
+/// This `struct` is created by the [`range_values_mut`] method on [`SgMap`]. See its
+/// documentation for more.
+///
+/// [`range_values_mut`]: SgMap::range_values_mut
+pub struct RangeValuesMut<'a, K: Ord, V, const N: usize> {
+    pub(crate) inner: RangeMut<'a, K, V, N>,
+}
+
+impl<'a, K: Ord, V, const N: usize> Iterator for RangeValuesMut<'a, K, V, N> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<&'a mut V> {
+        self.inner.next().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for RangeValuesMut<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<&'a mut V> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for RangeValuesMut<'a, K, V, N> {
+    fn len(&self) -> usize {
+        debug_assert!(self.inner.spent_cnt <= self.inner.total_cnt);
+        self.inner.total_cnt - self.inner.spent_cnt
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> FusedIterator for RangeValuesMut<'a, K, V, N> {}
 
 /*
 // TODO: does commit to this interface limit potential optimizations?
 impl<'a, K, V, const N: usize> ExactSizeIterator for RangeMut<'a, K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     fn len(&self) -> usize {
         debug_assert!(self.spent_cnt <= self.total_cnt);
@@ -858,3 +1217,40 @@ where
     }
 }
 */
+
+// Diagnostics ---------------------------------------------------------------------------------------------------------
+
+/// Occupancy snapshot of a [`SgMap`][crate::map::SgMap]'s backing arena, e.g. to gauge fragmentation.
+///
+/// This `struct` is created by the [`arena_stats`][crate::map::SgMap::arena_stats] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArenaStats {
+    /// Number of arena slots ever used, e.g. the high-water mark of the backing storage.
+    pub physical_len: usize,
+
+    /// Number of occupied slots, always equal to the map's [`len`][crate::map::SgMap::len].
+    pub live_nodes: usize,
+
+    /// Number of vacated slots below `physical_len`, reused by later insertions before the arena grows.
+    pub free_slots: usize,
+}
+
+/// Memory footprint of a [`SgMap`][crate::map::SgMap], e.g. to validate a capacity choice against a stack budget.
+///
+/// This `struct` is created by the [`memory_report`][crate::map::SgMap::memory_report] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemoryReport {
+    /// Total size of the map, in bytes. Fixed at compile time, since all storage is inline.
+    pub stack_bytes: usize,
+
+    /// Size of an individual arena node, in bytes.
+    pub node_bytes: usize,
+
+    /// Maximum number of map pairs, e.g. the const `N`.
+    pub capacity: usize,
+
+    /// Current number of map pairs.
+    pub used: usize,
+}
@@ -1,19 +1,29 @@
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
 use core::iter::FromIterator;
-use core::ops::RangeBounds;
-use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+use core::ops::{Index, RangeBounds};
 
 use crate::set_types::{
-    Difference, Intersection, IntoIter, Iter, Range, SymmetricDifference, Union,
+    Difference, Drain, Intersection, IntoIter, Iter, Range, Runs, SymmetricDifference,
+    TakeMatching, Union,
 };
-use crate::tree::{SgError, SgTree};
+use crate::tree::{node::NodeGetHelper, Idx, SgError, SgTree, SmallNode};
+
+use tinyvec::ArrayVec;
+
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
 
 /// Safe, fallible, embedded-friendly ordered set.
 ///
 /// ### Fallible APIs
 ///
 /// * [`try_insert`][crate::set::SgSet::try_insert]
+/// * [`try_insert_ref`][crate::set::SgSet::try_insert_ref]
 /// * [`try_append`][crate::set::SgSet::try_append]
 /// * [`try_extend`][crate::set::SgSet::try_extend]
 /// * [`try_from_iter`][crate::set::SgSet::try_from_iter]
@@ -22,17 +32,40 @@ use crate::tree::{SgError, SgTree};
 /// [`TryFrom`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) isn't implemented because it would collide with the blanket implementation.
 /// See [this open GitHub issue](https://github.com/rust-lang/rust/issues/50133#issuecomment-64690839) from 2018,
 /// this is a known Rust limitation that should be fixed via specialization in the future.
+/// To move into a different capacity, use [`resize`][crate::set::SgSet::resize].
+///
+/// ### Read-only APIs
+///
+/// Methods taking `&self` (e.g. `get`, `contains`, `first`, `iter`) never rebalance the tree or physically reorder its backing arena.
+/// Only mutating methods, like [`retain`][crate::set::SgSet::retain], may re-sort the arena.
 ///
 /// ### Attribution Note
 ///
 /// The majority of API examples and descriptions are adapted or directly copied from the standard library's [`BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html).
 /// The goal is to offer embedded developers familiar, ergonomic APIs on resource constrained systems that otherwise don't get the luxury of dynamic collections.
-#[derive(Default, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
-pub struct SgSet<T: Ord + Default, const N: usize> {
+#[derive(Clone, PartialEq, Eq, Ord, PartialOrd)]
+pub struct SgSet<T: Ord, const N: usize> {
     pub(crate) bst: SgTree<T, (), N>,
 }
 
-impl<T: Ord + Default, const N: usize> SgSet<T, N> {
+impl<T: Ord, const N: usize> SgSet<T, N> {
+    /// Total capacity, e.g. maximum number of set elements, as an associated constant.
+    /// Usable in const contexts, unlike the equivalent [`capacity`][SgSet::capacity] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// const NEEDED: usize = 8;
+    /// const SLOTS: [Option<u32>; SgSet::<u32, 10>::CAPACITY] = [None; SgSet::<u32, 10>::CAPACITY];
+    /// assert!(SLOTS.len() >= NEEDED);
+    ///
+    /// let set = SgSet::<u32, 10>::new();
+    /// assert_eq!(SgSet::<u32, 10>::CAPACITY, set.capacity());
+    /// ```
+    pub const CAPACITY: usize = N;
+
     /// Makes a new, empty `SgSet`.
     ///
     /// # Examples
@@ -96,6 +129,75 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.rebal_param()
     }
 
+    /// Get the number of times this set rebalanced itself, since creation or the last
+    /// [`reset_rebal_cnt`][SgSet::reset_rebal_cnt] call. Wraps if `usize::MAX` is exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<usize, 100>::new();
+    /// for i in 0..100 {
+    ///     set.insert(i);
+    /// }
+    ///
+    /// // Sequential inserts skew the tree, forcing rebuilds
+    /// assert!(set.rebal_cnt() > 0);
+    /// ```
+    pub fn rebal_cnt(&self) -> usize {
+        self.bst.rebal_cnt()
+    }
+
+    /// Reset the rebalance count to zero, e.g. at the start of a measurement window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<usize, 100>::new();
+    /// for i in 0..100 {
+    ///     set.insert(i);
+    /// }
+    ///
+    /// set.reset_rebal_cnt();
+    /// assert_eq!(set.rebal_cnt(), 0);
+    /// ```
+    pub fn reset_rebal_cnt(&mut self) {
+        self.bst.reset_rebal_cnt();
+    }
+
+    /// Register a callback invoked after every rebuild, passed the number of elements in the rebuilt subtree.
+    /// Pass `None` to remove a registered hook.
+    /// The hook survives [`clear`][SgSet::clear] and `clone`, but isn't carried over by `resize`.
+    ///
+    /// Requires the `profiling` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicUsize, Ordering};
+    /// use scapegoat::SgSet;
+    ///
+    /// static MAX_REBUILD: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let mut set = SgSet::<usize, 100>::new();
+    /// set.set_rebuild_hook(Some(|size| {
+    ///     MAX_REBUILD.fetch_max(size, Ordering::Relaxed);
+    /// }));
+    ///
+    /// for i in 0..100 {
+    ///     set.insert(i);
+    /// }
+    ///
+    /// assert!(MAX_REBUILD.load(Ordering::Relaxed) > 1);
+    /// ```
+    #[cfg(feature = "profiling")]
+    pub fn set_rebuild_hook(&mut self, hook: Option<fn(usize)>) {
+        self.bst.set_rebuild_hook(hook);
+    }
+
     /// Total capacity, e.g. maximum number of set elements.
     ///
     /// # Examples
@@ -111,6 +213,138 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.capacity()
     }
 
+    /// Returns `true` if `additional` more set elements are guaranteed to fit, e.g. `len() + additional <= N`.
+    ///
+    /// Capacity is fixed by `N`, so unlike `Vec::try_reserve` nothing is allocated.
+    /// This is a pre-insertion guard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<usize, 3>::new();
+    /// set.insert(1);
+    ///
+    /// assert!(set.can_insert(2));
+    /// assert!(!set.can_insert(3));
+    /// ```
+    pub fn can_insert(&self, additional: usize) -> bool {
+        self.bst.can_insert(additional)
+    }
+
+    /// Returns `Err(SgError::StackCapacityExceeded)` unless `additional` more set elements are guaranteed to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<usize, 3>::new();
+    /// set.insert(1);
+    ///
+    /// assert_eq!(set.ensure_capacity(2), Ok(()));
+    /// assert_eq!(set.ensure_capacity(3), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn ensure_capacity(&self, additional: usize) -> Result<(), SgError> {
+        self.bst.ensure_capacity(additional)
+    }
+
+    /// Clones the set into a new set of capacity `M`, e.g. to reclaim memory after a transient high-water mark.
+    /// The rebalance parameter is preserved.
+    ///
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the set's length,
+    /// or `Err(SgError::MaximumCapacityExceeded)` if `M` exceeds `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgSet, SgError};
+    ///
+    /// let mut big = SgSet::<_, 100>::new();
+    /// big.insert(1);
+    /// big.insert(2);
+    ///
+    /// let small = big.clone_resize::<2>().unwrap();
+    /// assert_eq!(small.capacity(), 2);
+    /// assert!(small.iter().eq(big.iter()));
+    ///
+    /// assert_eq!(big.clone_resize::<1>().unwrap_err(), SgError::StackCapacityExceeded);
+    /// ```
+    pub fn clone_resize<const M: usize>(&self) -> Result<SgSet<T, M>, SgError>
+    where
+        T: Clone,
+    {
+        self.bst.clone_resize().map(|bst| SgSet { bst })
+    }
+
+    /// Moves the set into a new set of capacity `M`, consuming it.
+    /// Unlike [`clone_resize`][SgSet::clone_resize], no `Clone` bound is required.
+    /// The rebalance parameter is preserved.
+    ///
+    /// Returns `Err(SgError::StackCapacityExceeded)` if `M` is less than the set's length,
+    /// or `Err(SgError::MaximumCapacityExceeded)` if `M` exceeds `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgSet, SgError};
+    ///
+    /// let mut big = SgSet::<_, 100>::new();
+    /// big.insert("a".to_string());
+    /// big.insert("b".to_string());
+    ///
+    /// let small = big.resize::<2>().unwrap();
+    /// assert_eq!(small.capacity(), 2);
+    /// assert!(small.into_iter().eq(vec!["a".to_string(), "b".to_string()]));
+    ///
+    /// let big: SgSet<_, 100> = (0..3).collect();
+    /// assert_eq!(big.resize::<2>().unwrap_err(), SgError::StackCapacityExceeded);
+    /// ```
+    pub fn resize<const M: usize>(self) -> Result<SgSet<T, M>, SgError> {
+        self.bst.resize().map(|bst| SgSet { bst })
+    }
+
+    /// Panics if internal invariants don't hold: key ordering, a single parent per node,
+    /// and cached min/max pointing at the extremes.
+    /// A correctness aid for downstream tests, compiled out if `debug_assertions` are disabled.
+    ///
+    /// Requires the `debug_checks` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<_, 10>::new();
+    /// set.insert(2);
+    /// set.insert(1);
+    ///
+    /// set.debug_assert_valid();
+    /// ```
+    #[cfg(feature = "debug_checks")]
+    pub fn debug_assert_valid(&self) {
+        self.bst.debug_assert_valid();
+    }
+
+    /// Checks internal invariants without panicking: every node link is in-bounds and occupied,
+    /// every node is reachable exactly once (no cycles), values are ordered, and the cached length and min/max are correct.
+    /// Unlike `debug_assert_valid`, this check is always available and never compiled out.
+    ///
+    /// Returns [`SgError::CorruptState`] on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = vec![3, 1, 2].into_iter().collect();
+    /// assert!(set.verify().is_ok());
+    /// ```
+    pub fn verify(&self) -> Result<(), SgError> {
+        self.bst.verify()
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
     ///
     /// # Examples
@@ -205,6 +439,11 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
     /// If the set did not have this value present, `true` is returned.
     /// If the set did have this value present, `false` is returned, and the entry is overwritten.
     ///
+    /// # Panics
+    ///
+    /// Panics if a new value is inserted into a full set. See [`try_insert`][SgSet::try_insert] for a fallible alternative,
+    /// available in all configurations.
+    ///
     /// # Examples
     ///
     /// ```
@@ -262,9 +501,120 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
     where
         T: Ord,
     {
-        match self.bst.try_insert(value, ()) {
-            Ok(opt_val) => Ok(opt_val.is_none()),
-            Err(_) => Err(SgError::StackCapacityExceeded),
+        self.bst
+            .try_insert(value, ())
+            .map(|opt_val| opt_val.is_none())
+    }
+
+    /// Adds every value in `values` to the set, returning how many weren't already present.
+    /// Like [`insert`][SgSet::insert], an existing equal element is overwritten.
+    ///
+    /// Unlike repeated [`insert`][SgSet::insert] calls or [`Extend`], rebalancing is deferred until the whole batch is
+    /// inserted: at most one rebuild (of the entire set) is performed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a new value is inserted into a full set. See [`try_insert_slice`][SgSet::try_insert_slice]
+    /// for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<_, 10> = [1, 5].iter().cloned().collect();
+    ///
+    /// assert_eq!(set.insert_slice(&[4, 2, 5, 3, 2]), 3);
+    /// assert!(set.iter().eq([1, 2, 3, 4, 5].iter()));
+    /// ```
+    pub fn insert_slice(&mut self, values: &[T]) -> usize
+    where
+        T: Ord + Clone,
+    {
+        match self.try_insert_slice(values) {
+            Ok(added_cnt) => added_cnt,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Adds every value in `values` to the set, deferring rebalancing until the batch completes.
+    /// Returns `Err` on the first new value that doesn't fit, with all values before it inserted.
+    /// Else the `Ok` contains how many values weren't already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<_, 3>::new();
+    ///
+    /// assert_eq!(set.try_insert_slice(&[2, 1, 2]), Ok(2));
+    /// assert_eq!(set.try_insert_slice(&[1, 3, 4, 5]), Err(SgError::StackCapacityExceeded));
+    /// assert!(set.iter().eq([1, 2, 3].iter()));
+    /// ```
+    pub fn try_insert_slice(&mut self, values: &[T]) -> Result<usize, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.bst
+            .try_insert_deferred(values.iter().map(|v| (v.clone(), ())))
+    }
+
+    /// Adds a value to the set if absent, keeping the existing element otherwise.
+    /// Returns a reference to the element now stored in the set, e.g. for interning.
+    ///
+    /// Unlike [`insert`][SgSet::insert], an existing equal element is *not* overwritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is absent and the set is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<String, 10>::new();
+    ///
+    /// let stored: *const String = set.insert_ref(String::from("a"));
+    /// assert!(core::ptr::eq(set.insert_ref(String::from("a")), stored));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    pub fn insert_ref(&mut self, value: T) -> &T
+    where
+        T: Ord,
+    {
+        let ngh: NodeGetHelper<Idx> = self.bst.internal_get(None, &value);
+        let idx = match ngh.node_idx() {
+            Some(idx) => idx,
+            None => self.bst.internal_balancing_insert::<Idx>(value, ()).1,
+        };
+
+        self.bst.arena[idx].key()
+    }
+
+    /// Adds a value to the set if absent, keeping the existing element otherwise.
+    /// Returns `Err` if the operation can't be completed,
+    /// else the `Ok` contains a reference to the element now stored in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<_, 1>::new();
+    ///
+    /// assert_eq!(set.try_insert_ref(2), Ok(&2));
+    /// assert_eq!(set.try_insert_ref(2), Ok(&2));
+    /// assert_eq!(set.try_insert_ref(3), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn try_insert_ref(&mut self, value: T) -> Result<&T, SgError>
+    where
+        T: Ord,
+    {
+        match self.bst.contains_key(&value) || (self.capacity() > self.len()) {
+            true => Ok(self.insert_ref(value)),
+            false => Err(SgTree::<T, (), N>::capacity_err()),
         }
     }
 
@@ -299,7 +649,7 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
             let map: crate::SgMap<T, (), N> = iter.into_iter().map(|e| (e, ())).collect();
             self.bst.try_extend(map.into_iter())
         } else {
-            Err(SgError::StackCapacityExceeded)
+            Err(SgTree::<T, (), N>::capacity_err())
         }
     }
 
@@ -390,6 +740,41 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.remove(value).is_some()
     }
 
+    /// Removes all values in the given range, returning the number of values removed.
+    /// Only in-range values are visited, unlike an equivalent [`retain`][SgSet::retain].
+    ///
+    /// The range may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the
+    /// ordering on the value type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<_, 10> = (0..8).collect();
+    ///
+    /// assert_eq!(set.remove_range(2..5), 3);
+    /// assert_eq!(set.remove_range(2..5), 0);
+    /// assert!(set.iter().eq([0, 1, 5, 6, 7].iter()));
+    ///
+    /// assert_eq!(set.remove_range(..=1), 2);
+    /// assert!(set.iter().eq([5, 6, 7].iter()));
+    /// ```
+    pub fn remove_range<K, R>(&mut self, range: R) -> usize
+    where
+        K: Ord + ?Sized,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+    {
+        self.bst.remove_range(range)
+    }
+
     /// Splits the collection into two at the given value. Returns everything after the given value,
     /// including the value.
     ///
@@ -427,6 +812,79 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Consumes the set, splitting it into two at the given value.
+    /// Returns everything before the given value, and everything after it (including the value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = (0..8).collect();
+    /// let len = set.len();
+    ///
+    /// let (lo, hi) = set.split_at_key(&3);
+    ///
+    /// assert_eq!(lo.len() + hi.len(), len);
+    /// assert!(lo.iter().eq([0, 1, 2].iter()));
+    /// assert!(hi.iter().eq([3, 4, 5, 6, 7].iter()));
+    /// ```
+    pub fn split_at_key<Q>(mut self, value: &Q) -> (SgSet<T, N>, SgSet<T, N>)
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let hi = self.split_off(value);
+        (self, hi)
+    }
+
+    /// Consumes the set, splitting it into two: elements for which `f` returns `true`, and the rest.
+    /// Like [`Iterator::partition`], but each half is a set of capacity `N`, sorted and balanced.
+    /// The rebalance parameter is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = (0..8).collect();
+    ///
+    /// let (even, odd) = set.partition(|x| x % 2 == 0);
+    ///
+    /// assert!(even.iter().eq([0, 2, 4, 6].iter()));
+    /// assert!(odd.iter().eq([1, 3, 5, 7].iter()));
+    /// ```
+    pub fn partition<F>(self, mut f: F) -> (SgSet<T, N>, SgSet<T, N>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (matching, rest) = self.bst.partition(|k, _| f(k));
+        (SgSet { bst: matching }, SgSet { bst: rest })
+    }
+
+    /// Returns an iterator over maximal runs of consecutive elements, as `(first, last)` pairs in ascending order.
+    /// Two consecutive elements `a < b` belong to the same run if `adjacent(a, b)` returns `true`.
+    /// An element with no adjacent neighbors is a run of its own, i.e. `(x, x)`.
+    ///
+    /// # Examples
+    ///
+    /// Collapsing a set of IDs into contiguous ranges:
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let ids: SgSet<u32, 10> = vec![1, 2, 3, 7, 9, 10].into_iter().collect();
+    ///
+    /// let ranges: Vec<_> = ids.runs(|a, b| a + 1 == *b).collect();
+    /// assert_eq!(ranges, [(&1, &3), (&7, &7), (&9, &10)]);
+    /// ```
+    pub fn runs<F>(&self, adjacent: F) -> Runs<'_, T, F, N>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        Runs::new(self, adjacent)
+    }
+
     /// Adds a value to the set, replacing the existing value, if any, that is equal to the given
     /// one. Returns the replaced value.
     ///
@@ -510,11 +968,70 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.retain(|k, _| f(k));
     }
 
-    /// Returns a reference to the value in the set, if any, that is equal to the given value.
+    /// Retains only the elements specified by the predicate, which also receives each element's position.
     ///
-    /// The value may be any borrowed form of the set's value type,
-    /// but the ordering on the borrowed form *must* match the
-    /// ordering on the value type.
+    /// In other words, remove all elements `e` such that `f(i, &e)` returns `false`,
+    /// where `i` is the element's 0-based position in ascending order (counting removed elements too).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<i32, 10> = (0..8).map(|x| x * 2).collect();
+    /// // Keep even-positioned elements
+    /// set.retain_indexed(|i, _| i % 2 == 0);
+    /// assert!(set.iter().eq([0, 4, 8, 12].iter()));
+    /// ```
+    pub fn retain_indexed<F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(usize, &T) -> bool,
+    {
+        let mut pos = 0;
+        self.bst.retain(|k, _| {
+            let keep = f(pos, k);
+            pos += 1;
+            keep
+        });
+    }
+
+    /// Creates an iterator that removes and yields the elements for which `f` returns `true`, in ascending order.
+    ///
+    /// Unlike [`retain`][SgSet::retain], removed elements are returned. The iterator is lazy: `f` is only called
+    /// as it's advanced, and if it's dropped early, every element not yet visited is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<i32, 10> = (1..=8).collect();
+    ///
+    /// // Only consume the first two matches
+    /// let evens: Vec<i32> = set.take_matching(|x| x % 2 == 0).take(2).collect();
+    /// assert_eq!(evens, [2, 4]);
+    /// assert!(set.iter().eq([1, 3, 5, 6, 7, 8].iter()));
+    ///
+    /// // Full consumption
+    /// assert!(set.take_matching(|x| x % 2 == 0).eq([6, 8]));
+    /// assert!(set.iter().eq([1, 3, 5, 7].iter()));
+    /// ```
+    #[doc(alias = "extract_if")]
+    #[doc(alias = "drain_filter")]
+    pub fn take_matching<F>(&mut self, f: F) -> TakeMatching<'_, T, F, N>
+    where
+        T: Ord,
+        F: FnMut(&T) -> bool,
+    {
+        TakeMatching::new(self, f)
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to the given value.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the
+    /// ordering on the value type.
     ///
     /// # Examples
     ///
@@ -549,6 +1066,32 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.clear()
     }
 
+    /// Clears the set, returning all elements as an iterator, in ascending order.
+    /// The set is left empty, ready for reuse, even if the iterator is dropped before being fully consumed.
+    ///
+    /// Unlike [`into_iter`][crate::set::SgSet::into_iter], the set itself isn't consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut v = SgSet::<_, 10>::new();
+    /// v.insert(3);
+    /// v.insert(1);
+    /// v.insert(2);
+    ///
+    /// let drained: Vec<usize> = v.drain().collect();
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert!(v.is_empty());
+    ///
+    /// v.insert(4);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        Drain::new(self)
+    }
+
     /// Returns `true` if the set contains a value.
     ///
     /// The value may be any borrowed form of the set's value type,
@@ -716,6 +1259,140 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Constructs a double-ended iterator over values greater than or equal to `start`.
+    /// Equivalent to `range(start..)`, without needing the range syntax.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = (0..8).collect();
+    ///
+    /// assert!(set.iter_from(&5).eq([5, 6, 7].iter()));
+    /// assert!(set.iter_from(&5).eq(set.range(5..)));
+    /// ```
+    pub fn iter_from<Q>(&self, start: &Q) -> Range<'_, T, N>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.range((Included(start), Unbounded))
+    }
+
+    /// Constructs a double-ended iterator over values strictly less than `end`.
+    /// Equivalent to `range(..end)`, without needing the range syntax.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = (0..8).collect();
+    ///
+    /// assert!(set.iter_until(&3).eq([0, 1, 2].iter()));
+    /// assert!(set.iter_until(&3).eq(set.range(..3)));
+    /// ```
+    pub fn iter_until<Q>(&self, end: &Q) -> Range<'_, T, N>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.range((Unbounded, Excluded(end)))
+    }
+
+    /// Returns the number of elements within a range, without iterating it.
+    /// Useful for deciding whether a potentially expensive scan is worthwhile.
+    ///
+    /// Runs in `O(log n)` if the `fast_rebalance` feature is enabled (uses cached subtree sizes),
+    /// else `O(log n + k)` for `k` elements in range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = (0..8).collect();
+    ///
+    /// assert_eq!(set.range_count(2..5), 3);
+    /// assert_eq!(set.range_count(..=3), 4);
+    /// assert_eq!(set.range_count(10..20), 0);
+    /// ```
+    pub fn range_count<Q, R>(&self, range: R) -> usize
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+    {
+        SgTree::<T, (), N>::assert_valid_range(&range);
+        self.bst.range_count(&range)
+    }
+
+    /// Returns `true` if every element of the set is within a range, e.g. to validate against an allowed interval.
+    /// An empty set is vacuously within any range.
+    ///
+    /// Runs in `O(1)`: only the cached first and last elements are checked, nothing is iterated.
+    /// Unlike [`range`][SgSet::range], doesn't panic for `start > end` (no element is within such a range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = vec![2, 4, 6].into_iter().collect();
+    ///
+    /// assert!(set.all_in_range(2..=6));
+    /// assert!(set.all_in_range(..));
+    /// assert!(!set.all_in_range(2..6));
+    /// assert!(SgSet::<usize, 10>::new().all_in_range(0..0));
+    /// ```
+    pub fn all_in_range<Q, R>(&self, range: R) -> bool
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+    {
+        self.bst.keys_all_in_range(&range)
+    }
+
+    /// Returns `true` if at least one element of the set is within a range.
+    /// Equivalent to `range(range).next().is_some()`, without collecting the range.
+    ///
+    /// Runs in `O(log n)`.
+    /// Unlike [`range`][SgSet::range], doesn't panic for `start > end` (no element is within such a range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = vec![2, 4, 6].into_iter().collect();
+    ///
+    /// assert!(set.any_in_range(3..5));
+    /// assert!(!set.any_in_range(3..4));
+    /// assert!(!set.any_in_range(7..));
+    /// ```
+    #[doc(alias = "contains_range")]
+    pub fn any_in_range<Q, R>(&self, range: R) -> bool
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+    {
+        self.bst.keys_any_in_range(&range)
+    }
+
     /// Returns an iterator over values representing set difference, e.g., values in `self` but not in `other`, in ascending order.
     ///
     /// # Examples
@@ -830,6 +1507,270 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         Union::new(self, other)
     }
 
+    /// Returns the number of values in both `self` and `other`, without building the intersection.
+    /// Computed in a single merge pass, `O(n + m)`, without allocation.
+    /// Useful for checking if a union or [`append`][SgSet::append] will fit in a given capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let b = SgSet::<_, 10>::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+    ///
+    /// assert_eq!(a.intersection_count(&b), a.intersection(&b).count());
+    /// assert_eq!(a.intersection_count(&b), 5);
+    /// ```
+    #[doc(alias = "intersection_len")]
+    pub fn intersection_count(&self, other: &SgSet<T, N>) -> usize {
+        self.bst.intersect_cnt(&other.bst)
+    }
+
+    /// Returns the number of values in `self` or `other`, without building the union.
+    /// Useful for checking if a union will fit in a given capacity.
+    /// Computed in a single merge pass, `O(n + m)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let b = SgSet::<_, 10>::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+    ///
+    /// assert_eq!(a.union_count(&b), a.union(&b).count());
+    /// assert!(a.union_count(&b) > a.capacity());
+    /// ```
+    pub fn union_count(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        self.len() + other.len() - self.intersection_count(other)
+    }
+
+    /// Returns the number of values in `self` but not in `other`, without building the difference.
+    /// Computed in a single merge pass, `O(n + m)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let b = SgSet::<_, 10>::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+    ///
+    /// assert_eq!(a.difference_count(&b), a.difference(&b).count());
+    /// assert_eq!(b.difference_count(&a), 5);
+    /// ```
+    pub fn difference_count(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        self.len() - self.intersection_count(other)
+    }
+
+    /// Returns the number of values in `self` or `other`, but not both, without building the symmetric difference.
+    /// Computed in a single merge pass, `O(n + m)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let b = SgSet::<_, 10>::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+    ///
+    /// assert_eq!(a.symmetric_difference_count(&b), a.symmetric_difference(&b).count());
+    /// assert_eq!(a.symmetric_difference_count(&b), 10);
+    /// ```
+    pub fn symmetric_difference_count(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        self.len() + other.len() - (2 * self.intersection_count(other))
+    }
+
+    /// Returns a new set of capacity `O` holding the union of `self` and `other`, which may differ in capacity.
+    /// Returns [`SgError::StackCapacityExceeded`] if the union doesn't fit in `O` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::{SgSet, SgError};
+    ///
+    /// let a: SgSet<_, 4> = [1, 2, 3].iter().cloned().collect();
+    /// let b: SgSet<_, 8> = [3, 4, 5, 6].iter().cloned().collect();
+    ///
+    /// let union: SgSet<_, 16> = a.union_sized(&b).unwrap();
+    /// assert!(union.iter().eq([1, 2, 3, 4, 5, 6].iter()));
+    ///
+    /// let too_small: Result<SgSet<_, 4>, _> = a.union_sized(&b);
+    /// assert_eq!(too_small, Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn union_sized<const M: usize, const O: usize>(
+        &self,
+        other: &SgSet<T, M>,
+    ) -> Result<SgSet<T, O>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.priv_merge_sized(other, |in_self, in_other| in_self || in_other)
+    }
+
+    /// Returns a new set of capacity `O` holding the intersection of `self` and `other`, which may differ in capacity.
+    /// Returns [`SgError::StackCapacityExceeded`] if the intersection doesn't fit in `O` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a: SgSet<_, 4> = [1, 2, 3].iter().cloned().collect();
+    /// let b: SgSet<_, 8> = [2, 3, 4, 5].iter().cloned().collect();
+    ///
+    /// let intersection: SgSet<_, 2> = a.intersection_sized(&b).unwrap();
+    /// assert!(intersection.iter().eq([2, 3].iter()));
+    /// ```
+    pub fn intersection_sized<const M: usize, const O: usize>(
+        &self,
+        other: &SgSet<T, M>,
+    ) -> Result<SgSet<T, O>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.priv_merge_sized(other, |in_self, in_other| in_self && in_other)
+    }
+
+    /// Returns a new set of capacity `O` holding the values in `self` but not in `other`, which may differ in capacity.
+    /// Returns [`SgError::StackCapacityExceeded`] if the difference doesn't fit in `O` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a: SgSet<_, 4> = [1, 2, 3].iter().cloned().collect();
+    /// let b: SgSet<_, 8> = [2, 3, 4, 5].iter().cloned().collect();
+    ///
+    /// let diff: SgSet<_, 16> = a.difference_sized(&b).unwrap();
+    /// assert!(diff.iter().eq([1].iter()));
+    /// ```
+    pub fn difference_sized<const M: usize, const O: usize>(
+        &self,
+        other: &SgSet<T, M>,
+    ) -> Result<SgSet<T, O>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.priv_merge_sized(other, |in_self, in_other| in_self && !in_other)
+    }
+
+    /// Returns a new set of capacity `O` holding the values in `self` or `other` but not both, which may differ in capacity.
+    /// Returns [`SgError::StackCapacityExceeded`] if the symmetric difference doesn't fit in `O` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let a: SgSet<_, 4> = [1, 2, 3].iter().cloned().collect();
+    /// let b: SgSet<_, 8> = [2, 3, 4, 5].iter().cloned().collect();
+    ///
+    /// let sym_diff: SgSet<_, 16> = a.symmetric_difference_sized(&b).unwrap();
+    /// assert!(sym_diff.iter().eq([1, 4, 5].iter()));
+    /// ```
+    pub fn symmetric_difference_sized<const M: usize, const O: usize>(
+        &self,
+        other: &SgSet<T, M>,
+    ) -> Result<SgSet<T, O>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.priv_merge_sized(other, |in_self, in_other| in_self != in_other)
+    }
+
+    /// Mutates `self` to equal `target`, removing values not in `target` and cloning in those missing from `self`.
+    /// Returns `(removed_count, added_count)`.
+    ///
+    /// Walks both sets together in sorted order, so membership is resolved in `O(n + m)` comparisons.
+    /// Values common to both sets are left untouched, and removals happen before insertions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut replica: SgSet<_, 10> = vec![1, 2, 3, 4].into_iter().collect();
+    /// let primary: SgSet<_, 10> = vec![2, 4, 6].into_iter().collect();
+    ///
+    /// assert_eq!(replica.apply_diff(&primary), (2, 1));
+    /// assert_eq!(replica, primary);
+    /// ```
+    pub fn apply_diff(&mut self, target: &SgSet<T, N>) -> (usize, usize)
+    where
+        T: Ord + Clone,
+    {
+        let len_old = self.len();
+
+        // Remove values not in target
+        let mut target_iter = target.iter().peekable();
+        self.retain(|v| {
+            while target_iter.next_if(|t| *t < v).is_some() {}
+            target_iter.peek() == Some(&v)
+        });
+
+        let removed_cnt = len_old - self.len();
+
+        // Remaining values are a subset of target, so any target value not matched in order is missing
+        let mut self_iter = self.iter().peekable();
+        let missing: ArrayVec<[Option<&T>; N]> = target
+            .iter()
+            .filter(|t| self_iter.next_if_eq(t).is_none())
+            .map(Some)
+            .collect();
+
+        let added_cnt = missing.len();
+        for v in missing.into_iter().flatten() {
+            self.insert(v.clone());
+        }
+
+        (removed_cnt, added_cnt)
+    }
+
+    /// Removes every value in `other` from `self`, returning the number of values removed.
+    /// The in-place counterpart of [`difference`][SgSet::difference].
+    ///
+    /// Walks both sets together in sorted order, so membership is resolved in `O(n + m)` comparisons
+    /// rather than a separate lookup per value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut a: SgSet<_, 10> = vec![1, 2, 3, 4, 5].into_iter().collect();
+    /// let b: SgSet<_, 10> = vec![2, 4, 6].into_iter().collect();
+    ///
+    /// assert_eq!(a.subtract(&b), 2);
+    /// assert!(a.into_iter().eq([1, 3, 5]));
+    /// ```
+    #[doc(alias = "difference_in_place")]
+    pub fn subtract(&mut self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        let len_old = self.len();
+
+        let mut other_iter = other.iter().peekable();
+        self.retain(|v| {
+            while other_iter.next_if(|o| *o < v).is_some() {}
+            other_iter.peek() != Some(&v)
+        });
+
+        len_old - self.len()
+    }
+
     /// Returns `true` if the set contains no elements.
     ///
     /// # Examples
@@ -933,14 +1874,78 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
     {
         other.is_subset(self)
     }
+
+    // Private API -----------------------------------------------------------------------------------------------------
+
+    // Walk `self` and `other` together in sorted order, cloning each value `keep(in_self, in_other)` selects into a new set.
+    fn priv_merge_sized<const M: usize, const O: usize, F>(
+        &self,
+        other: &SgSet<T, M>,
+        keep: F,
+    ) -> Result<SgSet<T, O>, SgError>
+    where
+        T: Ord + Clone,
+        F: Fn(bool, bool) -> bool,
+    {
+        let mut self_iter = self.iter().peekable();
+        let mut other_iter = other.iter().peekable();
+
+        let merge_iter = core::iter::from_fn(|| loop {
+            let ord = match (self_iter.peek(), other_iter.peek()) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return None,
+            };
+
+            let (opt_val, in_self, in_other) = match ord {
+                Ordering::Less => (self_iter.next(), true, false),
+                Ordering::Greater => (other_iter.next(), false, true),
+                Ordering::Equal => {
+                    other_iter.next();
+                    (self_iter.next(), true, true)
+                }
+            };
+
+            if keep(in_self, in_other) {
+                return opt_val.map(|v| (v.clone(), ()));
+            }
+        });
+
+        SgTree::try_from_sorted_iter(merge_iter).map(|bst| SgSet { bst })
+    }
 }
 
 // Convenience Traits --------------------------------------------------------------------------------------------------
 
+// Default
+impl<T: Ord, const N: usize> Default for SgSet<T, N> {
+    /// Equivalent to [`SgSet::new`]: an empty set of capacity `N` with the default rebalance parameter.
+    /// Storage is inline, nothing is allocated.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Hash
+impl<T, const N: usize> Hash for SgSet<T, N>
+where
+    T: Ord + Hash,
+{
+    /// Hashes the length, then every element in sorted order, like `BTreeSet`.
+    /// Independent of capacity `N`, insertion order, and internal arena layout.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
 // Debug
 impl<T, const N: usize> Debug for SgSet<T, N>
 where
-    T: Ord + Default + Debug,
+    T: Ord + Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_set()
@@ -949,10 +1954,61 @@ where
     }
 }
 
+// defmt
+#[cfg(feature = "defmt")]
+impl<T, const N: usize> defmt::Format for SgSet<T, N>
+where
+    T: Ord + defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{{");
+        for (i, (k, _)) in self.bst.iter().enumerate() {
+            if i > 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{}", k);
+        }
+        defmt::write!(f, "}}");
+    }
+}
+
+// Comparison with std's set
+#[cfg(feature = "std")]
+impl<T, const N: usize> PartialEq<BTreeSet<T>> for SgSet<T, N>
+where
+    T: Ord,
+{
+    /// Compares elements in ascending order.
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let sgs = SgSet::from([2, 3, 1]);
+    /// let bts = [1, 2, 3].iter().cloned().collect::<BTreeSet<_>>();
+    /// assert_eq!(sgs, bts);
+    /// assert_eq!(bts, sgs);
+    /// ```
+    fn eq(&self, other: &BTreeSet<T>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+// Comparison with std's set, reversed
+#[cfg(feature = "std")]
+impl<T, const N: usize> PartialEq<SgSet<T, N>> for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &SgSet<T, N>) -> bool {
+        other == self
+    }
+}
+
 // From array.
 impl<T, const N: usize> From<[T; N]> for SgSet<T, N>
 where
-    T: Ord + Default,
+    T: Ord,
 {
     /// ```
     /// use scapegoat::SgSet;
@@ -975,10 +2031,45 @@ where
     }
 }
 
+// Indexing by rank
+impl<T, const N: usize> Index<usize> for SgSet<T, N>
+where
+    T: Ord,
+{
+    type Output = T;
+
+    /// Returns a reference to the `rank`-th smallest element, e.g. `set[0]` is the minimum.
+    ///
+    /// `O(log n)` if the [`fast_rebalance` feature](https://github.com/tnballo/scapegoat/blob/master/CONFIG.md) is enabled,
+    /// since per-node subtree sizes are maintained. Otherwise `O(rank)`, via in-order iteration.
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let set = SgSet::from([30, 10, 20]);
+    /// assert_eq!(set[0], 10);
+    /// assert_eq!(set[2], 30);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rank >= self.len()`.
+    fn index(&self, rank: usize) -> &Self::Output {
+        match self.bst.select(rank) {
+            Some((elem, _)) => elem,
+            None => panic!(
+                "Index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                rank
+            ),
+        }
+    }
+}
+
 // Construct from iterator.
 impl<T, const N: usize> FromIterator<T> for SgSet<T, N>
 where
-    T: Ord + Default,
+    T: Ord,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut sgs = SgSet::new();
@@ -990,7 +2081,7 @@ where
 // Extension from iterator.
 impl<T, const N: usize> Extend<T> for SgSet<T, N>
 where
-    T: Ord + Default,
+    T: Ord,
 {
     fn extend<TreeIter: IntoIterator<Item = T>>(&mut self, iter: TreeIter) {
         self.bst.extend(iter.into_iter().map(|e| (e, ())));
@@ -1000,7 +2091,7 @@ where
 // Extension from reference iterator.
 impl<'a, T, const N: usize> Extend<&'a T> for SgSet<T, N>
 where
-    T: 'a + Ord + Default + Copy,
+    T: 'a + Ord + Copy,
 {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
@@ -1010,7 +2101,7 @@ where
 // General Iterators ---------------------------------------------------------------------------------------------------
 
 // Reference iterator
-impl<'a, T: Ord + Default, const N: usize> IntoIterator for &'a SgSet<T, N> {
+impl<'a, T: Ord, const N: usize> IntoIterator for &'a SgSet<T, N> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T, N>;
 
@@ -1020,7 +2111,7 @@ impl<'a, T: Ord + Default, const N: usize> IntoIterator for &'a SgSet<T, N> {
 }
 
 // Consuming iterator
-impl<T: Ord + Default, const N: usize> IntoIterator for SgSet<T, N> {
+impl<T: Ord, const N: usize> IntoIterator for SgSet<T, N> {
     type Item = T;
     type IntoIter = IntoIter<T, N>;
 
@@ -1031,7 +2122,7 @@ impl<T: Ord + Default, const N: usize> IntoIterator for SgSet<T, N> {
 
 // Operator Overloading ------------------------------------------------------------------------------------------------
 
-impl<T: Ord + Default + Clone, const N: usize> Sub<&SgSet<T, N>> for &SgSet<T, N> {
+impl<T: Ord + Clone, const N: usize> Sub<&SgSet<T, N>> for &SgSet<T, N> {
     type Output = SgSet<T, N>;
 
     /// Returns the difference of `self` and `rhs` as a new `SgSet<T, N>`.
@@ -1053,7 +2144,7 @@ impl<T: Ord + Default + Clone, const N: usize> Sub<&SgSet<T, N>> for &SgSet<T, N
     }
 }
 
-impl<T: Ord + Default + Clone, const N: usize> BitAnd<&SgSet<T, N>> for &SgSet<T, N> {
+impl<T: Ord + Clone, const N: usize> BitAnd<&SgSet<T, N>> for &SgSet<T, N> {
     type Output = SgSet<T, N>;
 
     /// Returns the intersection of `self` and `rhs` as a new `SgSet<T, N>`.
@@ -1075,7 +2166,7 @@ impl<T: Ord + Default + Clone, const N: usize> BitAnd<&SgSet<T, N>> for &SgSet<T
     }
 }
 
-impl<T: Ord + Default + Clone, const N: usize> BitOr<&SgSet<T, N>> for &SgSet<T, N> {
+impl<T: Ord + Clone, const N: usize> BitOr<&SgSet<T, N>> for &SgSet<T, N> {
     type Output = SgSet<T, N>;
 
     /// Returns the union of `self` and `rhs` as a new `SgSet<T, N>`.
@@ -1097,7 +2188,7 @@ impl<T: Ord + Default + Clone, const N: usize> BitOr<&SgSet<T, N>> for &SgSet<T,
     }
 }
 
-impl<T: Ord + Default + Clone, const N: usize> BitXor<&SgSet<T, N>> for &SgSet<T, N> {
+impl<T: Ord + Clone, const N: usize> BitXor<&SgSet<T, N>> for &SgSet<T, N> {
     type Output = SgSet<T, N>;
 
     /// Returns the symmetric difference of `self` and `rhs` as a new `SgSet<T, N>`.
@@ -1118,3 +2209,109 @@ impl<T: Ord + Default + Clone, const N: usize> BitXor<&SgSet<T, N>> for &SgSet<T
         self.symmetric_difference(rhs).cloned().collect()
     }
 }
+
+impl<T: Ord, const N: usize> SubAssign<&SgSet<T, N>> for SgSet<T, N> {
+    /// Removes every element of `rhs` from `self`, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut a: SgSet<_, 10> = vec![1, 2, 3].into_iter().collect();
+    /// let b: SgSet<_, 10> = vec![3, 4, 5].into_iter().collect();
+    ///
+    /// a -= &b;
+    /// assert!(a.into_iter().eq([1, 2]));
+    /// ```
+    fn sub_assign(&mut self, rhs: &SgSet<T, N>) {
+        self.subtract(rhs);
+    }
+}
+
+impl<T: Ord, const N: usize> BitAndAssign<&SgSet<T, N>> for SgSet<T, N> {
+    /// Retains only the elements of `self` also present in `rhs`, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut a: SgSet<_, 10> = vec![1, 2, 3].into_iter().collect();
+    /// let b: SgSet<_, 10> = vec![2, 3, 4].into_iter().collect();
+    ///
+    /// a &= &b;
+    /// assert!(a.into_iter().eq([2, 3]));
+    /// ```
+    fn bitand_assign(&mut self, rhs: &SgSet<T, N>) {
+        self.retain(|x| rhs.contains(x));
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> BitOrAssign<&SgSet<T, N>> for SgSet<T, N> {
+    /// Inserts every element of `rhs` into `self`, in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the union exceeds the set's stack capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut a: SgSet<_, 10> = vec![1, 2, 3].into_iter().collect();
+    /// let b: SgSet<_, 10> = vec![3, 4, 5].into_iter().collect();
+    ///
+    /// a |= &b;
+    /// assert!(a.into_iter().eq([1, 2, 3, 4, 5]));
+    /// ```
+    fn bitor_assign(&mut self, rhs: &SgSet<T, N>) {
+        self.extend(rhs.iter().cloned());
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> BitXorAssign<&SgSet<T, N>> for SgSet<T, N> {
+    /// Toggles membership of every element of `rhs` in `self`, in place.
+    /// Common elements are removed before new ones are inserted,
+    /// so capacity is only exceeded if the final symmetric difference doesn't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symmetric difference exceeds the set's stack capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgSet;
+    ///
+    /// let mut a: SgSet<_, 10> = vec![1, 2, 3].into_iter().collect();
+    /// let b: SgSet<_, 10> = vec![3, 4, 5].into_iter().collect();
+    ///
+    /// a ^= &b;
+    /// assert!(a.into_iter().eq([1, 2, 4, 5]));
+    /// ```
+    fn bitxor_assign(&mut self, rhs: &SgSet<T, N>) {
+        // Single merge pass: drop common elements, flagging which `rhs` elements they matched
+        let mut rhs_iter = rhs.iter().peekable();
+        let mut is_common: ArrayVec<[bool; N]> = ArrayVec::new();
+        self.retain(|x| {
+            while rhs_iter.next_if(|y| *y < x).is_some() {
+                is_common.push(false);
+            }
+            let common = rhs_iter.next_if_eq(&x).is_some();
+            if common {
+                is_common.push(true);
+            }
+            !common
+        });
+
+        // Unflagged (including unvisited trailing) `rhs` elements weren't in `self`
+        let flags = is_common.into_iter().chain(core::iter::repeat(false));
+        for (x, common) in rhs.iter().zip(flags) {
+            if !common {
+                self.insert(x.clone());
+            }
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use core::cmp::Ordering;
-use core::iter::FusedIterator;
+use core::iter::{FusedIterator, Peekable};
 
 use crate::set::SgSet;
-use crate::tree::{Idx, IntoIter as TreeIntoIter, Iter as TreeIter, SmallNode};
+use crate::tree::{
+    clone_idx_iter, Drain as TreeDrain, Idx, IntoIter as TreeIntoIter, Iter as TreeIter, SmallNode,
+    TakeMatching as TreeTakeMatching,
+};
 
 use smallnum::SmallUnsigned;
 use tinyvec::{ArrayVec, ArrayVecIterator};
@@ -13,11 +16,11 @@ use tinyvec::{ArrayVec, ArrayVecIterator};
 ///
 /// This `struct` is created by the [`iter`][crate::set::SgSet::iter] method on [`SgSet`][crate::set::SgSet].
 /// See its documentation for more.
-pub struct Iter<'a, T: Ord + Default, const N: usize> {
+pub struct Iter<'a, T: Ord, const N: usize> {
     ref_iter: TreeIter<'a, T, (), N>,
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iter<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iter<'a, T, N> {
     /// Construct reference iterator.
     pub(crate) fn new(set: &'a SgSet<T, N>) -> Self {
         Iter {
@@ -26,31 +29,62 @@ impl<'a, T: Ord + Default, const N: usize> Iter<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iterator for Iter<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iterator for Iter<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.ref_iter.next().map(|(k, _)| k)
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.ref_iter.nth(n).map(|(k, _)| k)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.ref_iter.last().map(|(k, _)| k)
+    }
+
+    // Items are yielded in ascending order
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
-impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
+impl<'a, T: Ord, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.ref_iter.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<'a, T: Ord, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
     fn len(&self) -> usize {
         self.ref_iter.len()
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> FusedIterator for Iter<'a, T, N> {}
+impl<'a, T: Ord, const N: usize> FusedIterator for Iter<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for Iter<'a, T, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
 
 /// An owning iterator over the items of a [`SgSet`][crate::set::SgSet].
 ///
 /// This `struct` is created by the [`into_iter`][crate::set::SgSet::into_iter] method on [`SgSet`][crate::set::SgSet]
 /// (provided by the IntoIterator trait). See its documentation for more.
-pub struct IntoIter<T: Ord + Default, const N: usize> {
+pub struct IntoIter<T: Ord, const N: usize> {
     cons_iter: TreeIntoIter<T, (), N>,
 }
 
-impl<T: Ord + Default, const N: usize> IntoIter<T, N> {
+impl<T: Ord, const N: usize> IntoIter<T, N> {
     /// Construct owning iterator.
     pub(crate) fn new(set: SgSet<T, N>) -> Self {
         IntoIter {
@@ -59,21 +93,120 @@ impl<T: Ord + Default, const N: usize> IntoIter<T, N> {
     }
 }
 
-impl<T: Ord + Default, const N: usize> Iterator for IntoIter<T, N> {
+impl<T: Ord, const N: usize> Iterator for IntoIter<T, N> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.cons_iter.next().map(|(k, _)| k)
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cons_iter.nth(n).map(|(k, _)| k)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.cons_iter.last().map(|(k, _)| k)
+    }
+
+    // Items are yielded in ascending order
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T: Ord, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cons_iter.next_back().map(|(k, _)| k)
+    }
 }
 
-impl<T: Ord + Default, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+impl<T: Ord, const N: usize> ExactSizeIterator for IntoIter<T, N> {
     fn len(&self) -> usize {
         self.cons_iter.len()
     }
 }
 
-impl<T: Ord + Default, const N: usize> FusedIterator for IntoIter<T, N> {}
+impl<T: Ord, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+/// A draining iterator over the items of a [`SgSet`][crate::set::SgSet].
+///
+/// This `struct` is created by the [`drain`][crate::set::SgSet::drain] method on [`SgSet`][crate::set::SgSet].
+/// See its documentation for more.
+pub struct Drain<'a, T: Ord, const N: usize> {
+    drain_iter: TreeDrain<'a, T, (), N>,
+}
+
+impl<'a, T: Ord, const N: usize> Drain<'a, T, N> {
+    /// Construct draining iterator.
+    pub(crate) fn new(set: &'a mut SgSet<T, N>) -> Self {
+        Drain {
+            drain_iter: TreeDrain::new(&mut set.bst),
+        }
+    }
+}
+
+impl<'a, T: Ord, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain_iter.next().map(|(k, _)| k)
+    }
+}
+
+impl<'a, T: Ord, const N: usize> ExactSizeIterator for Drain<'a, T, N> {
+    fn len(&self) -> usize {
+        self.drain_iter.len()
+    }
+}
+
+impl<'a, T: Ord, const N: usize> FusedIterator for Drain<'a, T, N> {}
+
+/// A lazy, filtering draining iterator over the items of a [`SgSet`][crate::set::SgSet].
+///
+/// This `struct` is created by the [`take_matching`][crate::set::SgSet::take_matching] method on [`SgSet`][crate::set::SgSet].
+/// See its documentation for more.
+pub struct TakeMatching<'a, T: Ord, F, const N: usize> {
+    take_iter: TreeTakeMatching<'a, T, (), N>,
+    pred: F,
+}
+
+impl<'a, T: Ord, F, const N: usize> TakeMatching<'a, T, F, N>
+where
+    F: FnMut(&T) -> bool,
+{
+    /// Construct filtering draining iterator.
+    pub(crate) fn new(set: &'a mut SgSet<T, N>, pred: F) -> Self {
+        TakeMatching {
+            take_iter: TreeTakeMatching::new(&mut set.bst),
+            pred,
+        }
+    }
+}
+
+impl<'a, T: Ord, F, const N: usize> Iterator for TakeMatching<'a, T, F, N>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let TakeMatching { take_iter, pred } = self;
+        take_iter.next_matching(|k, _| pred(k)).map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.take_iter.remaining()))
+    }
+}
+
+impl<'a, T: Ord, F, const N: usize> FusedIterator for TakeMatching<'a, T, F, N> where
+    F: FnMut(&T) -> bool
+{
+}
 
 /*
 Workaround Note:
@@ -98,14 +231,14 @@ const PLACEHOLDER_2N: usize = 4096;
 ///
 /// This `struct` is created by the [`intersection`][crate::set::SgSet::difference] method on [`SgSet`][crate::set::SgSet].
 /// See its documentation for more.
-pub struct Intersection<'a, T: Ord + Default, const N: usize> {
+pub struct Intersection<'a, T: Ord, const N: usize> {
     pub(crate) inner: ArrayVecIterator<[Idx; N]>,
     set_this: &'a SgSet<T, N>,
     total_cnt: usize,
     spent_cnt: usize,
 }
 
-impl<'a, T: Ord + Default, const N: usize> Intersection<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Intersection<'a, T, N> {
     /// Construct `Intersection` iterator.
     /// Values that are both in `this` and `other`.
     pub(crate) fn new(this: &'a SgSet<T, N>, other: &SgSet<T, N>) -> Self {
@@ -145,7 +278,7 @@ impl<'a, T: Ord + Default, const N: usize> Intersection<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iterator for Intersection<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iterator for Intersection<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
@@ -162,14 +295,25 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Intersection<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Intersection<'a, T, N> {
+impl<'a, T: Ord, const N: usize> ExactSizeIterator for Intersection<'a, T, N> {
     fn len(&self) -> usize {
         debug_assert!(self.spent_cnt <= self.total_cnt);
         self.total_cnt - self.spent_cnt
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> FusedIterator for Intersection<'a, T, N> {}
+impl<'a, T: Ord, const N: usize> FusedIterator for Intersection<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for Intersection<'a, T, N> {
+    fn clone(&self) -> Self {
+        Intersection {
+            inner: clone_idx_iter(&self.inner),
+            set_this: self.set_this,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
 
 // Difference Iterator -------------------------------------------------------------------------------------------------
 
@@ -180,14 +324,14 @@ impl<'a, T: Ord + Default, const N: usize> FusedIterator for Intersection<'a, T,
 ///
 /// This `struct` is created by the [`difference`][crate::set::SgSet::difference] method
 /// on [`SgSet`][crate::set::SgSet]. See its documentation for more.
-pub struct Difference<'a, T: Ord + Default, const N: usize> {
+pub struct Difference<'a, T: Ord, const N: usize> {
     pub(crate) inner: ArrayVecIterator<[Idx; N]>,
     set_this: &'a SgSet<T, N>,
     total_cnt: usize,
     spent_cnt: usize,
 }
 
-impl<'a, T: Ord + Default, const N: usize> Difference<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Difference<'a, T, N> {
     /// Construct `Difference` iterator.
     /// Values that are in `this` but not in `other`.
     pub(crate) fn new(this: &'a SgSet<T, N>, other: &SgSet<T, N>) -> Self {
@@ -210,7 +354,7 @@ impl<'a, T: Ord + Default, const N: usize> Difference<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iterator for Difference<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iterator for Difference<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
@@ -227,14 +371,25 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Difference<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Difference<'a, T, N> {
+impl<'a, T: Ord, const N: usize> ExactSizeIterator for Difference<'a, T, N> {
     fn len(&self) -> usize {
         debug_assert!(self.spent_cnt <= self.total_cnt);
         self.total_cnt - self.spent_cnt
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> FusedIterator for Difference<'a, T, N> {}
+impl<'a, T: Ord, const N: usize> FusedIterator for Difference<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for Difference<'a, T, N> {
+    fn clone(&self) -> Self {
+        Difference {
+            inner: clone_idx_iter(&self.inner),
+            set_this: self.set_this,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
 
 // Symmetric Difference Iterator ---------------------------------------------------------------------------------------
 
@@ -245,7 +400,7 @@ impl<'a, T: Ord + Default, const N: usize> FusedIterator for Difference<'a, T, N
 ///
 /// This `struct` is created by the [`symmetric_difference`][crate::set::SgSet::symmetric_difference]
 /// method on [`SgSet`][crate::set::SgSet]. See its documentation for more.
-pub struct SymmetricDifference<'a, T: Ord + Default, const N: usize> {
+pub struct SymmetricDifference<'a, T: Ord, const N: usize> {
     pub(crate) inner: ArrayVecIterator<[(Idx, bool); PLACEHOLDER_2N]>, // TODO: placeholder
     set_this: &'a SgSet<T, N>,
     set_other: &'a SgSet<T, N>,
@@ -253,7 +408,7 @@ pub struct SymmetricDifference<'a, T: Ord + Default, const N: usize> {
     spent_cnt: usize,
 }
 
-impl<'a, T: Ord + Default, const N: usize> SymmetricDifference<'a, T, N> {
+impl<'a, T: Ord, const N: usize> SymmetricDifference<'a, T, N> {
     /// Construct `SymmetricDifference` iterator.
     /// Values that are in `this` or in `other` but not in both.
     pub(crate) fn new(this: &'a SgSet<T, N>, other: &'a SgSet<T, N>) -> Self {
@@ -290,7 +445,7 @@ impl<'a, T: Ord + Default, const N: usize> SymmetricDifference<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iterator for SymmetricDifference<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iterator for SymmetricDifference<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
@@ -316,14 +471,26 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for SymmetricDifference<'a,
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for SymmetricDifference<'a, T, N> {
+impl<'a, T: Ord, const N: usize> ExactSizeIterator for SymmetricDifference<'a, T, N> {
     fn len(&self) -> usize {
         debug_assert!(self.spent_cnt <= self.total_cnt);
         self.total_cnt - self.spent_cnt
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> FusedIterator for SymmetricDifference<'a, T, N> {}
+impl<'a, T: Ord, const N: usize> FusedIterator for SymmetricDifference<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for SymmetricDifference<'a, T, N> {
+    fn clone(&self) -> Self {
+        SymmetricDifference {
+            inner: clone_idx_iter(&self.inner),
+            set_this: self.set_this,
+            set_other: self.set_other,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
 
 // Union Iterator ------------------------------------------------------------------------------------------------------
 
@@ -334,7 +501,7 @@ impl<'a, T: Ord + Default, const N: usize> FusedIterator for SymmetricDifference
 ///
 /// This `struct` is created by the [`union`][crate::set::SgSet::difference] method on [`SgSet`][crate::set::SgSet].
 /// See its documentation for more.
-pub struct Union<'a, T: Ord + Default, const N: usize> {
+pub struct Union<'a, T: Ord, const N: usize> {
     pub(crate) inner: ArrayVecIterator<[(Idx, bool); PLACEHOLDER_2N]>,
     set_this: &'a SgSet<T, N>,
     set_other: &'a SgSet<T, N>,
@@ -342,7 +509,7 @@ pub struct Union<'a, T: Ord + Default, const N: usize> {
     spent_cnt: usize,
 }
 
-impl<'a, T: Ord + Default, const N: usize> Union<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Union<'a, T, N> {
     /// Construct `Union` iterator.
     /// Values in `this` or `other`, without duplicates.
     pub(crate) fn new(this: &'a SgSet<T, N>, other: &'a SgSet<T, N>) -> Self {
@@ -377,7 +544,7 @@ impl<'a, T: Ord + Default, const N: usize> Union<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iterator for Union<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iterator for Union<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
@@ -403,14 +570,26 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Union<'a, T, N> {
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Union<'a, T, N> {
+impl<'a, T: Ord, const N: usize> ExactSizeIterator for Union<'a, T, N> {
     fn len(&self) -> usize {
         debug_assert!(self.spent_cnt <= self.total_cnt);
         self.total_cnt - self.spent_cnt
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> FusedIterator for Union<'a, T, N> {}
+impl<'a, T: Ord, const N: usize> FusedIterator for Union<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for Union<'a, T, N> {
+    fn clone(&self) -> Self {
+        Union {
+            inner: clone_idx_iter(&self.inner),
+            set_this: self.set_this,
+            set_other: self.set_other,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
 
 // Range APIs ----------------------------------------------------------------------------------------------------------
 
@@ -420,12 +599,12 @@ impl<'a, T: Ord + Default, const N: usize> FusedIterator for Union<'a, T, N> {}
 /// See its documentation for more.
 ///
 /// [`range`]: SgSet::range
-pub struct Range<'a, T: Ord + Default, const N: usize> {
+pub struct Range<'a, T: Ord, const N: usize> {
     pub(crate) table: &'a SgSet<T, N>,
     pub(crate) node_idx_iter: <ArrayVec<[usize; N]> as IntoIterator>::IntoIter,
 }
 
-impl<'a, T: Ord + Default, const N: usize> Iterator for Range<'a, T, N> {
+impl<'a, T: Ord, const N: usize> Iterator for Range<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -433,9 +612,15 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Range<'a, T, N> {
         let node = &self.table.bst.arena[node_idx];
         Some(node.key())
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let node_idx = self.node_idx_iter.nth(n)?;
+        let node = &self.table.bst.arena[node_idx];
+        Some(node.key())
+    }
 }
 
-impl<'a, T: Ord + Default, const N: usize> DoubleEndedIterator for Range<'a, T, N> {
+impl<'a, T: Ord, const N: usize> DoubleEndedIterator for Range<'a, T, N> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let node_idx = self.node_idx_iter.next_back()?;
         let node = &self.table.bst.arena[node_idx];
@@ -443,4 +628,69 @@ impl<'a, T: Ord + Default, const N: usize> DoubleEndedIterator for Range<'a, T,
     }
 }
 
-impl<'a, T: Ord + Default, const N: usize> FusedIterator for Range<'a, T, N> {}
+impl<'a, T: Ord, const N: usize> FusedIterator for Range<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for Range<'a, T, N> {
+    fn clone(&self) -> Self {
+        Range {
+            table: self.table,
+            node_idx_iter: clone_idx_iter(&self.node_idx_iter),
+        }
+    }
+}
+
+// Run APIs ------------------------------------------------------------------------------------------------------------
+
+/// An iterator over maximal runs of adjacent items in a [`SgSet`], as `(first, last)` pairs.
+///
+/// This `struct` is created by the [`runs`] method on [`SgSet`].
+/// See its documentation for more.
+///
+/// [`runs`]: SgSet::runs
+pub struct Runs<'a, T: Ord, F, const N: usize> {
+    inner: Peekable<Iter<'a, T, N>>,
+    adjacent: F,
+}
+
+impl<'a, T: Ord, F, const N: usize> Runs<'a, T, F, N>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    /// Construct run iterator.
+    pub(crate) fn new(set: &'a SgSet<T, N>, adjacent: F) -> Self {
+        Runs {
+            inner: set.iter().peekable(),
+            adjacent,
+        }
+    }
+}
+
+impl<'a, T: Ord, F, const N: usize> Iterator for Runs<'a, T, F, N>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.inner.next()?;
+        let mut last = first;
+
+        let adjacent = &mut self.adjacent;
+        while let Some(next) = self.inner.next_if(|next| adjacent(last, next)) {
+            last = next;
+        }
+
+        Some((first, last))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // At least one run if non-empty, at most one per item
+        let (lo, hi) = self.inner.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+impl<'a, T: Ord, F, const N: usize> FusedIterator for Runs<'a, T, F, N> where
+    F: FnMut(&T, &T) -> bool
+{
+}
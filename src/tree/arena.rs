@@ -17,19 +17,15 @@ If caller obeys contract, `U` will be smallest unsigned capable of representing
 
 /// An arena allocator, meta programmable for low memory footprint.
 #[derive(Clone, Debug)]
-pub struct Arena<K: Default, V: Default, U: Default, const N: usize> {
+pub struct Arena<K, V, U: Default, const N: usize> {
     vec: ArrayVec<[Option<Node<K, V, U>>; N]>,
 
     #[cfg(not(feature = "low_mem_insert"))]
     free_list: ArrayVec<[U; N]>,
 }
 
-impl<
-        K: Default,
-        V: Default,
-        U: Default + Copy + SmallUnsigned + Ord + PartialEq + PartialOrd,
-        const N: usize,
-    > Arena<K, V, U, N>
+impl<K, V, U: Default + Copy + SmallUnsigned + Ord + PartialEq + PartialOrd, const N: usize>
+    Arena<K, V, U, N>
 {
     // TODO: is this function necessary?
     /// Const associated constructor for index scratch vector.
@@ -134,6 +130,72 @@ impl<
         }
     }
 
+    /// Get mutable references to the nodes at two distinct, known-good indexes.
+    /// This function can panic if either index is invalid or both are equal.
+    pub fn hard_get_pair_mut(
+        &mut self,
+        idx_a: usize,
+        idx_b: usize,
+    ) -> (&mut Node<K, V, U>, &mut Node<K, V, U>) {
+        assert_ne!(
+            idx_a, idx_b,
+            "Internal invariant failed: aliasing mutable node references requested."
+        );
+
+        // Split so the borrow checker can see the references are disjoint
+        let (lo_idx, hi_idx) = (idx_a.min(idx_b), idx_a.max(idx_b));
+        let (lo_slice, hi_slice) = self.vec.split_at_mut(hi_idx);
+        match (lo_slice[lo_idx].as_mut(), hi_slice[0].as_mut()) {
+            (Some(lo), Some(hi)) if idx_a < idx_b => (lo, hi),
+            (Some(lo), Some(hi)) => (hi, lo),
+            _ => panic!("Internal invariant failed: attempted access of node at invalid index."),
+        }
+    }
+
+    /// Clone into an arena with a new value type, every node keeps its index.
+    pub fn clone_map_vals<B, F: FnMut(&V) -> B>(&self, mut f: F) -> Arena<K, B, U, N>
+    where
+        K: Clone,
+    {
+        Arena {
+            vec: self
+                .vec
+                .iter()
+                .map(|opt_node| opt_node.as_ref().map(|node| node.clone_map_val(&mut f)))
+                .collect(),
+
+            #[cfg(not(feature = "low_mem_insert"))]
+            free_list: self.free_list,
+        }
+    }
+
+    /// Convert into an arena with a new key type, every node keeps its index.
+    /// `f` is called in the order of `sorted_idxs`, which must name every occupied index exactly once.
+    pub fn map_keys<B, F: FnMut(K) -> B>(
+        mut self,
+        sorted_idxs: &[usize],
+        mut f: F,
+    ) -> Arena<B, V, U, N> {
+        let mut vec: ArrayVec<[Option<Node<B, V, U>>; N]> =
+            (0..self.vec.len()).map(|_| None).collect();
+
+        for idx in sorted_idxs {
+            vec[*idx] = self.vec[*idx].take().map(|node| node.map_key(&mut f));
+        }
+
+        debug_assert!(
+            self.vec.iter().all(|n| n.is_none()),
+            "Internal invariant failed: key mapping skipped an occupied index!"
+        );
+
+        Arena {
+            vec,
+
+            #[cfg(not(feature = "low_mem_insert"))]
+            free_list: self.free_list,
+        }
+    }
+
     /// Sort the arena in caller-requested order and update all tree metadata accordingly
     /// `unwraps` will never panic if caller invariants upheld (checked via `debug_assert`)
     pub fn sort(
@@ -189,6 +251,27 @@ impl<
         self.vec.len()
     }
 
+    /// Returns true if the free list names every unoccupied index exactly once, and nothing else.
+    /// Always true if the `low_mem_insert` feature is enabled, since there's no free list.
+    pub fn free_list_is_consistent(&self) -> bool {
+        #[cfg(not(feature = "low_mem_insert"))]
+        {
+            let mut sorted_free = self.free_list;
+            sorted_free.sort_unstable();
+
+            let free_cnt = self.vec.iter().filter(|n| n.is_none()).count();
+            let all_free = sorted_free
+                .iter()
+                .all(|idx| (idx.usize() < self.vec.len()) && !self.is_occupied(idx.usize()));
+            let no_dups = sorted_free.windows(2).all(|w| w[0] != w[1]);
+
+            (sorted_free.len() == free_cnt) && all_free && no_dups
+        }
+
+        #[cfg(feature = "low_mem_insert")]
+        true
+    }
+
     /// Returns true if the index is occupied, e.g. `Some(node)`.
     pub fn is_occupied(&self, idx: usize) -> bool {
         (idx < self.vec.len()) && (self.vec[idx].is_some())
@@ -204,9 +287,10 @@ impl<
 
 /// Immutable indexing.
 /// Indexed location MUST be occupied.
-impl<K: Default, V: Default, U: Default, const N: usize> Index<usize> for Arena<K, V, U, N> {
+impl<K, V, U: Default, const N: usize> Index<usize> for Arena<K, V, U, N> {
     type Output = Node<K, V, U>;
 
+    #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         match &self.vec[index] {
             Some(node) => node,
@@ -217,7 +301,8 @@ impl<K: Default, V: Default, U: Default, const N: usize> Index<usize> for Arena<
 
 /// Mutable indexing
 /// Indexed location MUST be occupied.
-impl<K: Default, V: Default, U: Default, const N: usize> IndexMut<usize> for Arena<K, V, U, N> {
+impl<K, V, U: Default, const N: usize> IndexMut<usize> for Arena<K, V, U, N> {
+    #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         match self.vec.index_mut(index) {
             Some(node) => node,
@@ -227,8 +312,8 @@ impl<K: Default, V: Default, U: Default, const N: usize> IndexMut<usize> for Are
 }
 
 impl<
-        K: Ord + Default,
-        V: Default,
+        K: Ord,
+        V,
         U: Default + Copy + SmallUnsigned + Ord + PartialEq + PartialOrd,
         const N: usize,
     > Default for Arena<K, V, U, N>
@@ -243,11 +328,11 @@ NOTE: This is draft code for upgrades when `feature(generic_const_exprs)` stabil
 
 // Wrapper Iterators ---------------------------------------------------------------------------------------------------
 
-pub struct ArenaIter<'a, K: Default, V: Default, U, const N: usize> {
+pub struct ArenaIter<'a, K, V, U, const N: usize> {
     arena_iter: core::slice::Iter<'a, Option<Node<K, V, U>>>,
 }
 
-impl<'a, K: Default, V: Default, U, const N: usize> ArenaIter<'a, K, V, U, N> {
+impl<'a, K, V, U, const N: usize> ArenaIter<'a, K, V, U, N> {
     pub fn new(arena: &'a Arena<K, V, U, N>) -> Self {
         ArenaIter {
             arena_iter: arena.vec.iter(),
@@ -255,7 +340,7 @@ impl<'a, K: Default, V: Default, U, const N: usize> ArenaIter<'a, K, V, U, N> {
     }
 }
 
-impl<'a, K: Default, V: Default, U: SmallUnsigned + Copy, const N: usize> Iterator for ArenaIter<'a, K, V, U, N> {
+impl<'a, K, V, U: SmallUnsigned + Copy, const N: usize> Iterator for ArenaIter<'a, K, V, U, N> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -266,11 +351,11 @@ impl<'a, K: Default, V: Default, U: SmallUnsigned + Copy, const N: usize> Iterat
     }
 }
 
-pub struct ArenaIterMut<'a, K: Default, V: Default, U, const N: usize> {
+pub struct ArenaIterMut<'a, K, V, U, const N: usize> {
     arena_iter_mut: core::slice::IterMut<'a, Option<Node<K, V, U>>>,
 }
 
-impl<'a, K: Default, V: Default, U, const N: usize> ArenaIterMut<'a, K, V, U, N> {
+impl<'a, K, V, U, const N: usize> ArenaIterMut<'a, K, V, U, N> {
     pub fn new(arena: &'a mut Arena<K, V, U, N>) -> Self {
         ArenaIterMut {
             arena_iter_mut: arena.vec.iter_mut(),
@@ -278,7 +363,7 @@ impl<'a, K: Default, V: Default, U, const N: usize> ArenaIterMut<'a, K, V, U, N>
     }
 }
 
-impl<'a, K: Default, V: Default, U: SmallUnsigned + Copy, const N: usize> Iterator for ArenaIterMut<'a, K, V, U, N> {
+impl<'a, K, V, U: SmallUnsigned + Copy, const N: usize> Iterator for ArenaIterMut<'a, K, V, U, N> {
     type Item = (&'a K, &'a mut V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -332,7 +417,11 @@ mod tests {
         let n_1_idx = arena.add(1, "n/a");
         assert_eq!(arena[n_1_idx].val(), &"n/a");
         let n_1_mut_ref = &mut arena[n_1_idx];
-        n_1_mut_ref.set_val("This is a value. There are many like it but this one is mine.");
+        assert_eq!(
+            n_1_mut_ref
+                .replace_val("This is a value. There are many like it but this one is mine."),
+            "n/a"
+        );
         assert_ne!(arena[n_1_idx].val(), &"n/a");
     }
 
@@ -1,5 +1,8 @@
+use core::fmt;
+
 /// Errors for fallible operations.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[non_exhaustive]
 pub enum SgError {
     /// Cannot construct instance, maximum supported capacity exceeded.
@@ -12,9 +15,8 @@ pub enum SgError {
     /// Requested operation cannot complete, heap storage is full.
     HeapCapacityExceeded,
     */
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved3,
+    /// Requested operation cannot complete, stack storage has zero capacity (e.g. `N == 0`).
+    CapacityZero,
 
     /// Reserved for future use
     #[doc(hidden)]
@@ -34,6 +36,106 @@ pub enum SgError {
 
     /// Invalid rebalance factor requested, cannot set.
     RebalanceFactorOutOfRange,
+
+    /// Internal state is inconsistent (e.g. a dangling or duplicate node link, a stale length or min/max).
+    CorruptState,
+}
+
+impl SgError {
+    /// Stable numeric code for this error, e.g. for mapping into a project-wide error type without matching exhaustively.
+    /// Codes start at `1` (so `0` is free to mean "no error") and are never reassigned, even if variants are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scapegoat::SgError;
+    ///
+    /// assert_eq!(SgError::MaximumCapacityExceeded.as_code(), 1);
+    /// assert_eq!(SgError::StackCapacityExceeded.as_code(), 2);
+    /// assert_eq!(SgError::CapacityZero.as_code(), 3);
+    /// assert_eq!(SgError::RebalanceFactorOutOfRange.as_code(), 8);
+    /// assert_eq!(SgError::CorruptState.as_code(), 9);
+    /// ```
+    pub const fn as_code(&self) -> u8 {
+        match self {
+            SgError::MaximumCapacityExceeded => 1,
+            SgError::StackCapacityExceeded => 2,
+            SgError::CapacityZero => 3,
+            SgError::Reserved4 => 4,
+            SgError::Reserved5 => 5,
+            SgError::Reserved6 => 6,
+            SgError::Reserved7 => 7,
+            SgError::RebalanceFactorOutOfRange => 8,
+            SgError::CorruptState => 9,
+        }
+    }
+}
+
+impl fmt::Display for SgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SgError::MaximumCapacityExceeded => write!(f, "maximum capacity exceeded"),
+            SgError::StackCapacityExceeded => write!(f, "stack capacity exceeded"),
+            SgError::CapacityZero => write!(f, "zero capacity"),
+            SgError::Reserved4 | SgError::Reserved5 | SgError::Reserved6 | SgError::Reserved7 => {
+                write!(f, "reserved error")
+            }
+            SgError::RebalanceFactorOutOfRange => write!(f, "rebalance factor out of range"),
+            SgError::CorruptState => write!(f, "corrupt internal state"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SgError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::SgError;
+
+    #[test]
+    fn test_err_display() {
+        assert_eq!(
+            SgError::MaximumCapacityExceeded.to_string(),
+            "maximum capacity exceeded"
+        );
+        assert_eq!(
+            SgError::StackCapacityExceeded.to_string(),
+            "stack capacity exceeded"
+        );
+        assert_eq!(SgError::CapacityZero.to_string(), "zero capacity");
+        assert_eq!(
+            SgError::RebalanceFactorOutOfRange.to_string(),
+            "rebalance factor out of range"
+        );
+        assert_eq!(SgError::CorruptState.to_string(), "corrupt internal state");
+    }
+
+    #[test]
+    fn test_err_code() {
+        let errs = [
+            SgError::MaximumCapacityExceeded,
+            SgError::StackCapacityExceeded,
+            SgError::CapacityZero,
+            SgError::Reserved4,
+            SgError::Reserved5,
+            SgError::Reserved6,
+            SgError::Reserved7,
+            SgError::RebalanceFactorOutOfRange,
+            SgError::CorruptState,
+        ];
+
+        for (i, err) in errs.iter().enumerate() {
+            assert_eq!(err.as_code() as usize, i + 1);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_err_std_error() {
+        let err: Box<dyn std::error::Error> = Box::new(SgError::StackCapacityExceeded);
+        assert_eq!(err.to_string(), "stack capacity exceeded");
+    }
 }
 
 /*
@@ -1,6 +1,7 @@
-use core::iter::FusedIterator;
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, Take};
 
-use tinyvec::ArrayVec;
+use tinyvec::{Array, ArrayVec, ArrayVecIterator};
 
 use super::node::Node;
 use super::node_dispatch::SmallNode;
@@ -10,18 +11,21 @@ use super::tree::{Idx, SgTree};
 
 /// Uses iterative in-order tree traversal algorithm.
 /// Maintains a small stack of arena indexes (won't contain all indexes simultaneously for a balanced tree).
-pub struct Iter<'a, K: Default, V: Default, const N: usize> {
+/// Reverse traversal starts at the cached maximum and steps to in-order predecessors, so it doesn't need a second stack.
+pub struct Iter<'a, K, V, const N: usize> {
     bst: &'a SgTree<K, V, N>,
     idx_stack: ArrayVec<[usize; N]>,
+    opt_back_idx: Option<usize>,
     total_cnt: usize,
     spent_cnt: usize,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iter<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iter<'a, K, V, N> {
     pub fn new(bst: &'a SgTree<K, V, N>) -> Self {
         let mut ordered_iter = Iter {
             bst,
             idx_stack: ArrayVec::<[usize; N]>::new(),
+            opt_back_idx: None,
             total_cnt: bst.len(),
             spent_cnt: 0,
         };
@@ -45,12 +49,47 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iter<'a, K, V, N> {
 
         ordered_iter
     }
+
+    // In-order predecessor in `O(log n)`, without parent links: the rightmost node of the left subtree if present,
+    // otherwise the closest ancestor whose right subtree contains `idx`.
+    fn pred_idx(&self, idx: usize) -> Option<usize> {
+        let arena = &self.bst.arena;
+
+        if let Some(mut curr_idx) = arena[idx].left_idx() {
+            while let Some(gt_idx) = arena[curr_idx].right_idx() {
+                curr_idx = gt_idx;
+            }
+            return Some(curr_idx);
+        }
+
+        let key = arena[idx].key();
+        let mut opt_pred_idx = None;
+        let mut opt_curr_idx = self.bst.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &arena[curr_idx];
+            match key.cmp(node.key()) {
+                Ordering::Greater => {
+                    opt_pred_idx = Some(curr_idx);
+                    opt_curr_idx = node.right_idx();
+                }
+                Ordering::Less => opt_curr_idx = node.left_idx(),
+                Ordering::Equal => break,
+            }
+        }
+
+        opt_pred_idx
+    }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Iter<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for Iter<'a, K, V, N> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Remaining nodes may have already been yielded from the back
+        if self.len() == 0 {
+            return None;
+        }
+
         match self.idx_stack.pop() {
             Some(pop_idx) => {
                 let node = &self.bst.arena[pop_idx];
@@ -78,33 +117,139 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Iter<'a, K,
             None => None,
         }
     }
+
+    // Skips whole subtrees using cached sizes, `O(log n)` instead of `O(n)`
+    #[cfg(feature = "fast_rebalance")]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.idx_stack.clear();
+            self.spent_cnt = self.total_cnt;
+            return None;
+        }
+
+        let bst = self.bst;
+        let subtree_size =
+            |opt_idx: Option<usize>| opt_idx.map_or(0, |i| bst.arena[i].subtree_size());
+
+        while n > 0 {
+            // Skip the next in-order node
+            let pop_idx = self.idx_stack.pop()?;
+            let opt_right_idx = bst.arena[pop_idx].right_idx();
+            self.spent_cnt += 1;
+            n -= 1;
+
+            // Skip its entire right subtree, or descend into it
+            let right_size = subtree_size(opt_right_idx);
+            if n >= right_size {
+                self.spent_cnt += right_size;
+                n -= right_size;
+                continue;
+            }
+
+            let mut opt_curr_idx = opt_right_idx;
+            while let Some(curr_idx) = opt_curr_idx {
+                let node = &bst.arena[curr_idx];
+                let left_size = subtree_size(node.left_idx());
+
+                if n <= left_size {
+                    // Target is this node or in its left subtree
+                    self.idx_stack.push(curr_idx);
+                    opt_curr_idx = node.left_idx();
+                    if n == left_size {
+                        self.spent_cnt += left_size;
+                        n = 0;
+                        break;
+                    }
+                } else {
+                    // Skip this node and its left subtree
+                    self.spent_cnt += left_size + 1;
+                    n -= left_size + 1;
+                    opt_curr_idx = node.right_idx();
+                }
+            }
+        }
+
+        self.next()
+    }
+
+    // `O(1)` via the cached maximum, unless iteration from the back has already started
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for Iter<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let back_idx = match self.opt_back_idx {
+            Some(prev_idx) => self.pred_idx(prev_idx)?,
+            None => self.bst.max_idx,
+        };
+
+        self.opt_back_idx = Some(back_idx);
+        self.spent_cnt += 1;
+
+        let node = &self.bst.arena[back_idx];
+        Some((node.key(), node.val()))
+    }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
     fn len(&self) -> usize {
         debug_assert!(self.spent_cnt <= self.total_cnt);
         self.total_cnt - self.spent_cnt
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for Iter<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Iter<'a, K, V, N> {}
+
+// Manual impl, derive would needlessly require `K: Clone` and `V: Clone`
+impl<'a, K, V, const N: usize> Clone for Iter<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            bst: self.bst,
+            idx_stack: self.idx_stack,
+            opt_back_idx: self.opt_back_idx,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
+
+/// Clone the remaining items of an index iterator (`ArrayVecIterator` doesn't implement `Clone`).
+pub(crate) fn clone_idx_iter<A: Array>(iter: &ArrayVecIterator<A>) -> ArrayVecIterator<A>
+where
+    A::Item: Copy,
+{
+    iter.as_slice()
+        .iter()
+        .copied()
+        .collect::<ArrayVec<A>>()
+        .into_iter()
+}
 
 // Mutable Reference Iterator ------------------------------------------------------------------------------------------
 
+/// Cheats a little by sorting the arena in-place, then iterating over its occupied prefix.
 pub struct IterMut<'a, K, V, const N: usize> {
-    arena_iter_mut: core::slice::IterMut<'a, Option<Node<K, V, Idx>>>,
+    arena_iter_mut: Take<core::slice::IterMut<'a, Option<Node<K, V, Idx>>>>,
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> IterMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> IterMut<'a, K, V, N> {
     pub fn new(bst: &'a mut SgTree<K, V, N>) -> Self {
+        // Sorting packs nodes into the arena's front, any free slots trail them
+        let len = bst.len();
         bst.sort_arena();
         IterMut {
-            arena_iter_mut: bst.arena.iter_mut(),
+            arena_iter_mut: bst.arena.iter_mut().take(len),
         }
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for IterMut<'a, K, V, N> {
     type Item = (&'a K, &'a mut V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -115,9 +260,7 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a,
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator
-    for IterMut<'a, K, V, N>
-{
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for IterMut<'a, K, V, N> {
     fn next_back(&mut self) -> Option<Self::Item> {
         match self.arena_iter_mut.next_back() {
             Some(Some(node)) => Some(node.get_mut()),
@@ -126,40 +269,109 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
     fn len(&self) -> usize {
         self.arena_iter_mut.len()
     }
 }
 
-impl<'a, K: Ord + Default, V: Default, const N: usize> FusedIterator for IterMut<'a, K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for IterMut<'a, K, V, N> {}
 
 // Consuming Iterator --------------------------------------------------------------------------------------------------
 
 /// Cheats a little by using internal flattening logic to sort, instead of re-implementing proper traversal.
-/// Maintains a shrinking list of arena indexes, initialized with all of them.
-pub struct IntoIter<K: Default, V: Default, const N: usize> {
+/// Maintains a shrinking list of arena indexes, initialized with all of them and consumable from either end.
+pub struct IntoIter<K, V, const N: usize> {
     bst: SgTree<K, V, N>,
-    sorted_idxs: ArrayVec<[usize; N]>,
+    sorted_idxs: ArrayVecIterator<[usize; N]>,
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> IntoIter<K, V, N> {
+impl<K: Ord, V, const N: usize> IntoIter<K, V, N> {
     pub fn new(bst: SgTree<K, V, N>) -> Self {
-        let mut ordered_iter = IntoIter {
-            bst,
-            sorted_idxs: ArrayVec::<[usize; N]>::new(),
+        let sorted_idxs: ArrayVec<[usize; N]> = match bst.opt_root_idx {
+            Some(root_idx) => bst.flatten_subtree_to_sorted_idxs(root_idx),
+            None => ArrayVec::new(),
         };
 
-        if let Some(root_idx) = ordered_iter.bst.opt_root_idx {
-            ordered_iter.sorted_idxs = ordered_iter.bst.flatten_subtree_to_sorted_idxs(root_idx);
-            ordered_iter.sorted_idxs.reverse();
+        IntoIter {
+            bst,
+            sorted_idxs: sorted_idxs.into_iter(),
         }
+    }
 
-        ordered_iter
+    // The tree is consumed, so nodes are taken straight from the arena without maintaining its structure
+    fn take_kv(&mut self, idx: usize) -> Option<(K, V)> {
+        match self.bst.arena.remove(idx) {
+            Some(node) => Some(node.into_kv()),
+            None => {
+                debug_assert!(false, "Use of invalid index in consuming iterator!");
+                None
+            }
+        }
+    }
+}
+
+impl<K: Ord, V, const N: usize> Iterator for IntoIter<K, V, N> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.sorted_idxs.next()?;
+        self.take_kv(idx)
+    }
+
+    // Skipped entries are dropped immediately, like `BTreeMap`'s `IntoIter`
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for idx in self.sorted_idxs.by_ref().take(n) {
+            self.bst.arena.remove(idx);
+        }
+
+        self.next()
+    }
+
+    // `O(1)`, skipped entries are dropped with the tree
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<K: Ord, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.sorted_idxs.next_back()?;
+        self.take_kv(idx)
+    }
+}
+
+impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
+    fn len(&self) -> usize {
+        self.sorted_idxs.len()
+    }
+}
+
+impl<K: Ord, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+
+// Draining Iterator ---------------------------------------------------------------------------------------------------
+
+/// Like `IntoIter`, but borrows the tree mutably instead of consuming it.
+/// Any entries not yet yielded are cleared on drop, leaving the tree empty but reusable.
+pub struct Drain<'a, K: Ord, V, const N: usize> {
+    bst: &'a mut SgTree<K, V, N>,
+    sorted_idxs: ArrayVec<[usize; N]>,
+}
+
+impl<'a, K: Ord, V, const N: usize> Drain<'a, K, V, N> {
+    pub fn new(bst: &'a mut SgTree<K, V, N>) -> Self {
+        let mut sorted_idxs = ArrayVec::<[usize; N]>::new();
+
+        if let Some(root_idx) = bst.opt_root_idx {
+            sorted_idxs = bst.flatten_subtree_to_sorted_idxs(root_idx);
+            sorted_idxs.reverse();
+        }
+
+        Drain { bst, sorted_idxs }
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoIter<K, V, N> {
+impl<'a, K: Ord, V, const N: usize> Iterator for Drain<'a, K, V, N> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -167,7 +379,7 @@ impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoIter<K, V, N
             Some(idx) => match self.bst.priv_remove_by_idx(idx) {
                 Some((key, val)) => Some((key, val)),
                 None => {
-                    debug_assert!(false, "Use of invalid index in consuming iterator!");
+                    debug_assert!(false, "Use of invalid index in draining iterator!");
                     None
                 }
             },
@@ -176,10 +388,70 @@ impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoIter<K, V, N
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Drain<'a, K, V, N> {
     fn len(&self) -> usize {
         self.sorted_idxs.len()
     }
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Drain<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> Drop for Drain<'a, K, V, N> {
+    fn drop(&mut self) {
+        self.bst.clear();
+    }
+}
+
+// Filtered Draining Iterator ------------------------------------------------------------------------------------------
+
+/// Like `Drain`, but only removes entries matching a predicate supplied on each advance.
+/// Entries are visited in ascending key order. Any entries not yet visited are kept on drop.
+pub struct TakeMatching<'a, K: Ord, V, const N: usize> {
+    bst: &'a mut SgTree<K, V, N>,
+    sorted_idxs: ArrayVec<[usize; N]>,
+}
+
+impl<'a, K: Ord, V, const N: usize> TakeMatching<'a, K, V, N> {
+    pub fn new(bst: &'a mut SgTree<K, V, N>) -> Self {
+        let mut sorted_idxs = ArrayVec::<[usize; N]>::new();
+
+        if let Some(root_idx) = bst.opt_root_idx {
+            sorted_idxs = bst.flatten_subtree_to_sorted_idxs(root_idx);
+            sorted_idxs.reverse();
+        }
+
+        TakeMatching { bst, sorted_idxs }
+    }
+
+    /// Remove and return the next entry for which `pred` returns `true`, skipping (and keeping) the rest.
+    /// Removal is zero-copy, so the arena indexes of unvisited entries stay valid.
+    pub fn next_matching<F>(&mut self, mut pred: F) -> Option<(K, V)>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        while let Some(idx) = self.sorted_idxs.pop() {
+            let (k, v) = self.bst.arena[idx].get_mut();
+            if pred(k, v) {
+                let opt_kv = self.bst.priv_remove_by_idx(idx);
+                debug_assert!(
+                    opt_kv.is_some(),
+                    "Use of invalid index in filtered draining iterator!"
+                );
+                return opt_kv;
+            }
+        }
+
+        None
+    }
+
+    /// Number of entries not yet visited, an upper bound on remaining removals.
+    pub fn remaining(&self) -> usize {
+        self.sorted_idxs.len()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> Drop for TakeMatching<'a, K, V, N> {
+    fn drop(&mut self) {
+        self.bst.priv_sparse_rebuild();
+    }
+}
@@ -13,7 +13,8 @@ pub(super) mod node;
 pub use node::{Node, NodeGetHelper, NodeRebuildHelper};
 
 mod iter;
-pub use iter::{IntoIter, Iter, IterMut};
+pub(crate) use iter::clone_idx_iter;
+pub use iter::{Drain, IntoIter, Iter, IterMut, TakeMatching};
 
 mod error;
 pub use error::SgError;
@@ -43,9 +43,47 @@ impl<K, V, U: SmallUnsigned> Node<K, V, U> {
             subtree_size: U::checked_from(1),
         }
     }
+
+    /// Destructure into owned key and value.
+    pub fn into_kv(self) -> (K, V) {
+        (self.key, self.val)
+    }
+
+    /// Convert into a node with a new key type, computed from the old key.
+    /// Value and links are kept.
+    pub fn map_key<B, F: FnOnce(K) -> B>(self, f: F) -> Node<B, V, U> {
+        Node {
+            key: f(self.key),
+            val: self.val,
+            left_idx: self.left_idx,
+            right_idx: self.right_idx,
+
+            #[cfg(feature = "fast_rebalance")]
+            subtree_size: self.subtree_size,
+        }
+    }
+
+    /// Clone into a node with a new value type, computed from a reference to the old value.
+    /// Key and links are kept.
+    pub fn clone_map_val<B, F: FnOnce(&V) -> B>(&self, f: F) -> Node<K, B, U>
+    where
+        K: Clone,
+        U: Copy,
+    {
+        Node {
+            key: self.key.clone(),
+            val: f(&self.val),
+            left_idx: self.left_idx,
+            right_idx: self.right_idx,
+
+            #[cfg(feature = "fast_rebalance")]
+            subtree_size: self.subtree_size,
+        }
+    }
 }
 
-impl<K: Default, V: Default, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K, V, U> {
+impl<K, V, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K, V, U> {
+    #[inline]
     fn key(&self) -> &K {
         &self.key
     }
@@ -54,26 +92,25 @@ impl<K: Default, V: Default, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K
         self.key = key;
     }
 
-    fn take_key(&mut self) -> K {
-        core::mem::take(&mut self.key)
+    fn replace_key(&mut self, key: K) -> K {
+        core::mem::replace(&mut self.key, key)
     }
 
+    #[inline]
     fn val(&self) -> &V {
         &self.val
     }
 
+    #[inline]
     fn get_mut(&mut self) -> (&K, &mut V) {
         (&self.key, &mut self.val)
     }
 
-    fn take_val(&mut self) -> V {
-        core::mem::take(&mut self.val)
-    }
-
-    fn set_val(&mut self, val: V) {
-        self.val = val;
+    fn replace_val(&mut self, val: V) -> V {
+        core::mem::replace(&mut self.val, val)
     }
 
+    #[inline]
     fn left_idx(&self) -> Option<usize> {
         self.left_idx.map(|i| i.usize())
     }
@@ -85,6 +122,7 @@ impl<K: Default, V: Default, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K
         }
     }
 
+    #[inline]
     fn right_idx(&self) -> Option<usize> {
         self.right_idx.map(|i| i.usize())
     }
@@ -150,7 +188,7 @@ impl<U: SmallUnsigned + Copy> NodeGetHelper<U> {
 /// Helper for in-place iterative rebuild.
 /// Users of it's APIs only need to declare `U` type or trait bounds at construction.
 /// All APIs take/return `usize` and normalize to `U` internally.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct NodeRebuildHelper<U> {
     /// Provided low index
     pub low_idx: U,
@@ -176,6 +214,19 @@ impl<U: SmallUnsigned + Ord + Sub + Copy> NodeRebuildHelper<U> {
     }
 }
 
+/// Working storage for in-place iterative rebuild.
+/// Kept by the tree when feature `fast_rebalance` is enabled, so successive rebuilds reuse it instead of
+/// initializing fresh `N`-sized stack buffers each time.
+#[derive(Clone, Debug, Default)]
+pub struct NodeRebuildScratch<U: Default, const N: usize> {
+    /// Traversal worklist used to flatten the subtree
+    pub flatten_worklist: ArrayVec<[U; N]>,
+    /// Flattened subtree, sorted by key
+    pub sorted_idxs: ArrayVec<[U; N]>,
+    /// Pending sorted index ranges to re-link
+    pub rebuild_worklist: ArrayVec<[NodeRebuildHelper<U>; N]>,
+}
+
 // Swap History Cache --------------------------------------------------------------------------------------------------
 
 /// A helper "cache" for swap operation history.
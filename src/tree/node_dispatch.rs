@@ -1,15 +1,15 @@
 // Size-optimized Node Trait -------------------------------------------------------------------------------------------
 
 /// Interface encapsulates `U`.
-pub trait SmallNode<K, V: Default> {
+pub trait SmallNode<K, V> {
     /// Get key.
     fn key(&self) -> &K;
 
     /// Set key.
     fn set_key(&mut self, key: K);
 
-    // Take key, replacing current with `K::Default()`.
-    fn take_key(&mut self) -> K;
+    /// Replace key, returning the old one.
+    fn replace_key(&mut self, key: K) -> K;
 
     /// Get value.
     fn val(&self) -> &V;
@@ -17,11 +17,8 @@ pub trait SmallNode<K, V: Default> {
     /// Get key and mutable value.
     fn get_mut(&mut self) -> (&K, &mut V);
 
-    /// Set value.
-    fn set_val(&mut self, val: V);
-
-    // Take value, replacing current with `V::Default()`.
-    fn take_val(&mut self) -> V;
+    /// Replace value, returning the old one.
+    fn replace_val(&mut self, val: V) -> V;
 
     /// Get left index as `usize`.
     fn left_idx(&self) -> Option<usize>;
@@ -53,7 +50,7 @@ use smallnum::SmallUnsignedLabel;
 // Enum Dispatch -------------------------------------------------------------------------------------------------------
 
 #[derive(Clone)]
-pub enum SmallNodeDispatch<K: Default, V: Default> {
+pub enum SmallNodeDispatch<K, V> {
     NodeUSIZE(Node<K, V, usize>),
     NodeU8(Node<K, V, u8>),
 
@@ -79,7 +76,7 @@ pub enum SmallNodeDispatch<K: Default, V: Default> {
     NodeU128(Node<K, V, u128>),
 }
 
-impl<K: Default, V: Default> SmallNodeDispatch<K, V> {
+impl<K, V> SmallNodeDispatch<K, V> {
     pub const fn new(key: K, val: V, uint: SmallUnsignedLabel) -> Self {
         match uint {
             SmallUnsignedLabel::USIZE => SmallNodeDispatch::NodeUSIZE(Node::<K, V, usize>::new(key, val)),
@@ -141,7 +138,7 @@ macro_rules! dispatch {
     };
 }
 
-impl<K: Default, V: Default> SmallNode<K, V> for SmallNodeDispatch<K, V> {
+impl<K, V> SmallNode<K, V> for SmallNodeDispatch<K, V> {
     fn key(&self) -> &K {
         dispatch!(self, key)
     }
@@ -150,8 +147,8 @@ impl<K: Default, V: Default> SmallNode<K, V> for SmallNodeDispatch<K, V> {
         dispatch!(self, set_key, key);
     }
 
-    fn take_key(&mut self) -> K {
-        dispatch!(self, take_key)
+    fn replace_key(&mut self, key: K) -> K {
+        dispatch!(self, replace_key, key)
     }
 
     fn val(&self) -> &V {
@@ -162,12 +159,8 @@ impl<K: Default, V: Default> SmallNode<K, V> for SmallNodeDispatch<K, V> {
         dispatch!(self, get_mut)
     }
 
-    fn set_val(&mut self, val: V) {
-        dispatch!(self, set_val, val);
-    }
-
-    fn take_val(&mut self) -> V {
-        dispatch!(self, take_val)
+    fn replace_val(&mut self, val: V) -> V {
+        dispatch!(self, replace_val, val)
     }
 
     fn left_idx(&self) -> Option<usize> {
@@ -40,7 +40,9 @@ pub fn get_test_tree_and_keys() -> (SgTree<usize, &'static str, CAPACITY>, Vec<u
 // 1. A right child node's key is always greater than it's parent's key.
 // 2. A left child node's key is always less than it's parent's key.
 // 3. Every node has at most 1 parent.
-fn assert_logical_invariants<K: Ord + Default, V: Default, const N: usize>(sgt: &SgTree<K, V, N>) {
+fn assert_logical_invariants<K: Ord, V, const N: usize>(sgt: &SgTree<K, V, N>) {
+    assert_eq!(sgt.verify(), Ok(()));
+
     if let Some(root_idx) = sgt.opt_root_idx {
         let mut child_idxs = vec![root_idx]; // Count as "child" to make sure there's no other ref to this index
         let mut subtree_worklist = vec![&sgt.arena[root_idx]];
@@ -74,7 +76,39 @@ fn assert_logical_invariants<K: Ord + Default, V: Default, const N: usize>(sgt:
             dedup_child_idxs.len() == child_idxs.len(),
             "Internal invariant failed: node with multiple parents present!"
         );
+
+        // Recount from scratch rather than trusting children's cached sizes
+        #[cfg(feature = "fast_rebalance")]
+        for idx in child_idxs {
+            let mut actual_size = 0;
+            let mut subtree_worklist = vec![idx];
+            while let Some(i) = subtree_worklist.pop() {
+                actual_size += 1;
+                subtree_worklist.extend(sgt.arena[i].left_idx());
+                subtree_worklist.extend(sgt.arena[i].right_idx());
+            }
+
+            assert_eq!(
+                sgt.arena[idx].subtree_size(),
+                actual_size,
+                "Internal invariant failed: cached subtree size is stale!"
+            );
+        }
+    }
+}
+
+// Number of edges on the longest root-to-leaf path, `0` for an empty or single-node tree.
+fn max_depth<K, V, const N: usize>(sgt: &SgTree<K, V, N>) -> usize {
+    let mut max_depth = 0;
+    let mut worklist: Vec<(usize, usize)> = sgt.opt_root_idx.map(|i| (i, 0)).into_iter().collect();
+
+    while let Some((idx, depth)) = worklist.pop() {
+        max_depth = max_depth.max(depth);
+        worklist.extend(sgt.arena[idx].left_idx().map(|i| (i, depth + 1)));
+        worklist.extend(sgt.arena[idx].right_idx().map(|i| (i, depth + 1)));
     }
+
+    max_depth
 }
 
 // Inserts random `usize` keys, and randomly removes 20%.
@@ -225,6 +259,7 @@ fn test_tree_sizing() {
 
     // No features
     #[cfg(target_pointer_width = "64")]
+    #[cfg(not(feature = "profiling"))]
     #[cfg(not(feature = "low_mem_insert"))]
     #[cfg(not(feature = "fast_rebalance"))]
     {
@@ -233,14 +268,16 @@ fn test_tree_sizing() {
 
     // All features
     #[cfg(target_pointer_width = "64")]
+    #[cfg(not(feature = "profiling"))]
     #[cfg(feature = "low_mem_insert")]
     #[cfg(feature = "fast_rebalance")]
     {
-        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 20_552);
+        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 30_800);
     }
 
     // low_mem_insert only
     #[cfg(target_pointer_width = "64")]
+    #[cfg(not(feature = "profiling"))]
     #[cfg(feature = "low_mem_insert")]
     #[cfg(not(feature = "fast_rebalance"))]
     {
@@ -249,10 +286,11 @@ fn test_tree_sizing() {
 
     // fast_rebalance only
     #[cfg(target_pointer_width = "64")]
+    #[cfg(not(feature = "profiling"))]
     #[cfg(not(feature = "low_mem_insert"))]
     #[cfg(feature = "fast_rebalance")]
     {
-        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 22_600);
+        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 32_848);
     }
 }
 
@@ -607,6 +645,54 @@ fn test_extend() {
     assert!(sgt_1.into_iter().eq(iterable_3.into_iter()));
 }
 
+#[test]
+fn test_extend_batched() {
+    let data: Vec<(usize, usize)> = (0..100).map(|x| (x, x)).collect();
+
+    // Per-element insert
+    let mut sgt_1 = SgTree::<_, _, CAPACITY>::new();
+    data.iter().for_each(|(k, v)| {
+        sgt_1.insert(*k, *v);
+    });
+
+    // Sorted extend, single rebuild
+    let mut sgt_2 = SgTree::<_, _, CAPACITY>::new();
+    sgt_2.extend(data.clone().into_iter());
+    assert_logical_invariants(&sgt_2);
+    assert!(sgt_2.iter().eq(sgt_1.iter()));
+    assert_eq!(sgt_2.first_key_value(), Some((&0, &0)));
+    assert_eq!(sgt_2.last_key_value(), Some((&99, &99)));
+    assert_eq!(sgt_2.rebal_cnt(), 1);
+    assert!(sgt_1.rebal_cnt() > sgt_2.rebal_cnt());
+
+    // Sorted extend beyond current max, single rebuild
+    sgt_2.extend((100..200).map(|x| (x, x)));
+    assert_logical_invariants(&sgt_2);
+    assert!(sgt_2.iter().map(|(k, _)| *k).eq(0..200));
+    assert_eq!(sgt_2.rebal_cnt(), 2);
+
+    // Small sorted extend within depth bound, no rebuild
+    sgt_2.extend((200..202).map(|x| (x, x)));
+    assert_logical_invariants(&sgt_2);
+    assert_eq!(sgt_2.last_key_value(), Some((&201, &201)));
+    assert_eq!(sgt_2.rebal_cnt(), 2);
+
+    // Unsorted tail falls back to per-element insert
+    let mut sgt_3 = SgTree::<_, _, CAPACITY>::new();
+    sgt_3.extend((0..50).chain((50..100).rev()).map(|x| (x, x)));
+    assert_logical_invariants(&sgt_3);
+    assert!(sgt_3.iter().eq(sgt_1.iter()));
+
+    // Remains balanced for subsequent use
+    for i in 0..100 {
+        assert_eq!(sgt_3.remove(&i), Some(i));
+        assert_eq!(sgt_2.remove(&i), Some(i));
+        assert_logical_invariants(&sgt_2);
+    }
+    assert!(sgt_3.is_empty());
+    assert_eq!(sgt_2.len(), 102);
+}
+
 #[test]
 fn test_slice_search() {
     let bad_code: [u8; 8] = [0xB, 0xA, 0xA, 0xD, 0xC, 0x0, 0xD, 0xE];
@@ -724,6 +810,37 @@ fn test_set_rebal_param() {
         Err(SgError::RebalanceFactorOutOfRange)
     );
 
+    // Sorted extend rebuilds once, regardless of alpha
+    assert!(sgt_1.rebal_cnt() > sgt_2.rebal_cnt());
+    assert!(sgt_1.rebal_cnt() > sgt_3.rebal_cnt());
+
+    // Exact counts, useful to verify that different features being enabled don't change these numbers
+    assert_eq!(sgt_1.rebal_cnt(), 52);
+    assert_eq!(sgt_2.rebal_cnt(), 1);
+    assert_eq!(sgt_3.rebal_cnt(), 1);
+}
+
+#[cfg(not(feature = "alt_impl"))] // This affects rebalance count and is experimental.
+#[test]
+fn test_set_rebal_param_per_insert() {
+    assert!(CAPACITY >= 100);
+    let data: Vec<(usize, usize)> = (0..100).map(|x| (x, x)).collect();
+    let sgt_1 = SgTree::<_, _, CAPACITY>::from_iter(data.clone().into_iter());
+
+    // Lax rebalancing
+    let mut sgt_2 = SgTree::<_, _, CAPACITY>::new();
+    assert!(sgt_2.set_rebal_param(0.9, 1.0).is_ok());
+    data.iter().for_each(|(k, v)| {
+        sgt_2.insert(*k, *v);
+    });
+
+    // Strict rebalancing
+    let mut sgt_3 = SgTree::<_, _, CAPACITY>::new();
+    assert!(sgt_3.set_rebal_param(1.0, 2.0).is_ok());
+    data.iter().for_each(|(k, v)| {
+        sgt_3.insert(*k, *v);
+    });
+
     // Alpha tuning OK
     assert!(sgt_3.rebal_cnt() > sgt_2.rebal_cnt());
     assert!(sgt_1.rebal_cnt() > sgt_2.rebal_cnt());
@@ -756,7 +873,19 @@ fn test_intersect_cnt() {
 #[test]
 fn test_capacity_exceed() {
     const OVER_CAP: usize = (Idx::MAX as usize) + 1;
-    let _ = SgTree::<u8, u8, OVER_CAP>::new();
+
+    // Tree this large can exceed the default 2MB test thread stack (e.g. with `fast_rebalance` scratch space)
+    let res = std::thread::Builder::new()
+        .stack_size(8 * 1024 * 1024)
+        .spawn(|| {
+            let _ = SgTree::<u8, u8, OVER_CAP>::new();
+        })
+        .unwrap()
+        .join();
+
+    if let Err(panic) = res {
+        std::panic::resume_unwind(panic);
+    }
 }
 
 #[test]
@@ -774,3 +903,1057 @@ fn test_double_ended_iter_mut() {
     assert_eq!(None, iter.next());
     assert_eq!(None, iter.next_back());
 }
+
+#[cfg(feature = "debug_checks")]
+#[test]
+fn test_debug_assert_valid() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+    sgt.debug_assert_valid();
+
+    for k in keys {
+        sgt.remove(&k);
+        sgt.debug_assert_valid();
+    }
+
+    SgTree::<usize, usize, CAPACITY>::new().debug_assert_valid();
+}
+
+#[cfg(feature = "debug_checks")]
+#[should_panic(expected = "Internal invariant failed: cached min/max isn't an extreme!")]
+#[test]
+fn test_debug_assert_valid_bad_min() {
+    let (mut sgt, _) = get_test_tree_and_keys();
+    sgt.min_idx = sgt.max_idx;
+    sgt.debug_assert_valid();
+}
+
+#[cfg(feature = "debug_checks")]
+#[should_panic(expected = "Internal invariant failed: node with multiple parents present!")]
+#[test]
+fn test_debug_assert_valid_bad_link() {
+    let mut sgt = SgTree::from([(1, 1), (0, 0), (2, 2)]);
+    let root_idx = sgt.opt_root_idx.unwrap();
+    let left_idx = sgt.arena[root_idx].left_idx().unwrap();
+
+    // Root's left child also claims root's right child
+    let right_idx = sgt.arena[root_idx].right_idx();
+    sgt.arena[left_idx].set_right_idx(right_idx);
+    sgt.debug_assert_valid();
+}
+
+#[cfg(feature = "debug_checks")]
+#[should_panic(
+    expected = "Internal invariant failed: node ordered incorrectly w.r.t. an ancestor!"
+)]
+#[test]
+fn test_debug_assert_valid_bad_grandchild() {
+    // Level-order insertion builds a perfect tree
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    for k in [7, 3, 11, 1, 5, 9, 13, 0, 2, 4, 6, 8, 10, 12, 14] {
+        sgt.insert(k, k);
+    }
+
+    let root_idx = sgt.opt_root_idx.unwrap();
+    let root_key = *sgt.arena[root_idx].key();
+    let left_idx = sgt.arena[root_idx].left_idx().unwrap();
+
+    // Root's predecessor, at the end of its left child's right spine
+    let mut pred_idx = sgt.arena[left_idx].right_idx().unwrap();
+    while let Some(right_idx) = sgt.arena[pred_idx].right_idx() {
+        pred_idx = right_idx;
+    }
+
+    // Still greater than its parent, but no longer less than the root
+    sgt.arena[pred_idx].replace_key(root_key);
+    assert!(sgt.verify().is_err());
+    sgt.debug_assert_valid();
+}
+
+#[test]
+fn test_select_rank() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    let mut rng = SmallRng::from_entropy();
+    assert_eq!(sgt.select(0), None);
+
+    for _ in 0..5_000 {
+        let key = rng.gen_range(0, CAPACITY / 2);
+        match rng.gen_range(0, 3) {
+            0 => {
+                sgt.remove(&key);
+            }
+            _ => {
+                sgt.insert(key, key);
+            }
+        }
+    }
+
+    for (rank, (k, v)) in sgt.iter().enumerate() {
+        assert_eq!(sgt.select(rank), Some((k, v)));
+        assert_eq!(sgt.rank(k), Some(rank));
+    }
+    assert_eq!(sgt.select(sgt.len()), None);
+    assert_eq!(sgt.rank(&CAPACITY), None);
+}
+
+#[test]
+fn test_reads_dont_reorder() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+
+    // Force a non-trivial physical layout
+    sgt.retain(|k, _| k % 3 != 0);
+    sgt.insert(3, "n/a");
+
+    let arena_keys = |sgt: &SgTree<usize, &'static str, CAPACITY>| -> Vec<Option<usize>> {
+        sgt.arena
+            .iter()
+            .map(|opt_node| opt_node.as_ref().map(|n| *n.key()))
+            .collect()
+    };
+
+    let layout_before = arena_keys(&sgt);
+    let rebal_cnt_before = sgt.rebal_cnt();
+    let root_before = (sgt.opt_root_idx, sgt.min_idx, sgt.max_idx);
+
+    for i in 0..10_000 {
+        let k = keys[i % keys.len()];
+        assert_eq!(sgt.get(&k).is_some(), sgt.contains_key(&k));
+        assert!(sgt.first_key_value().is_some());
+        assert_eq!(sgt.iter().count(), sgt.len());
+    }
+
+    assert_eq!(arena_keys(&sgt), layout_before);
+    assert_eq!(sgt.rebal_cnt(), rebal_cnt_before);
+    assert_eq!((sgt.opt_root_idx, sgt.min_idx, sgt.max_idx), root_before);
+    assert_logical_invariants(&sgt);
+}
+
+#[test]
+fn test_can_insert() {
+    let mut sgt = SgTree::<usize, usize, 4>::new();
+    assert!(sgt.can_insert(0));
+    assert!(sgt.can_insert(4));
+    assert!(!sgt.can_insert(5));
+    assert!(!sgt.can_insert(usize::MAX));
+
+    sgt.extend((0..4).map(|k| (k, k)));
+    assert!(sgt.is_full());
+    assert!(sgt.can_insert(0));
+    assert_eq!(sgt.ensure_capacity(0), Ok(()));
+    assert_eq!(sgt.ensure_capacity(1), Err(SgError::StackCapacityExceeded));
+
+    // Guard agrees with fallible insertion
+    assert_eq!(sgt.try_insert(4, 4).is_ok(), sgt.ensure_capacity(1).is_ok());
+}
+
+#[test]
+fn test_clone_resize() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+    sgt.set_rebal_param(2.0, 3.0).unwrap();
+
+    let sgt_small = sgt.clone_resize::<17>().unwrap();
+    assert_eq!(sgt_small.capacity(), keys.len());
+    assert_eq!(sgt_small.rebal_param(), (2.0, 3.0));
+    assert!(sgt_small.iter().eq(sgt.iter()));
+    assert_logical_invariants(&sgt_small);
+
+    assert_eq!(
+        sgt.clone_resize::<16>().unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+}
+
+#[test]
+fn test_resize() {
+    // Not `Clone`, so entries must be moved
+    #[derive(Debug, PartialEq)]
+    struct Val(usize);
+
+    let (sgt, keys) = get_test_tree_and_keys();
+    let mut sgt: SgTree<usize, Val, CAPACITY> = sgt.iter().map(|(k, _)| (*k, Val(*k))).collect();
+    sgt.set_rebal_param(2.0, 3.0).unwrap();
+
+    let sgt_small = sgt.resize::<17>().unwrap();
+    assert_eq!(sgt_small.capacity(), keys.len());
+    assert_eq!(sgt_small.rebal_param(), (2.0, 3.0));
+    assert!(sgt_small.iter().all(|(k, v)| *k == v.0));
+    assert_logical_invariants(&sgt_small);
+
+    let sgt_big = sgt_small.resize::<CAPACITY>().unwrap();
+    assert_eq!(sgt_big.len(), keys.len());
+    assert_logical_invariants(&sgt_big);
+
+    assert_eq!(
+        sgt_big.resize::<16>().unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+}
+
+#[test]
+fn test_split_off_full() {
+    const SMALL_CAPACITY: usize = 64;
+
+    for fill in [SMALL_CAPACITY - 1, SMALL_CAPACITY] {
+        for split_key in 0..=(fill + 1) {
+            let mut sgt = SgTree::<usize, usize, SMALL_CAPACITY>::new();
+            for k in 0..fill {
+                sgt.insert(k, k);
+            }
+
+            let sgt_hi = sgt.split_off(&split_key);
+
+            // No element lost or duplicated
+            assert_eq!(sgt.len() + sgt_hi.len(), fill);
+            assert!(sgt.iter().chain(sgt_hi.iter()).map(|(k, _)| *k).eq(0..fill));
+            assert!(sgt.iter().all(|(k, _)| *k < split_key));
+            assert!(sgt_hi.iter().all(|(k, _)| *k >= split_key));
+
+            assert_logical_invariants(&sgt);
+            assert_logical_invariants(&sgt_hi);
+        }
+    }
+}
+
+#[test]
+fn test_iter_mut_double_ended() {
+    let (mut sgt, mut keys) = get_test_tree_and_keys();
+
+    // Leave free slots in the arena
+    for k in keys.drain(..5) {
+        sgt.remove(&k);
+    }
+    keys.sort_unstable();
+
+    let mut iter_mut = sgt.iter_mut();
+    assert_eq!(iter_mut.len(), keys.len());
+
+    // Alternate ends, each entry must be visited exactly once
+    let mut visited = Vec::new();
+    let mut from_front = true;
+    loop {
+        let next = match from_front {
+            true => iter_mut.next(),
+            false => iter_mut.next_back(),
+        };
+        match next {
+            Some((k, v)) => {
+                *v = "visited";
+                visited.push(*k);
+            }
+            None => break,
+        }
+        from_front = !from_front;
+    }
+
+    visited.sort_unstable();
+    assert_eq!(visited, keys);
+    assert!(sgt.iter().all(|(_, v)| *v == "visited"));
+    assert!(sgt
+        .iter_mut()
+        .rev()
+        .map(|(k, _)| *k)
+        .eq(keys.iter().rev().copied()));
+}
+
+#[test]
+fn test_bulk_remove() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+    let rebal_cnt = sgt.rebal_cnt();
+
+    // Sorted with duplicates and absent keys
+    let sorted_keys = [0, 1, 1, 4, 5, 8, 13, 18, 19];
+    assert_eq!(sgt.bulk_remove(&sorted_keys[..]), 5);
+    assert!(sgt.rebal_cnt() <= rebal_cnt + 1);
+    assert_logical_invariants(&sgt);
+
+    for k in &keys {
+        assert_eq!(sgt.contains_key(k), !sorted_keys.contains(k));
+    }
+
+    // Nothing left to match
+    assert_eq!(sgt.bulk_remove(&sorted_keys[..]), 0);
+    assert_eq!(sgt.bulk_remove(&[]), 0);
+
+    // Remove everything
+    let mut remaining: Vec<usize> = sgt.iter().map(|(k, _)| *k).collect();
+    remaining.sort_unstable();
+    assert_eq!(sgt.bulk_remove(&remaining), remaining.len());
+    assert!(sgt.is_empty());
+    assert_logical_invariants(&sgt);
+}
+
+fn assert_default_eq_new<const N: usize>() {
+    let map_default = crate::SgMap::<usize, usize, N>::default();
+    let map_new = crate::SgMap::<usize, usize, N>::new();
+
+    assert_eq!(map_default, map_new);
+    assert_eq!(map_default.capacity(), N);
+    assert_eq!(map_default.rebal_param(), map_new.rebal_param());
+    assert_eq!(map_default.bst.rebal_cnt(), map_new.bst.rebal_cnt());
+    assert_eq!(map_default.bst.arena.len(), 0);
+
+    let set_default = crate::SgSet::<usize, N>::default();
+    let set_new = crate::SgSet::<usize, N>::new();
+
+    assert_eq!(set_default, set_new);
+    assert_eq!(set_default.capacity(), N);
+    assert_eq!(set_default.rebal_param(), set_new.rebal_param());
+    assert_eq!(set_default.bst.rebal_cnt(), set_new.bst.rebal_cnt());
+
+    // Identical behavior from here on
+    let mut map_default = map_default;
+    let mut map_new = map_new;
+    for k in 0..N {
+        map_default.insert(k, k);
+        map_new.insert(k, k);
+    }
+    assert_eq!(map_default, map_new);
+    assert_eq!(map_default.bst.rebal_cnt(), map_new.bst.rebal_cnt());
+}
+
+#[test]
+fn test_default_eq_new() {
+    assert_default_eq_new::<1>();
+    assert_default_eq_new::<2>();
+    assert_default_eq_new::<10>();
+    assert_default_eq_new::<255>();
+    assert_default_eq_new::<256>();
+    assert_default_eq_new::<CAPACITY>();
+}
+
+#[test]
+fn test_max_size_bound() {
+    const SMALL_CAPACITY: usize = 256;
+    const OPS: usize = 1_000_000;
+
+    let mut rng = SmallRng::seed_from_u64(0x5ca9e);
+    let mut sgt = SgTree::<usize, usize, SMALL_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for _ in 0..OPS {
+        let key = rng.gen_range(0, 2 * SMALL_CAPACITY);
+        match rng.gen_range(0, 1000) {
+            // Insert-heavy mix, so the tree grows and shrinks
+            0..=449 => {
+                if sgt.try_insert(key, key).is_ok() {
+                    bt_map.insert(key, key);
+                }
+            }
+            450..=849 => assert_eq!(sgt.remove(&key), bt_map.remove(&key)),
+            850..=919 => {
+                let bt_first = bt_map.keys().next().copied();
+                assert_eq!(sgt.pop_first().map(|(k, _)| k), bt_first);
+                bt_first.map(|k| bt_map.remove(&k));
+            }
+            920..=989 => {
+                let bt_last = bt_map.keys().next_back().copied();
+                assert_eq!(sgt.pop_last().map(|(k, _)| k), bt_last);
+                bt_last.map(|k| bt_map.remove(&k));
+            }
+            990..=994 => {
+                sgt.retain(|k, _| *k < key);
+                bt_map.retain(|k, _| *k < key);
+            }
+            995..=998 => {
+                assert_eq!(sgt.remove_range(key..), bt_map.range(key..).count());
+                bt_map.retain(|k, _| *k < key);
+            }
+            _ => {
+                let mut sgt_hi = sgt.split_off(&key);
+                assert!(sgt_hi.max_size <= 2 * sgt_hi.len());
+                sgt.append(&mut sgt_hi);
+            }
+        }
+
+        assert_eq!(sgt.len(), bt_map.len());
+        assert!(
+            sgt.max_size <= 2 * sgt.len(),
+            "max_size {} drifted from len {}",
+            sgt.max_size,
+            sgt.len()
+        );
+    }
+
+    assert!(sgt.iter().eq(bt_map.iter()));
+    assert_logical_invariants(&sgt);
+}
+
+#[test]
+fn test_map_values_preserves_layout() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+
+    // Leave free slots behind
+    for k in keys.iter().step_by(3) {
+        sgt.remove(k);
+    }
+
+    let mapped = sgt.map_values(|v| v.len());
+    assert_logical_invariants(&mapped);
+
+    assert_eq!(mapped.opt_root_idx, sgt.opt_root_idx);
+    assert_eq!(mapped.min_idx, sgt.min_idx);
+    assert_eq!(mapped.max_idx, sgt.max_idx);
+    assert_eq!(mapped.max_size, sgt.max_size);
+    assert_eq!(mapped.rebal_cnt(), sgt.rebal_cnt());
+
+    for idx in 0..sgt.arena.len() {
+        assert_eq!(sgt.arena.is_occupied(idx), mapped.arena.is_occupied(idx));
+        if sgt.arena.is_occupied(idx) {
+            let (node, mapped_node) = (&sgt.arena[idx], &mapped.arena[idx]);
+            assert_eq!(node.key(), mapped_node.key());
+            assert_eq!(node.left_idx(), mapped_node.left_idx());
+            assert_eq!(node.right_idx(), mapped_node.right_idx());
+            assert_eq!(node.val().len(), *mapped_node.val());
+        }
+    }
+
+    // Still a fully functional tree
+    let mut mapped = mapped;
+    assert_eq!(mapped.insert(keys[0], 0), None);
+    assert_logical_invariants(&mapped);
+    assert_eq!(mapped.len(), sgt.len() + 1);
+}
+
+#[cfg(feature = "membership_filter")]
+#[test]
+fn test_membership_filter_no_false_negatives() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    assert!(!sgt.priv_may_contain(&0));
+
+    for _ in 0..(CAPACITY * 8) {
+        let key = rng.gen_range(0, CAPACITY * 2);
+        match rng.gen_range(0, 3) {
+            0 | 1 if sgt.len() < CAPACITY => {
+                sgt.insert(key, key);
+                bt_map.insert(key, key);
+            }
+            _ => {
+                assert_eq!(sgt.remove(&key), bt_map.remove(&key));
+            }
+        }
+
+        // Every present key passes the filter
+        for k in bt_map.keys() {
+            assert!(sgt.priv_may_contain(k));
+        }
+
+        // Anything outside the key span is filtered
+        match (bt_map.keys().next(), bt_map.keys().next_back()) {
+            (Some(min), Some(max)) => {
+                if let Some(below_min) = min.checked_sub(1) {
+                    assert!(!sgt.priv_may_contain(&below_min));
+                }
+                assert!(!sgt.priv_may_contain(&(max + 1)));
+            }
+            _ => assert!(!sgt.priv_may_contain(&key)),
+        }
+
+        assert_eq!(sgt.get(&key), bt_map.get(&key));
+        assert_eq!(sgt.get_mut(&key), bt_map.get_mut(&key));
+    }
+}
+
+#[test]
+fn test_verify() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+    assert_eq!(sgt.verify(), Ok(()));
+
+    for k in keys {
+        sgt.remove(&k);
+        assert_eq!(sgt.verify(), Ok(()));
+    }
+
+    assert_eq!(SgTree::<usize, usize, CAPACITY>::new().verify(), Ok(()));
+}
+
+#[test]
+fn test_verify_corrupt_state() {
+    let (sgt, _) = get_test_tree_and_keys();
+    let root_idx = sgt.opt_root_idx.unwrap();
+    let left_idx = sgt.arena[root_idx].left_idx().unwrap();
+    let right_idx = sgt.arena[root_idx].right_idx().unwrap();
+
+    // Stale min/max
+    let mut bad_sgt = sgt.clone();
+    bad_sgt.min_idx = sgt.max_idx;
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Orphaned node, length is stale
+    let mut bad_sgt = sgt.clone();
+    bad_sgt.arena.add(0, "n/a");
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Missing root
+    let mut bad_sgt = sgt.clone();
+    bad_sgt.opt_root_idx = None;
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Out-of-bounds link
+    let mut bad_sgt = sgt.clone();
+    bad_sgt.arena[left_idx].set_left_idx(Some(CAPACITY - 1));
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Link to an empty slot
+    let mut bad_sgt = sgt.clone();
+    let free_idx = bad_sgt.arena.add(0, "n/a");
+    bad_sgt.arena.hard_remove(free_idx);
+    bad_sgt.arena[left_idx].set_left_idx(Some(free_idx));
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Cycle
+    let mut bad_sgt = sgt.clone();
+    bad_sgt.arena[left_idx].set_left_idx(Some(root_idx));
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Multiple parents
+    let mut bad_sgt = sgt.clone();
+    bad_sgt.arena[left_idx].set_right_idx(Some(right_idx));
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+
+    // Mis-ordered keys, the grandchild is locally ordered w.r.t. its parent but not the root
+    let mut bad_sgt = sgt.clone();
+    let root_key = *bad_sgt.arena[root_idx].key();
+    let mut idx = left_idx;
+    while let Some(right_idx) = bad_sgt.arena[idx].right_idx() {
+        idx = right_idx;
+    }
+    bad_sgt.arena[idx].set_key(root_key + 1);
+    assert_eq!(bad_sgt.verify(), Err(SgError::CorruptState));
+}
+
+#[cfg(feature = "profiling")]
+#[test]
+fn test_rebuild_hook() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static HOOK_CNT: AtomicUsize = AtomicUsize::new(0);
+    static HOOK_MAX_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    sgt.set_rebuild_hook(Some(|size| {
+        HOOK_CNT.fetch_add(1, Ordering::SeqCst);
+        HOOK_MAX_SIZE.fetch_max(size, Ordering::SeqCst);
+    }));
+
+    // Sequential inserts force rebuilds
+    for i in 0..CAPACITY {
+        sgt.insert(i, i);
+    }
+
+    assert!(sgt.rebal_cnt() > 0);
+    assert_eq!(HOOK_CNT.load(Ordering::SeqCst), sgt.rebal_cnt());
+    assert!(HOOK_MAX_SIZE.load(Ordering::SeqCst) > 1);
+    assert!(HOOK_MAX_SIZE.load(Ordering::SeqCst) <= CAPACITY);
+
+    // Hook survives clear, count does too until reset
+    sgt.clear();
+    sgt.reset_rebal_cnt();
+    HOOK_CNT.store(0, Ordering::SeqCst);
+    assert_eq!(sgt.rebal_cnt(), 0);
+
+    for i in 0..CAPACITY {
+        sgt.insert(i, i);
+    }
+
+    assert!(sgt.rebal_cnt() > 0);
+    assert_eq!(HOOK_CNT.load(Ordering::SeqCst), sgt.rebal_cnt());
+
+    // Removed hook is no longer called
+    sgt.set_rebuild_hook(None);
+    sgt.clear();
+    for i in 0..CAPACITY {
+        sgt.insert(i, i);
+    }
+    assert!(sgt.rebal_cnt() > HOOK_CNT.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_retain_keep_all_is_noop() {
+    // Per arena slot: key and child links
+    type Layout = Vec<Option<(usize, Option<usize>, Option<usize>)>>;
+
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    // Random insert and remove order, so the arena isn't sorted
+    for _ in 0..(CAPACITY / 2) {
+        let key = rng.gen_range(0, CAPACITY);
+        sgt.insert(key, key);
+    }
+
+    for _ in 0..(CAPACITY / 8) {
+        sgt.remove(&rng.gen_range(0, CAPACITY));
+    }
+
+    let layout = |sgt: &SgTree<usize, usize, CAPACITY>| -> Layout {
+        sgt.arena
+            .iter()
+            .map(|opt_node| {
+                opt_node
+                    .as_ref()
+                    .map(|node| (*node.key(), node.left_idx(), node.right_idx()))
+            })
+            .collect()
+    };
+
+    let layout_before = layout(&sgt);
+    let rebal_cnt_before = sgt.rebal_cnt();
+    let root_before = sgt.opt_root_idx;
+
+    let mut visited = Vec::new();
+    sgt.retain(|k, _| {
+        visited.push(*k);
+        true
+    });
+
+    assert!(visited.iter().eq(sgt.iter().map(|(k, _)| k)));
+    assert_eq!(layout(&sgt), layout_before);
+    assert_eq!(sgt.rebal_cnt(), rebal_cnt_before);
+    assert_eq!(sgt.opt_root_idx, root_before);
+
+    // Values may still be mutated in place
+    sgt.retain_rev(|_, v| {
+        *v += 1;
+        true
+    });
+    assert_eq!(layout(&sgt), layout_before);
+    assert!(sgt.iter().all(|(k, v)| *v == (k + 1)));
+}
+
+#[test]
+fn test_iter_nth() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    for _ in 0..(CAPACITY / 4) {
+        let key = rng.gen_range(0, CAPACITY);
+        sgt.insert(key, key);
+    }
+
+    for _ in 0..(CAPACITY / 16) {
+        sgt.remove(&rng.gen_range(0, CAPACITY));
+    }
+
+    let expected: Vec<_> = sgt.iter().map(|(k, _)| *k).collect();
+
+    // Every start offset, then a random skip
+    for start in 0..=expected.len() {
+        let skip = rng.gen_range(0, expected.len() + 2);
+        let mut iter = sgt.iter();
+
+        if start > 0 {
+            assert_eq!(
+                iter.nth(start - 1).map(|(k, _)| *k),
+                expected.get(start - 1).copied()
+            );
+        }
+
+        assert_eq!(
+            iter.nth(skip).map(|(k, _)| *k),
+            expected.get(start + skip).copied()
+        );
+        assert_eq!(iter.len(), expected.len().saturating_sub(start + skip + 1));
+        assert!(iter
+            .map(|(k, _)| k)
+            .eq(expected.iter().skip(start + skip + 1)));
+    }
+
+    // Consuming
+    for skip in 0..=expected.len() {
+        let mut into_iter = sgt.clone().into_iter();
+        assert_eq!(
+            into_iter.nth(skip).map(|(k, _)| k),
+            expected.get(skip).copied()
+        );
+        assert_eq!(into_iter.len(), expected.len().saturating_sub(skip + 1));
+        assert!(into_iter
+            .map(|(k, _)| k)
+            .eq(expected.iter().skip(skip + 1).copied()));
+    }
+}
+
+#[test]
+fn test_append_disjoint_splice() {
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    for _ in 0..100 {
+        let split = rng.gen_range(1, CAPACITY - 1);
+        let mut sgt_lo = SgTree::<usize, usize, CAPACITY>::new();
+        let mut sgt_hi = SgTree::<usize, usize, CAPACITY>::new();
+
+        for _ in 0..rng.gen_range(1, CAPACITY / 2) {
+            let key = rng.gen_range(0, split);
+            sgt_lo.insert(key, key);
+        }
+
+        for _ in 0..rng.gen_range(1, CAPACITY / 2) {
+            let key = rng.gen_range(split, CAPACITY);
+            sgt_hi.insert(key, key);
+        }
+
+        let expected: Vec<_> = sgt_lo
+            .iter()
+            .chain(sgt_hi.iter())
+            .map(|(k, v)| (*k, *v))
+            .collect();
+        let rebal_cnt = sgt_lo.rebal_cnt();
+
+        // Greater range: splice, at most one rebuild
+        let mut sgt = sgt_lo.clone();
+        let mut other = sgt_hi.clone();
+        sgt.append(&mut other);
+        assert!(other.is_empty());
+        assert!(sgt.rebal_cnt() <= rebal_cnt + 1);
+        assert!(sgt
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .eq(expected.iter().copied()));
+        assert_eq!(sgt.first_key(), expected.first().map(|(k, _)| k));
+        assert_eq!(sgt.last_key(), expected.last().map(|(k, _)| k));
+        assert_logical_invariants(&sgt);
+
+        // Lesser range: fallback
+        let mut sgt = sgt_hi.clone();
+        let mut other = sgt_lo.clone();
+        sgt.append(&mut other);
+        assert!(other.is_empty());
+        assert!(sgt
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .eq(expected.iter().copied()));
+        assert_logical_invariants(&sgt);
+
+        // Overlapping range: fallback, other's value wins
+        let mut sgt = sgt_lo.clone();
+        let mut other: SgTree<usize, usize, CAPACITY> =
+            sgt_lo.iter().map(|(k, v)| (*k, v + 1)).collect();
+        other.insert(split, split);
+        sgt.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(sgt.len(), sgt_lo.len() + 1);
+        assert!(sgt.iter().all(|(k, v)| (*k == split) || (*v == k + 1)));
+        assert_logical_invariants(&sgt);
+
+        // Splice then keep using the tree
+        let mut sgt = sgt_lo.clone();
+        sgt.append_with(&mut sgt_hi.clone(), |_, _, _| unreachable!());
+        for (k, _) in expected.iter().step_by(3) {
+            assert!(sgt.remove(k).is_some());
+        }
+        assert_logical_invariants(&sgt);
+    }
+}
+
+#[test]
+fn test_entry_search_vacant_insert() {
+    let mut rng = SmallRng::seed_from_u64(0x5ca9_e60a7);
+
+    for _ in 0..10 {
+        let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+        let mut bt = BTreeMap::new();
+
+        for _ in 0..CAPACITY {
+            let key = rng.gen_range(0, CAPACITY * 2);
+            let mut path = tinyvec::ArrayVec::<[Idx; CAPACITY]>::new();
+            let ngh = sgt.internal_entry_search::<Idx>(&mut path, &key);
+
+            match ngh.node_idx() {
+                // Hit: path holds parents, same as a plain lookup
+                Some(node_idx) => {
+                    let mut get_path = tinyvec::ArrayVec::<[Idx; CAPACITY]>::new();
+                    let get_ngh = sgt.internal_get::<_, Idx>(Some(&mut get_path), &key);
+                    assert_eq!(get_ngh, ngh);
+                    assert_eq!(get_path, path);
+                    assert_eq!(sgt.arena[node_idx].key(), &key);
+                }
+                // Miss: link without a second traversal
+                None => {
+                    let rebal_cnt = sgt.rebal_cnt();
+                    let new_node_idx = sgt.internal_vacant_insert(&path, &ngh, key, key);
+                    assert!(sgt.rebal_cnt() <= rebal_cnt + 1);
+                    assert_eq!(sgt.arena[new_node_idx].key(), &key);
+                    assert!(bt.insert(key, key).is_none());
+                }
+            }
+        }
+
+        assert!(sgt.iter().eq(bt.iter()));
+        assert_eq!(sgt.first_key(), bt.keys().next());
+        assert_eq!(sgt.last_key(), bt.keys().next_back());
+        assert_logical_invariants(&sgt);
+    }
+}
+
+#[test]
+fn test_min_max_after_sort_arena_and_retain() {
+    let mut rng = SmallRng::seed_from_u64(0x5ca9_e60a7);
+
+    for _ in 0..10 {
+        let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+        for _ in 0..(CAPACITY / 2) {
+            let key = rng.gen_range(0, CAPACITY * 4);
+            sgt.insert(key, key);
+        }
+
+        // Scramble physical layout, leaving holes in the arena
+        for _ in 0..(CAPACITY / 8) {
+            let key = rng.gen_range(0, CAPACITY * 4);
+            sgt.remove(&key);
+        }
+
+        // Physically reorder the arena, then drop the current min and max
+        sgt.iter_mut().for_each(|(_, v)| *v += 1);
+        let min = *sgt.first_key().unwrap();
+        let max = *sgt.last_key().unwrap();
+        sgt.retain(|k, _| (*k != min) && (*k != max));
+
+        assert_eq!(sgt.first_key(), sgt.iter().map(|(k, _)| k).next());
+        assert_eq!(sgt.last_key(), sgt.iter().map(|(k, _)| k).last());
+        assert!(sgt.first_key().filter(|k| **k <= min).is_none());
+        assert!(sgt.last_key().filter(|k| **k >= max).is_none());
+        assert_logical_invariants(&sgt);
+
+        // Coalesce over the scrambled arena, folding away the max
+        let new_max = *sgt.last_key().unwrap();
+        sgt.coalesce(|_, _, k, _| *k == new_max);
+        assert!(sgt.last_key().filter(|k| **k >= new_max).is_none());
+        assert_eq!(sgt.last_key(), sgt.iter().map(|(k, _)| k).last());
+        assert_logical_invariants(&sgt);
+    }
+}
+
+#[test]
+fn test_mixed_ops_match_btreemap() {
+    let mut rng = SmallRng::seed_from_u64(0x5ca9_e60a7);
+    let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    let mut bt_map = BTreeMap::new();
+
+    // Exercises every path that updates per-node bookkeeping, e.g. subtree sizes with `fast_rebalance`
+    for i in 0..(CAPACITY * 4) {
+        let key = rng.gen_range(0, CAPACITY * 2);
+        match rng.gen_range(0, 8) {
+            0..=3 => {
+                if sgt.len() < CAPACITY {
+                    assert_eq!(sgt.insert(key, i), bt_map.insert(key, i));
+                }
+            }
+            4 => assert_eq!(sgt.remove(&key), bt_map.remove(&key)),
+            5 => {
+                let bt_min = bt_map.keys().next().copied();
+                assert_eq!(
+                    sgt.pop_first(),
+                    bt_min.map(|k| (k, bt_map.remove(&k).unwrap()))
+                );
+            }
+            6 => {
+                let bt_max = bt_map.keys().last().copied();
+                assert_eq!(
+                    sgt.pop_last(),
+                    bt_max.map(|k| (k, bt_map.remove(&k).unwrap()))
+                );
+            }
+            _ => {
+                let mut sgt_hi = sgt.split_off(&key);
+                let mut bt_hi = bt_map.split_off(&key);
+                assert!(sgt_hi.iter().eq(bt_hi.iter()));
+                assert_logical_invariants(&sgt_hi);
+
+                sgt.append(&mut sgt_hi);
+                bt_map.append(&mut bt_hi);
+            }
+        }
+
+        assert!(sgt.iter().eq(bt_map.iter()));
+        assert_logical_invariants(&sgt);
+    }
+}
+
+#[test]
+fn test_rebalance_now_applies_new_alpha() {
+    let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    assert!(sgt.set_rebal_param(99.0, 100.0).is_ok());
+
+    for i in 0..64 {
+        sgt.insert(i, i);
+    }
+
+    // Lax alpha tolerates the degenerate chain left by sequential inserts
+    assert_eq!(sgt.rebal_cnt(), 0);
+    assert_eq!(max_depth(&sgt), 63);
+
+    // New alpha alone doesn't touch existing structure
+    assert!(sgt.set_rebal_param(1.0, 2.0).is_ok());
+    assert_eq!(max_depth(&sgt), 63);
+
+    sgt.rebalance_now();
+    assert_eq!(sgt.rebal_cnt(), 1);
+    assert_eq!(sgt.max_size, sgt.len());
+    assert_eq!(max_depth(&sgt), 6);
+    assert!(sgt.iter().map(|(k, _)| *k).eq(0..64));
+    assert_logical_invariants(&sgt);
+
+    // Empty tree is a no-op
+    let mut sgt_empty: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    sgt_empty.rebalance_now();
+    assert_eq!(sgt_empty.rebal_cnt(), 0);
+    assert!(sgt_empty.is_empty());
+}
+
+#[test]
+fn test_try_insert_deferred() {
+    let mut rng = SmallRng::seed_from_u64(0x5ca9_e60a7);
+    let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    let mut bt_map = BTreeMap::new();
+
+    // Scapegoat height bound, log base 1/alpha for the default alpha of 2/3
+    let assert_height_bound = |sgt: &SgTree<usize, usize, CAPACITY>| {
+        let bound = (sgt.max_size as f32).log(1.5).floor() as usize;
+        assert!(max_depth(sgt) <= bound);
+    };
+
+    // Sequential batch would force many rebuilds if inserted one-by-one
+    let batch: Vec<(usize, usize)> = (0..(CAPACITY / 2)).map(|k| (k * 2, k)).collect();
+    assert_eq!(
+        sgt.try_insert_deferred(batch.iter().copied()),
+        Ok(CAPACITY / 2)
+    );
+    bt_map.extend(batch.iter().copied());
+    assert_eq!(sgt.rebal_cnt(), 1);
+    assert!(sgt.iter().eq(bt_map.iter()));
+    assert_height_bound(&sgt);
+    assert_logical_invariants(&sgt);
+
+    // Descending batch, below the current maximum
+    sgt.reset_rebal_cnt();
+    let batch: Vec<(usize, usize)> = (0..(CAPACITY / 8)).rev().map(|k| (k * 2 + 1, k)).collect();
+    assert_eq!(
+        sgt.try_insert_deferred(batch.iter().copied()),
+        Ok(CAPACITY / 8)
+    );
+    bt_map.extend(batch.iter().copied());
+    assert_eq!(sgt.rebal_cnt(), 1);
+    assert!(sgt.iter().eq(bt_map.iter()));
+    assert_height_bound(&sgt);
+    assert_logical_invariants(&sgt);
+
+    // Random batch, duplicates (within the batch and with the tree) don't count as added
+    sgt.reset_rebal_cnt();
+    let batch: Vec<(usize, usize)> = (0..(CAPACITY / 4))
+        .map(|_| {
+            let k = rng.gen_range(0, CAPACITY * 2);
+            (k, k + 1)
+        })
+        .collect();
+    let len_old = sgt.len();
+    let added_cnt = sgt.try_insert_deferred(batch.iter().copied()).unwrap();
+    bt_map.extend(batch.iter().copied());
+    assert_eq!(added_cnt, bt_map.len() - len_old);
+    assert!(sgt.rebal_cnt() <= 1);
+    assert!(sgt.iter().eq(bt_map.iter()));
+    assert_height_bound(&sgt);
+    assert_logical_invariants(&sgt);
+
+    // Only existing keys, updated in-place without a rebuild
+    sgt.reset_rebal_cnt();
+    let batch: Vec<(usize, usize)> = bt_map.keys().step_by(3).map(|k| (*k, 0)).collect();
+    assert_eq!(sgt.try_insert_deferred(batch.iter().copied()), Ok(0));
+    bt_map.extend(batch.iter().copied());
+    assert_eq!(sgt.rebal_cnt(), 0);
+    assert!(sgt.iter().eq(bt_map.iter()));
+
+    // Zig-zag batch into an empty tree
+    let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    let batch: Vec<(usize, usize)> = (0..(CAPACITY / 2))
+        .map(|i| match i % 2 {
+            0 => (i / 2, i),
+            _ => (CAPACITY - (i / 2), i),
+        })
+        .collect();
+    assert_eq!(
+        sgt.try_insert_deferred(batch.iter().copied()),
+        Ok(CAPACITY / 2)
+    );
+    assert_eq!(sgt.rebal_cnt(), 1);
+    assert!(sgt
+        .iter()
+        .map(|(k, _)| *k)
+        .eq(batch.iter().map(|(k, _)| *k).collect::<BTreeSet<_>>()));
+    assert_height_bound(&sgt);
+    assert_logical_invariants(&sgt);
+
+    // Stops at capacity, keeping everything inserted before the overflow
+    let mut sgt_small: SgTree<usize, usize, 4> = SgTree::new();
+    assert_eq!(
+        sgt_small.try_insert_deferred([(3, 3), (1, 1), (3, 4), (2, 2), (0, 0), (5, 5), (4, 4)]),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert!(sgt_small
+        .iter()
+        .eq([(&0, &0), (&1, &1), (&2, &2), (&3, &4)]));
+    assert!(sgt_small.rebal_cnt() <= 1);
+    assert_logical_invariants(&sgt_small);
+
+    // Duplicate of a staged key still fits at capacity
+    let mut sgt_small: SgTree<usize, usize, 4> = SgTree::new();
+    assert_eq!(
+        sgt_small.try_insert_deferred([(0, 0), (1, 1), (2, 2), (3, 3), (1, 5), (4, 4)]),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert!(sgt_small
+        .iter()
+        .eq([(&0, &0), (&1, &5), (&2, &2), (&3, &3)]));
+    assert_logical_invariants(&sgt_small);
+}
+
+#[test]
+fn test_map_keys_monotonic() {
+    let mut rng = SmallRng::seed_from_u64(0x5ca9_e60a7);
+    let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+
+    for _ in 0..(CAPACITY / 2) {
+        let k = rng.gen_range(0, CAPACITY * 4);
+        sgt.insert(k, k);
+    }
+
+    // Churn so the arena has free slots
+    for _ in 0..(CAPACITY / 8) {
+        let k = rng.gen_range(0, CAPACITY * 4);
+        sgt.remove(&k);
+    }
+
+    let expected: Vec<(usize, usize)> = sgt.iter().map(|(k, v)| (*k + 1_000, *v)).collect();
+    let (len, rebal_cnt) = (sgt.len(), sgt.rebal_cnt());
+
+    let mut prev_key = None;
+    let sgt_shifted = sgt.map_keys_monotonic(|k| {
+        assert!(prev_key < Some(k));
+        prev_key = Some(k);
+        k + 1_000
+    });
+
+    assert!(sgt_shifted
+        .iter()
+        .map(|(k, v)| (*k, *v))
+        .eq(expected.into_iter()));
+    assert_eq!(sgt_shifted.len(), len);
+    assert_eq!(sgt_shifted.rebal_cnt(), rebal_cnt);
+    assert_logical_invariants(&sgt_shifted);
+
+    // Empty
+    let sgt_empty: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    assert!(sgt_empty.map_keys_monotonic(|k| k + 1).is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[should_panic(expected = "API misuse: key mapping isn't strictly monotonic!")]
+#[test]
+fn test_map_keys_non_monotonic_panic() {
+    let mut sgt: SgTree<usize, usize, CAPACITY> = SgTree::new();
+    for k in 0..10 {
+        sgt.insert(k, k);
+    }
+
+    let _ = sgt.map_keys_monotonic(|k| 10 - k);
+}
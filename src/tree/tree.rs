@@ -5,14 +5,14 @@ use core::hash::{Hash, Hasher};
 use core::iter::FromIterator;
 use core::mem;
 use core::ops::{
-    Bound::{Excluded, Included},
-    Index, RangeBounds, Sub,
+    Bound::{Excluded, Included, Unbounded},
+    ControlFlow, Index, RangeBounds, Sub,
 };
 
 use super::arena::Arena;
 use super::error::SgError;
 use super::iter::{IntoIter, Iter, IterMut};
-use super::node::{NodeGetHelper, NodeRebuildHelper};
+use super::node::{NodeGetHelper, NodeRebuildHelper, NodeRebuildScratch};
 use super::node_dispatch::SmallNode;
 
 #[allow(unused_imports)] // micromath only used if `no_std`
@@ -23,13 +23,16 @@ use tinyvec::{array_vec, ArrayVec};
 // The `u16::MAX` limit is documented in our main `README.md`.
 pub type Idx = u16;
 
+// Node index with exclusive lower/upper bound node indexes, for ordering checks
+type BoundedIdx = (Idx, Option<Idx>, Option<Idx>);
+
 // See: https://github.com/tnballo/scapegoat/blob/master/CONFIG.md
 const DEFAULT_ALPHA_NUM: f32 = 2.0;
 const DEFAULT_ALPHA_DENOM: f32 = 3.0;
 
 /// A memory-efficient, self-balancing binary search tree.
 #[derive(Clone)]
-pub struct SgTree<K: Default, V: Default, const N: usize> {
+pub struct SgTree<K, V, const N: usize> {
     // Storage
     pub(crate) arena: Arena<K, V, Idx, N>,
     pub(crate) opt_root_idx: Option<usize>,
@@ -42,11 +45,17 @@ pub struct SgTree<K: Default, V: Default, const N: usize> {
     // Balance control
     alpha_num: f32,
     alpha_denom: f32,
-    max_size: usize,
+    pub(crate) max_size: usize,
     rebal_cnt: usize,
+    #[cfg(feature = "fast_rebalance")]
+    rebuild_scratch: NodeRebuildScratch<Idx, N>,
+
+    // Profiling
+    #[cfg(feature = "profiling")]
+    rebuild_hook: Option<fn(usize)>,
 }
 
-impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
+impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
     // Public API ------------------------------------------------------------------------------------------------------
 
     /// Makes a new, empty `SgTree`.
@@ -65,6 +74,11 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             alpha_denom: DEFAULT_ALPHA_DENOM,
             max_size: 0,
             rebal_cnt: 0,
+            #[cfg(feature = "fast_rebalance")]
+            rebuild_scratch: NodeRebuildScratch::default(),
+
+            #[cfg(feature = "profiling")]
+            rebuild_hook: None,
         }
     }
 
@@ -78,6 +92,9 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
     /// * As `a` approaches `1.0`, the tree will rebalance less often. This means quicker insertions, but slower lookups and deletions.
     ///     * If `a` reached `1.0`, it'd mean a tree that never rebalances.
     ///
+    /// A new `a` only governs future rebuild decisions, existing structure is left as-is.
+    /// Call [`rebalance_now`][SgTree::rebalance_now] to apply it retroactively.
+    ///
     /// Returns `Err` if `0.5 <= alpha_num / alpha_denom < 1.0` isn't `true` (invalid `a`, out of range).
     pub fn set_rebal_param(&mut self, alpha_num: f32, alpha_denom: f32) -> Result<(), SgError> {
         let a = alpha_num / alpha_denom;
@@ -123,10 +140,54 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             return;
         }
 
+        // All greater, splice without searching
+        if self.priv_splice_greater(other) {
+            return;
+        }
+
         // Rip elements directly out of other's arena and clear it
         for arena_idx in 0..other.arena.len() {
-            if let Some(mut node) = other.arena.remove(arena_idx) {
-                self.insert(node.take_key(), node.take_val());
+            if let Some(node) = other.arena.remove(arena_idx) {
+                let (key, val) = node.into_kv();
+                self.insert(key, val);
+            }
+        }
+        other.clear();
+    }
+
+    /// Moves all elements from `other` into `self`, leaving `other` empty.
+    /// For duplicate keys, the merged value is `resolve(key, self_val, other_val)` and `self`'s key is kept.
+    pub fn append_with<F>(&mut self, other: &mut SgTree<K, V, N>, mut resolve: F)
+    where
+        K: Ord,
+        F: FnMut(&K, V, V) -> V,
+    {
+        // Nothing to append!
+        if other.is_empty() {
+            return;
+        }
+
+        // Nothing to append to, so no conflicts!
+        if self.is_empty() {
+            mem::swap(self, other);
+            return;
+        }
+
+        // All greater, so no conflicts!
+        if self.priv_splice_greater(other) {
+            return;
+        }
+
+        // Pop elements one at a time, so both trees stay valid if `resolve` (or an insert) panics
+        while let Some((key, val)) = other.pop_first() {
+            let ngh: NodeGetHelper<Idx> = self.internal_get(None, &key);
+            match ngh.node_idx() {
+                Some(idx) => {
+                    self.map_val_by_idx(idx, |self_val| resolve(&key, self_val, val));
+                }
+                None => {
+                    self.insert(key, val);
+                }
             }
         }
         other.clear();
@@ -148,15 +209,35 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         // Rip elements directly out of other's arena and clear it
         if (self.len() + other.len() - self.intersect_cnt(other)) <= self.capacity() {
             for arena_idx in 0..other.arena.len() {
-                if let Some(mut node) = other.arena.remove(arena_idx) {
-                    self.try_insert(node.take_key(), node.take_val())?;
+                if let Some(node) = other.arena.remove(arena_idx) {
+                    let (key, val) = node.into_kv();
+                    self.try_insert(key, val)?;
                 }
             }
             other.clear();
         } else {
             // Preemptive - we haven't mutated `self` or `other`!
             // Caller can assume unchanged state.
-            return Err(SgError::StackCapacityExceeded);
+            return Err(Self::capacity_err());
+        }
+
+        Ok(())
+    }
+
+    /// Moves all pairs from an iterator into the tree, last value wins for duplicate keys.
+    /// Panics if stack capacity is exceeded.
+    pub fn append_iter<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.extend(iter);
+    }
+
+    /// Attempts to move all pairs from an iterator into the tree, last value wins for duplicate keys.
+    /// Returns `Err` at the first pair that can't fit, preceding pairs remain in the tree.
+    pub fn try_append_iter<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), SgError> {
+        for (k, v) in iter {
+            self.try_insert(k, v)?;
         }
 
         Ok(())
@@ -185,7 +266,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         // Replace current slot or safely fill a new one
         match self.contains_key(&key) || (self.capacity() > self.len()) {
             true => Ok(self.internal_balancing_insert::<Idx>(key, val).0),
-            false => Err(SgError::StackCapacityExceeded),
+            false => Err(Self::capacity_err()),
         }
     }
 
@@ -200,7 +281,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             });
             Ok(())
         } else {
-            Err(SgError::StackCapacityExceeded)
+            Err(Self::capacity_err())
         }
     }
 
@@ -215,6 +296,21 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Conversion from an iterator, keeping entries until capacity is reached.
+    /// Returns the tree and the number of entries dropped for lack of capacity.
+    pub fn from_iter_capped<I: IntoIterator<Item = (K, V)>>(iter: I) -> (Self, usize) {
+        let mut sgt = SgTree::new();
+        let mut dropped_cnt = 0;
+
+        for (k, v) in iter {
+            if sgt.try_insert(k, v).is_err() {
+                dropped_cnt += 1;
+            }
+        }
+
+        (sgt, dropped_cnt)
+    }
+
     /// Gets an iterator over the entries of the tree, sorted by key.
     pub fn iter(&self) -> Iter<'_, K, V, N> {
         Iter::new(self)
@@ -236,18 +332,72 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
     {
         match self.priv_remove_by_key(key) {
             Some((key, val)) => {
-                if self.max_size > (2 * self.curr_size) {
-                    if let Some(root_idx) = self.opt_root_idx {
-                        self.rebuild::<Idx>(root_idx);
-                        self.max_size = self.curr_size;
-                    }
-                }
+                self.priv_sparse_rebuild();
                 Some((key, val))
             }
             None => None,
         }
     }
 
+    /// Removes all elements with keys in the given range, returning the number of elements removed.
+    /// Only in-range nodes are visited, and the tree is rebuilt at most once.
+    ///
+    /// The range may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    pub fn remove_range<T, R>(&mut self, range: R) -> usize
+    where
+        T: Ord + ?Sized,
+        R: RangeBounds<T>,
+        K: Borrow<T> + Ord,
+    {
+        SgTree::<K, V, N>::assert_valid_range(&range);
+
+        // Arena indexes remain stable across removals
+        let remove_idxs = self.range_search(&range);
+        for idx in &remove_idxs {
+            self.priv_remove_by_idx(*idx);
+        }
+
+        self.priv_sparse_rebuild();
+        remove_idxs.len()
+    }
+
+    /// Removes every key yielded by `sorted_keys` (ascending order, duplicates allowed), returning the number removed.
+    /// Matches are found in a single in-order pass, and the tree is rebuilt at most once.
+    pub fn bulk_remove<'a, I>(&mut self, sorted_keys: I) -> usize
+    where
+        I: IntoIterator<Item = &'a K>,
+        K: 'a + Ord,
+    {
+        let mut sorted_keys = sorted_keys.into_iter().peekable();
+        let mut remove_idxs = ArrayVec::<[usize; N]>::new();
+
+        let first_key = match sorted_keys.peek() {
+            Some(key) => *key,
+            None => return 0,
+        };
+
+        // Merge in-order node keys against the sorted keys
+        for idx in self.range_search(&(first_key..)) {
+            let node_key = self.arena[idx].key();
+            while sorted_keys.next_if(|key| *key < node_key).is_some() {}
+
+            match sorted_keys.peek() {
+                Some(key) if *key == node_key => remove_idxs.push(idx),
+                Some(_) => {}
+                None => break,
+            }
+        }
+
+        // Arena indexes remain stable across removals
+        for idx in &remove_idxs {
+            self.priv_remove_by_idx(*idx);
+        }
+
+        self.priv_sparse_rebuild();
+        remove_idxs.len()
+    }
+
     /// Removes a key from the tree, returning the value at the key if the key was previously in the tree.
     ///
     /// The key may be any borrowed form of the map’s key type, but the ordering
@@ -266,7 +416,100 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         F: FnMut(&K, &mut V) -> bool,
         K: Ord,
     {
-        self.priv_drain_filter(|k, v| !f(k, v));
+        self.priv_drain_filter(|k, v| !f(k, v), false);
+    }
+
+    /// Retains only the elements specified by the predicate, visiting them in descending key order.
+    pub fn retain_rev<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+        K: Ord,
+    {
+        self.priv_drain_filter(|k, v| !f(k, v), true);
+    }
+
+    /// Retains elements specified by the predicate, visiting them in ascending key order until it breaks.
+    /// The predicate also receives the count of elements kept so far.
+    /// The element the predicate breaks on, and every element after it, is kept.
+    pub fn retain_while<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V, usize) -> ControlFlow<(), bool>,
+        K: Ord,
+    {
+        let mut stack = Arena::<K, V, Idx, N>::new_idx_vec();
+        let mut remove_idxs = Arena::<K, V, Idx, N>::new_idx_vec();
+        let mut opt_curr_idx = self.opt_root_idx;
+        let mut kept = 0;
+
+        // Iterative in-order traversal, stops as soon as the predicate breaks
+        'traverse: loop {
+            while let Some(idx) = opt_curr_idx {
+                stack.push(Idx::checked_from(idx));
+                opt_curr_idx = self.arena[idx].left_idx();
+            }
+
+            let idx = match stack.pop() {
+                Some(idx) => idx.usize(),
+                None => break,
+            };
+
+            let (k, v) = self.arena[idx].get_mut();
+            match f(k, v, kept) {
+                ControlFlow::Continue(true) => kept += 1,
+                ControlFlow::Continue(false) => remove_idxs.push(Idx::checked_from(idx)),
+                ControlFlow::Break(()) => break 'traverse,
+            }
+
+            opt_curr_idx = self.arena[idx].right_idx();
+        }
+
+        // Removal re-links the tree, so defer until traversal is done
+        if remove_idxs.is_empty() {
+            return;
+        }
+
+        for i in remove_idxs {
+            self.priv_remove_by_idx(i.usize());
+        }
+
+        self.priv_sparse_rebuild();
+    }
+
+    /// Walks adjacent pairs in ascending key order, folding the second into the first and removing it
+    /// whenever `f` returns `true`. A folded-into entry stays the first of the next pair.
+    pub fn coalesce<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V, &K, &V) -> bool,
+        K: Ord,
+    {
+        let sorted_idxs = match self.opt_root_idx {
+            Some(root_idx) => self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx),
+            None => return,
+        };
+        let mut remove_idxs = Arena::<K, V, Idx, N>::new_idx_vec();
+
+        // Single in-order pass over arena indexes, the accumulator is always a distinct node
+        let mut acc_idx = sorted_idxs[0].usize();
+        for idx in sorted_idxs.iter().skip(1).map(|idx| idx.usize()) {
+            let (acc_node, node) = self.arena.hard_get_pair_mut(acc_idx, idx);
+            let (acc_key, acc_val) = acc_node.get_mut();
+            let (key, val) = node.get_mut();
+
+            match f(acc_key, acc_val, key, val) {
+                true => remove_idxs.push(Idx::checked_from(idx)),
+                false => acc_idx = idx,
+            }
+        }
+
+        if remove_idxs.is_empty() {
+            return;
+        }
+
+        for i in remove_idxs {
+            self.priv_remove_by_idx(i.usize());
+        }
+
+        self.priv_sparse_rebuild();
     }
 
     /// Splits the collection into two at the given key. Returns everything after the given key, including the key.
@@ -275,7 +518,58 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
-        self.priv_drain_filter(|k, _| k >= key)
+        self.priv_drain_filter(|k, _| k >= key, false)
+    }
+
+    /// Removes pairs for which `f` returns `true`, returning them as a new tree with the same rebalance parameter.
+    /// Pairs are visited in ascending key order.
+    pub fn split_by<F>(&mut self, f: F) -> Self
+    where
+        F: FnMut(&K, &mut V) -> bool,
+        K: Ord,
+    {
+        let mut removed = self.priv_drain_filter(f, false);
+        removed.alpha_num = self.alpha_num;
+        removed.alpha_denom = self.alpha_denom;
+
+        removed
+    }
+
+    /// Consumes the tree, splitting it into pairs for which `f` returns `true` and the rest.
+    /// Both trees keep key order and the rebalance parameter.
+    /// Pairs are visited in ascending key order, so each tree is built by appending at its maximum,
+    /// rebalancing at most once.
+    pub fn partition<F>(self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let mut matching = Self::new();
+        matching.alpha_num = self.alpha_num;
+        matching.alpha_denom = self.alpha_denom;
+
+        let mut rest = Self::new();
+        rest.alpha_num = self.alpha_num;
+        rest.alpha_denom = self.alpha_denom;
+
+        let mut matching_cnt = 0;
+        let mut rest_cnt = 0;
+        for (k, v) in self {
+            match f(&k, &v) {
+                true => {
+                    matching.priv_append_max(k, v);
+                    matching_cnt += 1;
+                }
+                false => {
+                    rest.priv_append_max(k, v);
+                    rest_cnt += 1;
+                }
+            }
+        }
+
+        matching.priv_append_max_finish(matching_cnt);
+        rest.priv_append_max_finish(rest_cnt);
+
+        (matching, rest)
     }
 
     /// Returns the key-value pair corresponding to the given key.
@@ -287,6 +581,11 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
+        #[cfg(feature = "membership_filter")]
+        if !self.priv_may_contain(key) {
+            return None;
+        }
+
         let ngh: NodeGetHelper<Idx> = self.internal_get(None, key);
         match ngh.node_idx() {
             Some(idx) => {
@@ -318,6 +617,11 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
+        #[cfg(feature = "membership_filter")]
+        if !self.priv_may_contain(key) {
+            return None;
+        }
+
         let ngh: NodeGetHelper<Idx> = self.internal_get(None, key);
         match ngh.node_idx() {
             Some(idx) => {
@@ -328,12 +632,57 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Returns the stored key and a mutable reference to the value corresponding to the supplied key.
+    pub fn get_key_value_mut<Q>(&mut self, key: &Q) -> Option<(&K, &mut V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        #[cfg(feature = "membership_filter")]
+        if !self.priv_may_contain(key) {
+            return None;
+        }
+
+        let ngh: NodeGetHelper<Idx> = self.internal_get(None, key);
+        match ngh.node_idx() {
+            Some(idx) => Some(self.arena[idx].get_mut()),
+            None => None,
+        }
+    }
+
+    /// Returns mutable references to the values corresponding to two distinct keys.
+    /// `None` if either key is absent or both keys are equal.
+    pub fn get_pair_mut<Q>(&mut self, key_a: &Q, key_b: &Q) -> Option<(&mut V, &mut V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let ngh_a: NodeGetHelper<Idx> = self.internal_get(None, key_a);
+        let ngh_b: NodeGetHelper<Idx> = self.internal_get(None, key_b);
+        match (ngh_a.node_idx(), ngh_b.node_idx()) {
+            (Some(idx_a), Some(idx_b)) if idx_a != idx_b => {
+                let (node_a, node_b) = self.arena.hard_get_pair_mut(idx_a, idx_b);
+                Some((node_a.get_mut().1, node_b.get_mut().1))
+            }
+            _ => None,
+        }
+    }
+
     /// Clears the tree, removing all elements.
     pub fn clear(&mut self) {
         if !self.is_empty() {
             let rebal_cnt = self.rebal_cnt;
+
+            #[cfg(feature = "profiling")]
+            let rebuild_hook = self.rebuild_hook;
+
             *self = SgTree::new();
             self.rebal_cnt = rebal_cnt;
+
+            #[cfg(feature = "profiling")]
+            {
+                self.rebuild_hook = rebuild_hook;
+            }
         }
     }
 
@@ -360,90 +709,647 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         self.len() == self.capacity()
     }
 
-    /// Returns a reference to the first key-value pair in the tree.
-    /// The key in this pair is the minimum key in the tree.
-    pub fn first_key_value(&self) -> Option<(&K, &V)>
-    where
-        K: Ord,
-    {
-        if !self.is_empty() {
-            let node = &self.arena[self.min_idx];
-            Some((node.key(), node.val()))
-        } else {
-            None
-        }
+    /// Returns `true` if `additional` new elements are guaranteed to fit.
+    pub fn can_insert(&self, additional: usize) -> bool {
+        debug_assert!(self.len() <= self.capacity());
+        additional <= (self.capacity() - self.len())
     }
 
-    /// Returns a reference to the first/minium key in the tree, if any.
-    pub fn first_key(&self) -> Option<&K>
-    where
-        K: Ord,
-    {
-        self.first_key_value().map(|(k, _)| k)
+    /// Fallible variant of `can_insert`.
+    pub fn ensure_capacity(&self, additional: usize) -> Result<(), SgError> {
+        match self.can_insert(additional) {
+            true => Ok(()),
+            false => Err(Self::capacity_err()),
+        }
     }
 
-    /// Removes and returns the first element in the tree.
-    /// The key of this element is the minimum key that was in the tree.
-    pub fn pop_first(&mut self) -> Option<(K, V)>
+    /// Clone into a tree of different capacity `M`, keeping the rebalance parameter.
+    /// Will fail if `M` is less than the current length or exceeds `u16::MAX`.
+    pub fn clone_resize<const M: usize>(&self) -> Result<SgTree<K, V, M>, SgError>
     where
-        K: Ord,
+        K: Clone,
+        V: Clone,
     {
-        self.priv_remove_by_idx(self.min_idx)
+        if M > SgTree::<K, V, M>::max_capacity() {
+            return Err(SgError::MaximumCapacityExceeded);
+        }
+
+        if self.len() > M {
+            return Err(SgTree::<K, V, M>::capacity_err());
+        }
+
+        // Sorted input, so extension is a single batched rebuild
+        let mut sgt = SgTree::<K, V, M>::new();
+        sgt.alpha_num = self.alpha_num;
+        sgt.alpha_denom = self.alpha_denom;
+        sgt.extend(self.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        Ok(sgt)
     }
 
-    /// Returns a reference to the last key-value pair in the tree.
-    /// The key in this pair is the maximum key in the tree.
-    pub fn last_key_value(&self) -> Option<(&K, &V)>
-    where
-        K: Ord,
-    {
-        if !self.is_empty() {
-            let node = &self.arena[self.max_idx];
-            Some((node.key(), node.val()))
-        } else {
-            None
+    /// Moves the tree's elements into a new tree of capacity `M`, without cloning.
+    /// The rebalance parameter is preserved.
+    pub fn resize<const M: usize>(self) -> Result<SgTree<K, V, M>, SgError> {
+        if M > SgTree::<K, V, M>::max_capacity() {
+            return Err(SgError::MaximumCapacityExceeded);
+        }
+
+        if self.len() > M {
+            return Err(SgTree::<K, V, M>::capacity_err());
         }
+
+        // Sorted input, so extension is a single batched rebuild
+        let mut sgt = SgTree::<K, V, M>::new();
+        sgt.alpha_num = self.alpha_num;
+        sgt.alpha_denom = self.alpha_denom;
+        sgt.extend(self);
+
+        Ok(sgt)
     }
 
-    /// Returns a reference to the last/maximum key in the tree, if any.
-    pub fn last_key(&self) -> Option<&K>
+    /// Clones the tree into one with values computed by `f`, preserving physical layout and balance.
+    pub fn map_values<B, F>(&self, f: F) -> SgTree<K, B, N>
     where
-        K: Ord,
+        K: Clone,
+        F: FnMut(&V) -> B,
     {
-        self.last_key_value().map(|(k, _)| k)
+        SgTree {
+            arena: self.arena.clone_map_vals(f),
+            opt_root_idx: self.opt_root_idx,
+            max_idx: self.max_idx,
+            min_idx: self.min_idx,
+            curr_size: self.curr_size,
+            alpha_num: self.alpha_num,
+            alpha_denom: self.alpha_denom,
+            max_size: self.max_size,
+            rebal_cnt: self.rebal_cnt,
+            #[cfg(feature = "fast_rebalance")]
+            rebuild_scratch: NodeRebuildScratch::default(),
+
+            #[cfg(feature = "profiling")]
+            rebuild_hook: self.rebuild_hook,
+        }
     }
 
-    /// Removes and returns the last element in the tree.
-    /// The key of this element is the maximum key that was in the tree.
-    pub fn pop_last(&mut self) -> Option<(K, V)>
+    /// Converts the tree into one with keys computed by `f`, preserving physical layout and balance.
+    /// `f` is called once per key, in ascending order, and must be strictly monotonic (checked via `debug_assert`).
+    pub fn map_keys_monotonic<B, F>(self, f: F) -> SgTree<B, V, N>
     where
-        K: Ord,
+        B: Ord,
+        F: FnMut(K) -> B,
     {
-        self.priv_remove_by_idx(self.max_idx)
-    }
+        let sorted_idxs: ArrayVec<[usize; N]> = match self.opt_root_idx {
+            Some(root_idx) => self.flatten_subtree_to_sorted_idxs(root_idx),
+            None => ArrayVec::new(),
+        };
 
-    /// Returns the number of elements in the tree.
-    pub fn len(&self) -> usize {
-        self.curr_size
-    }
+        let arena = self.arena.map_keys(&sorted_idxs, f);
+        debug_assert!(
+            sorted_idxs
+                .windows(2)
+                .all(|w| arena[w[0]].key() < arena[w[1]].key()),
+            "API misuse: key mapping isn't strictly monotonic!"
+        );
 
-    /// Get the number of times this tree rebalanced itself (for testing and/or performance engineering).
-    /// This count will wrap if `usize::MAX` is exceeded.
-    pub fn rebal_cnt(&self) -> usize {
-        self.rebal_cnt
+        SgTree {
+            arena,
+            opt_root_idx: self.opt_root_idx,
+            max_idx: self.max_idx,
+            min_idx: self.min_idx,
+            curr_size: self.curr_size,
+            alpha_num: self.alpha_num,
+            alpha_denom: self.alpha_denom,
+            max_size: self.max_size,
+            rebal_cnt: self.rebal_cnt,
+            #[cfg(feature = "fast_rebalance")]
+            rebuild_scratch: NodeRebuildScratch::default(),
+
+            #[cfg(feature = "profiling")]
+            rebuild_hook: self.rebuild_hook,
+        }
     }
 
-    // Crate-internal API ----------------------------------------------------------------------------------------------
+    /// Returns the key-value pair at the given in-order position (`rank`), if any.
+    /// E.g. rank `0` is the minimum key's pair.
+    ///
+    /// Runs in `O(log n)` if the `fast_rebalance` feature is enabled (uses cached subtree sizes), else `O(rank)`.
+    pub fn select(&self, rank: usize) -> Option<(&K, &V)> {
+        if rank >= self.len() {
+            return None;
+        }
 
-    // Remove a node by index.
-    // A wrapper for by-key removal, traversal is still required to determine node parent.
-    #[cfg(not(feature = "fast_rebalance"))]
-    pub(crate) fn priv_remove_by_idx(&mut self, idx: usize) -> Option<(K, V)> {
-        if self.arena.is_occupied(idx) {
-            let node = &self.arena[idx];
-            let ngh: NodeGetHelper<Idx> = self.internal_get(None, node.key());
-            debug_assert!(
-                ngh.node_idx().unwrap() == idx,
+        #[cfg(feature = "fast_rebalance")]
+        {
+            let mut rank = rank;
+            let mut opt_curr_idx = self.opt_root_idx;
+            while let Some(curr_idx) = opt_curr_idx {
+                let node = &self.arena[curr_idx];
+                let left_size = node
+                    .left_idx()
+                    .map_or(0, |left_idx| self.arena[left_idx].subtree_size());
+
+                match rank.cmp(&left_size) {
+                    Ordering::Less => opt_curr_idx = node.left_idx(),
+                    Ordering::Equal => return Some((node.key(), node.val())),
+                    Ordering::Greater => {
+                        rank -= left_size + 1;
+                        opt_curr_idx = node.right_idx();
+                    }
+                }
+            }
+
+            unreachable!("Internal invariant failed: subtree sizes don't match length!");
+        }
+
+        #[cfg(not(feature = "fast_rebalance"))]
+        self.iter().nth(rank)
+    }
+
+    /// Returns the in-order position (`rank`) of the given key, if present.
+    /// E.g. the number of keys in the tree strictly less than it.
+    ///
+    /// Runs in `O(log n)` if the `fast_rebalance` feature is enabled (uses cached subtree sizes), else `O(rank)`.
+    pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        #[cfg(feature = "fast_rebalance")]
+        {
+            let mut rank = 0;
+            let mut opt_curr_idx = self.opt_root_idx;
+            while let Some(curr_idx) = opt_curr_idx {
+                let node = &self.arena[curr_idx];
+                let left_size = node
+                    .left_idx()
+                    .map_or(0, |left_idx| self.arena[left_idx].subtree_size());
+
+                match key.cmp(node.key().borrow()) {
+                    Ordering::Less => opt_curr_idx = node.left_idx(),
+                    Ordering::Equal => return Some(rank + left_size),
+                    Ordering::Greater => {
+                        rank += left_size + 1;
+                        opt_curr_idx = node.right_idx();
+                    }
+                }
+            }
+
+            None
+        }
+
+        #[cfg(not(feature = "fast_rebalance"))]
+        match self.contains_key(key) {
+            true => Some(self.iter().take_while(|(k, _)| (*k).borrow() < key).count()),
+            false => None,
+        }
+    }
+
+    /// Returns the number of elements within a range, without iterating it.
+    ///
+    /// Runs in `O(log n)` if the `fast_rebalance` feature is enabled (uses cached subtree sizes),
+    /// else `O(log n + k)` for `k` elements in range.
+    pub fn range_count<T, R>(&self, range: &R) -> usize
+    where
+        T: Ord + ?Sized,
+        R: RangeBounds<T>,
+        K: Borrow<T> + Ord,
+    {
+        #[cfg(feature = "fast_rebalance")]
+        {
+            let lo = match range.start_bound() {
+                Included(start) => self.priv_count_below(start, false),
+                Excluded(start) => self.priv_count_below(start, true),
+                Unbounded => 0,
+            };
+
+            let hi = match range.end_bound() {
+                Included(end) => self.priv_count_below(end, true),
+                Excluded(end) => self.priv_count_below(end, false),
+                Unbounded => self.len(),
+            };
+
+            hi.saturating_sub(lo)
+        }
+
+        #[cfg(not(feature = "fast_rebalance"))]
+        self.range_search(range).len()
+    }
+
+    /// Returns `true` if every key is within a range (vacuously so if the tree is empty).
+    /// Runs in `O(1)`: only the cached minimum and maximum keys are checked.
+    pub fn keys_all_in_range<T, R>(&self, range: &R) -> bool
+    where
+        T: Ord + ?Sized,
+        R: RangeBounds<T>,
+        K: Borrow<T> + Ord,
+    {
+        match (self.first_key(), self.last_key()) {
+            (Some(min_key), Some(max_key)) => {
+                range.contains(min_key.borrow()) && range.contains(max_key.borrow())
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if at least one key is within a range.
+    /// Runs in `O(log n)`: a single descent, steering away from the side of the range each key falls on.
+    pub fn keys_any_in_range<T, R>(&self, range: &R) -> bool
+    where
+        T: Ord + ?Sized,
+        R: RangeBounds<T>,
+        K: Borrow<T> + Ord,
+    {
+        let mut opt_curr_idx = self.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            let key = node.key().borrow();
+
+            if range.contains(key) {
+                return true;
+            }
+
+            let below_start = match range.start_bound() {
+                Included(start) => key < start,
+                Excluded(start) => key <= start,
+                Unbounded => false,
+            };
+
+            opt_curr_idx = match below_start {
+                true => node.right_idx(),
+                false => node.left_idx(),
+            };
+        }
+
+        false
+    }
+
+    /// Returns a reference to the first key-value pair in the tree.
+    /// The key in this pair is the minimum key in the tree.
+    pub fn first_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            let node = &self.arena[self.min_idx];
+            Some((node.key(), node.val()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first key and a mutable reference to its value, via the cached minimum index.
+    pub fn first_key_value_mut(&mut self) -> Option<(&K, &mut V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            Some(self.arena[self.min_idx].get_mut())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the first/minium key in the tree, if any.
+    pub fn first_key(&self) -> Option<&K>
+    where
+        K: Ord,
+    {
+        self.first_key_value().map(|(k, _)| k)
+    }
+
+    /// Removes and returns the first element in the tree.
+    /// The key of this element is the minimum key that was in the tree.
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        let opt_kv = self.priv_remove_by_idx(self.min_idx);
+        self.priv_sparse_rebuild();
+        opt_kv
+    }
+
+    /// Returns a reference to the last key-value pair in the tree.
+    /// The key in this pair is the maximum key in the tree.
+    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            let node = &self.arena[self.max_idx];
+            Some((node.key(), node.val()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the last key and a mutable reference to its value, via the cached maximum index.
+    pub fn last_key_value_mut(&mut self) -> Option<(&K, &mut V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            Some(self.arena[self.max_idx].get_mut())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the last/maximum key in the tree, if any.
+    pub fn last_key(&self) -> Option<&K>
+    where
+        K: Ord,
+    {
+        self.last_key_value().map(|(k, _)| k)
+    }
+
+    /// Removes and returns the last element in the tree.
+    /// The key of this element is the maximum key that was in the tree.
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        let opt_kv = self.priv_remove_by_idx(self.max_idx);
+        self.priv_sparse_rebuild();
+        opt_kv
+    }
+
+    /// Swaps the key and value of the minimum entry in-place, via the cached minimum index.
+    /// Returns the old pair, or inserts and returns `Ok(None)` if the tree is empty.
+    /// If the new key isn't strictly less than every other key in the tree, the pair is returned in `Err`.
+    pub fn replace_entry_at_min(&mut self, key: K, val: V) -> Result<Option<(K, V)>, (K, V)>
+    where
+        K: Ord,
+    {
+        if self.is_empty() {
+            self.insert(key, val);
+            return Ok(None);
+        }
+
+        if let Some(next_idx) = self.priv_boundary_neighbor_idx(false) {
+            if key >= *self.arena[next_idx].key() {
+                return Err((key, val));
+            }
+        }
+
+        let node = &mut self.arena[self.min_idx];
+        Ok(Some((node.replace_key(key), node.replace_val(val))))
+    }
+
+    /// Swaps the key and value of the maximum entry in-place, via the cached maximum index.
+    /// Returns the old pair, or inserts and returns `Ok(None)` if the tree is empty.
+    /// If the new key isn't strictly greater than every other key in the tree, the pair is returned in `Err`.
+    pub fn replace_entry_at_max(&mut self, key: K, val: V) -> Result<Option<(K, V)>, (K, V)>
+    where
+        K: Ord,
+    {
+        if self.is_empty() {
+            self.insert(key, val);
+            return Ok(None);
+        }
+
+        if let Some(prev_idx) = self.priv_boundary_neighbor_idx(true) {
+            if key <= *self.arena[prev_idx].key() {
+                return Err((key, val));
+            }
+        }
+
+        let node = &mut self.arena[self.max_idx];
+        Ok(Some((node.replace_key(key), node.replace_val(val))))
+    }
+
+    /// Returns the number of elements in the tree.
+    pub fn len(&self) -> usize {
+        self.curr_size
+    }
+
+    /// Get the number of times this tree rebalanced itself (for testing and/or performance engineering).
+    /// This count will wrap if `usize::MAX` is exceeded.
+    pub fn rebal_cnt(&self) -> usize {
+        self.rebal_cnt
+    }
+
+    /// Reset the rebalance count to zero, e.g. at the start of a measurement window.
+    pub fn reset_rebal_cnt(&mut self) {
+        self.rebal_cnt = 0;
+    }
+
+    /// Rebuild the entire tree into a perfectly balanced shape, satisfying any valid `a`.
+    /// Also resets the max size tracked since the last full rebuild to the current length.
+    pub fn rebalance_now(&mut self) {
+        if let Some(root_idx) = self.opt_root_idx {
+            self.rebuild(root_idx);
+        }
+
+        self.max_size = self.curr_size;
+    }
+
+    /// Register a callback invoked after every rebuild with the rebuilt subtree's size, or `None` to remove it.
+    #[cfg(feature = "profiling")]
+    pub fn set_rebuild_hook(&mut self, hook: Option<fn(usize)>) {
+        self.rebuild_hook = hook;
+    }
+
+    /// Check the tree's internal invariants without panicking, suitable for untrusted state.
+    /// Every link must point at an occupied in-bounds slot, every node must be reachable from the root exactly once
+    /// (so no cycles), keys must be ordered, and the cached length and min/max must be correct.
+    pub fn verify(&self) -> Result<(), SgError> {
+        let corrupt = Err(SgError::CorruptState);
+
+        // Length and allocator metadata
+        if (self.arena.iter().filter(|n| n.is_some()).count() != self.len())
+            || !self.arena.free_list_is_consistent()
+        {
+            return corrupt;
+        }
+
+        let root_idx = match self.opt_root_idx {
+            Some(root_idx) if self.arena.is_occupied(root_idx) => root_idx,
+            Some(_) => return corrupt,
+            None if self.curr_size == 0 => return Ok(()),
+            None => return corrupt,
+        };
+
+        // Each worklist item carries its exclusive key bounds, inherited from ancestors
+        let mut visited = array_vec![[bool; N]];
+        visited.resize(self.arena.len(), false);
+        visited[root_idx] = true;
+
+        let mut reachable_cnt = 1;
+        let mut subtree_worklist =
+            array_vec![[BoundedIdx; N] => (Idx::checked_from(root_idx), None, None)];
+
+        while let Some((idx, opt_lower_idx, opt_upper_idx)) = subtree_worklist.pop() {
+            let node = &self.arena[idx.usize()];
+
+            if let Some(lower_idx) = opt_lower_idx {
+                if self.arena[lower_idx.usize()].key() >= node.key() {
+                    return corrupt;
+                }
+            }
+
+            if let Some(upper_idx) = opt_upper_idx {
+                if self.arena[upper_idx.usize()].key() <= node.key() {
+                    return corrupt;
+                }
+            }
+
+            for (opt_child_idx, child_bounds) in [
+                (node.left_idx(), (opt_lower_idx, Some(idx))),
+                (node.right_idx(), (Some(idx), opt_upper_idx)),
+            ] {
+                if let Some(child_idx) = opt_child_idx {
+                    if !self.arena.is_occupied(child_idx) || visited[child_idx] {
+                        return corrupt;
+                    }
+
+                    visited[child_idx] = true;
+                    reachable_cnt += 1;
+                    subtree_worklist.push((
+                        Idx::checked_from(child_idx),
+                        child_bounds.0,
+                        child_bounds.1,
+                    ));
+                }
+            }
+
+            #[cfg(feature = "fast_rebalance")]
+            {
+                let child_size =
+                    |opt_idx: Option<usize>| opt_idx.map_or(0, |i| self.arena[i].subtree_size());
+
+                if node.subtree_size()
+                    != (1 + child_size(node.left_idx()) + child_size(node.right_idx()))
+                {
+                    return corrupt;
+                }
+            }
+        }
+
+        if reachable_cnt != self.len() {
+            return corrupt;
+        }
+
+        // Links are now known to be acyclic, so extremes can be found by walking
+        let mut min_idx = root_idx;
+        while let Some(left_idx) = self.arena[min_idx].left_idx() {
+            min_idx = left_idx;
+        }
+
+        let mut max_idx = root_idx;
+        while let Some(right_idx) = self.arena[max_idx].right_idx() {
+            max_idx = right_idx;
+        }
+
+        match (min_idx == self.min_idx) && (max_idx == self.max_idx) {
+            true => Ok(()),
+            false => corrupt,
+        }
+    }
+
+    /// Panics if the tree's internal invariants don't hold: BST ordering, a single parent per node,
+    /// cached min/max pointing at the extremes, and max size within twice the length. Compiled out if `debug_assertions` are disabled.
+    #[cfg(feature = "debug_checks")]
+    pub fn debug_assert_valid(&self) {
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(
+                self.arena.iter().filter(|n| n.is_some()).count(),
+                self.len(),
+                "Internal invariant failed: arena occupancy doesn't match length!"
+            );
+
+            assert!(
+                self.max_size <= (2 * self.len()),
+                "Internal invariant failed: max size drifted from length!"
+            );
+
+            if let Some(root_idx) = self.opt_root_idx {
+                let min_key = self.arena[self.min_idx].key();
+                let max_key = self.arena[self.max_idx].key();
+                let mut reachable_idxs = array_vec![[Idx; N] => Idx::checked_from(root_idx)];
+
+                // Same bounded worklist as `verify`, each item carries exclusive key bounds inherited from ancestors
+                let mut subtree_worklist =
+                    array_vec![[BoundedIdx; N] => (Idx::checked_from(root_idx), None, None)];
+
+                while let Some((idx, opt_lower_idx, opt_upper_idx)) = subtree_worklist.pop() {
+                    let node = &self.arena[idx.usize()];
+                    assert!(
+                        (min_key <= node.key()) && (node.key() <= max_key),
+                        "Internal invariant failed: cached min/max isn't an extreme!"
+                    );
+                    let above_lower = match opt_lower_idx {
+                        Some(lower_idx) => self.arena[lower_idx.usize()].key() < node.key(),
+                        None => true,
+                    };
+                    let below_upper = match opt_upper_idx {
+                        Some(upper_idx) => node.key() < self.arena[upper_idx.usize()].key(),
+                        None => true,
+                    };
+                    assert!(
+                        above_lower && below_upper,
+                        "Internal invariant failed: node ordered incorrectly w.r.t. an ancestor!"
+                    );
+
+                    for (opt_child_idx, child_bounds) in [
+                        (node.left_idx(), (opt_lower_idx, Some(idx))),
+                        (node.right_idx(), (Some(idx), opt_upper_idx)),
+                    ] {
+                        if let Some(child_idx) = opt_child_idx {
+                            assert!(
+                                reachable_idxs.len() < self.len(),
+                                "Internal invariant failed: node with multiple parents present!"
+                            );
+                            reachable_idxs.push(Idx::checked_from(child_idx));
+                            subtree_worklist.push((
+                                Idx::checked_from(child_idx),
+                                child_bounds.0,
+                                child_bounds.1,
+                            ));
+                        }
+                    }
+                }
+
+                reachable_idxs.sort_unstable();
+                assert!(
+                    (reachable_idxs.len() == self.len())
+                        && reachable_idxs.windows(2).all(|w| w[0] != w[1]),
+                    "Internal invariant failed: node with multiple parents present!"
+                );
+            }
+        }
+    }
+
+    /// Assemble a tree from raw, unvalidated parts. Only exposed to the fuzzing harness.
+    #[cfg(fuzzing)]
+    pub fn from_raw_parts(
+        arena: Arena<K, V, Idx, N>,
+        opt_root_idx: Option<usize>,
+        min_idx: usize,
+        max_idx: usize,
+        curr_size: usize,
+    ) -> Self {
+        let mut sgt = Self::new();
+        sgt.arena = arena;
+        sgt.opt_root_idx = opt_root_idx;
+        sgt.min_idx = min_idx;
+        sgt.max_idx = max_idx;
+        sgt.curr_size = curr_size;
+        sgt.max_size = curr_size;
+        sgt
+    }
+
+    // Crate-internal API ----------------------------------------------------------------------------------------------
+
+    // Remove a node by index.
+    // A wrapper for by-key removal, traversal is still required to determine node parent.
+    #[cfg(not(feature = "fast_rebalance"))]
+    pub(crate) fn priv_remove_by_idx(&mut self, idx: usize) -> Option<(K, V)> {
+        if self.arena.is_occupied(idx) {
+            let node = &self.arena[idx];
+            let ngh: NodeGetHelper<Idx> = self.internal_get(None, node.key());
+            debug_assert!(
+                ngh.node_idx().unwrap() == idx,
                 "By-key retrieval index doesn't match arena storage index!"
             );
             self.priv_remove(None, ngh)
@@ -470,34 +1376,80 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
-    // Flatten subtree into array of node indexes sorted by node key
-    pub(crate) fn flatten_subtree_to_sorted_idxs<U: SmallUnsigned + Default + Copy>(
-        &self,
-        idx: usize,
-    ) -> ArrayVec<[U; N]> {
-        let mut subtree_worklist = array_vec![[U; N] => U::checked_from(idx)];
-        let mut subtree_flattened = array_vec![[U; N] => U::checked_from(idx)];
+    // Rebuild the entire tree if removals have left it sufficiently sparse.
+    // Every removal path must call this, it's what keeps `max_size <= 2 * curr_size`.
+    pub(crate) fn priv_sparse_rebuild(&mut self) {
+        if self.max_size > (2 * self.curr_size) {
+            if let Some(root_idx) = self.opt_root_idx {
+                self.rebuild(root_idx);
+            }
 
-        while let Some(idx) = subtree_worklist.pop() {
-            let node = &self.arena[idx.usize()];
+            // Reset even if now empty, else a stale `max_size` skews `alpha_balance_depth` for later inserts
+            self.max_size = self.curr_size;
+        }
+    }
 
-            if let Some(left_idx) = node.left_idx() {
-                let left = U::checked_from(left_idx);
-                subtree_worklist.push(left);
-                subtree_flattened.push(left);
+    // Index of the minimum's successor (or, if `at_max`, the maximum's predecessor), in O(log n).
+    // The boundary node has no child on the outer side, so its neighbor is either the nearest node
+    // on the inner side of its subtree or, failing that, its parent on the outer spine.
+    fn priv_boundary_neighbor_idx(&self, at_max: bool) -> Option<usize> {
+        let outer = |idx: usize| match at_max {
+            true => self.arena[idx].right_idx(),
+            false => self.arena[idx].left_idx(),
+        };
+        let inner = |idx: usize| match at_max {
+            true => self.arena[idx].left_idx(),
+            false => self.arena[idx].right_idx(),
+        };
+
+        let mut opt_parent_idx = None;
+        let mut idx = self.opt_root_idx?;
+        while let Some(next_idx) = outer(idx) {
+            opt_parent_idx = Some(idx);
+            idx = next_idx;
+        }
+
+        match inner(idx) {
+            Some(mut neighbor_idx) => {
+                while let Some(next_idx) = outer(neighbor_idx) {
+                    neighbor_idx = next_idx;
+                }
+                Some(neighbor_idx)
             }
+            None => opt_parent_idx,
+        }
+    }
 
-            if let Some(right_idx) = node.right_idx() {
-                let right = U::checked_from(right_idx);
-                subtree_worklist.push(right);
-                subtree_flattened.push(right);
+    // Replace the value of the node at a known-good index with `f(old_val)`, returning the node's new index.
+    // The entry is unlinked while `f` runs, so if `f` panics the tree stays valid (just without that entry).
+    pub(crate) fn map_val_by_idx<F: FnOnce(V) -> V>(&mut self, idx: usize, f: F) -> usize
+    where
+        K: Ord,
+    {
+        match self.priv_remove_by_idx(idx) {
+            Some((key, val)) => {
+                self.priv_sparse_rebuild();
+                self.internal_balancing_insert::<Idx>(key, f(val)).1
+            }
+            None => {
+                panic!("Internal invariant failed: attempted value map of node at invalid index.")
             }
         }
+    }
 
-        // Sort by key
-        // Faster than sort_by() but may not preserve order of equal elements - OK b/c tree won't have equal nodes
-        subtree_flattened
-            .sort_unstable_by(|a, b| self.arena[a.usize()].key().cmp(self.arena[b.usize()].key()));
+    // Flatten subtree into array of node indexes sorted by node key
+    pub(crate) fn flatten_subtree_to_sorted_idxs<U: SmallUnsigned + Default + Copy>(
+        &self,
+        idx: usize,
+    ) -> ArrayVec<[U; N]> {
+        let mut subtree_worklist = ArrayVec::<[U; N]>::new();
+        let mut subtree_flattened = ArrayVec::<[U; N]>::new();
+        Self::priv_flatten_subtree_into(
+            &self.arena,
+            idx,
+            &mut subtree_worklist,
+            &mut subtree_flattened,
+        );
 
         subtree_flattened
     }
@@ -522,9 +1474,34 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
-    /// Total common elements between two trees
+    /// Total common elements between two trees.
+    /// Single merge pass over both sorted sequences, no allocation.
     pub(crate) fn intersect_cnt(&self, other: &SgTree<K, V, N>) -> usize {
-        self.iter().filter(|(k, _)| other.contains_key(k)).count()
+        let mut self_iter = self.iter();
+        let mut other_iter = other.iter();
+
+        let mut opt_self = self_iter.next();
+        let mut opt_other = other_iter.next();
+        let mut cnt = 0;
+
+        // If either is shorter, short-circuit.
+        while let (Some((self_key, _)), Some((other_key, _))) = (opt_self, opt_other) {
+            match self_key.cmp(other_key) {
+                Ordering::Less => {
+                    opt_self = self_iter.next();
+                }
+                Ordering::Equal => {
+                    cnt += 1;
+                    opt_self = self_iter.next();
+                    opt_other = other_iter.next();
+                }
+                Ordering::Greater => {
+                    opt_other = other_iter.next();
+                }
+            }
+        }
+
+        cnt
     }
 
     // Maximum tree capacity (const N value).
@@ -532,6 +1509,14 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         Idx::MAX as usize
     }
 
+    // Error for an insertion that doesn't fit, distinguishing a tree that can never hold anything.
+    pub(crate) fn capacity_err() -> SgError {
+        match N {
+            0 => SgError::CapacityZero,
+            _ => SgError::StackCapacityExceeded,
+        }
+    }
+
     /// Find arena indexes for a given range
     pub(crate) fn range_search<T, R>(&self, range: &R) -> ArrayVec<[usize; N]>
     where
@@ -539,22 +1524,36 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         R: RangeBounds<T>,
         K: Borrow<T> + Ord,
     {
-        let mut node_idxs = ArrayVec::<[usize; N]>::new();
-
-        for (idx, node) in self
-            .arena
-            .iter()
-            .enumerate()
-            .filter_map(|(i, node)| Some((i, node.as_ref()?)))
-        {
-            if range.contains(node.key().borrow()) {
-                node_idxs.push(idx);
-            }
-        }
-
-        node_idxs.sort_unstable_by_key(|idx| self.arena[*idx].key());
-
-        node_idxs
+        self.priv_pruned_search(
+            // Smaller keys can only be in range if the start bound is below this key
+            |key| match range.start_bound() {
+                Included(start) | Excluded(start) => start < key.borrow(),
+                Unbounded => true,
+            },
+            // Larger keys can only be in range if the end bound is above this key
+            |key| match range.end_bound() {
+                Included(end) | Excluded(end) => end > key.borrow(),
+                Unbounded => true,
+            },
+            |key| range.contains(key.borrow()),
+        )
+    }
+
+    /// Find arena indexes for keys starting with a given byte prefix.
+    /// Matching keys are contiguous, from the prefix itself up to (excluding) the prefix with its last non-`0xFF`
+    /// byte incremented. A key is below that bound iff it's below the prefix or starts with it.
+    pub(crate) fn prefix_search(&self, prefix: &[u8]) -> ArrayVec<[usize; N]>
+    where
+        K: Borrow<[u8]> + Ord,
+    {
+        self.priv_pruned_search(
+            |key| prefix < key.borrow(),
+            |key| {
+                let key: &[u8] = key.borrow();
+                (key < prefix) || key.starts_with(prefix)
+            },
+            |key| key.borrow().starts_with(prefix),
+        )
     }
 
     /// Validate range
@@ -581,110 +1580,414 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
-    // Iterative search. If key found, returns node idx, parent idx, and a bool indicating if node is right child
-    // `opt_path` is only populated if `Some` and key is found.
-    pub(crate) fn internal_get<Q, U: SmallUnsigned + Default + Copy>(
-        &self,
-        mut opt_path: Option<&mut ArrayVec<[U; N]>>,
-        key: &Q,
-    ) -> NodeGetHelper<U>
+    // Iterative search. If key found, returns node idx, parent idx, and a bool indicating if node is right child
+    // `opt_path` is only populated if `Some` and key is found.
+    pub(crate) fn internal_get<Q, U: SmallUnsigned + Default + Copy>(
+        &self,
+        mut opt_path: Option<&mut ArrayVec<[U; N]>>,
+        key: &Q,
+    ) -> NodeGetHelper<U>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        match self.opt_root_idx {
+            Some(root_idx) => {
+                let mut opt_parent_idx = None;
+                let mut curr_idx = root_idx;
+                let mut is_right_child = false;
+                loop {
+                    let node = &self.arena[curr_idx];
+
+                    if let Some(ref mut path) = opt_path {
+                        path.push(U::checked_from(curr_idx));
+                    }
+
+                    match key.cmp(node.key().borrow()) {
+                        Ordering::Less => match node.left_idx() {
+                            Some(lt_idx) => {
+                                opt_parent_idx = Some(curr_idx);
+                                curr_idx = lt_idx;
+                                is_right_child = false;
+                            }
+                            None => {
+                                if let Some(path) = opt_path {
+                                    path.clear(); // Find failed, clear path
+                                }
+
+                                return NodeGetHelper::new(None, None, false);
+                            }
+                        },
+                        Ordering::Equal => {
+                            if let Some(path) = opt_path {
+                                path.pop(); // Only parents in path
+                            }
+
+                            return NodeGetHelper::new(
+                                Some(curr_idx),
+                                opt_parent_idx,
+                                is_right_child,
+                            );
+                        }
+                        Ordering::Greater => match node.right_idx() {
+                            Some(gt_idx) => {
+                                opt_parent_idx = Some(curr_idx);
+                                curr_idx = gt_idx;
+                                is_right_child = true;
+                            }
+                            None => {
+                                if let Some(path) = opt_path {
+                                    path.clear(); // Find failed, clear path
+                                }
+
+                                return NodeGetHelper::new(None, None, false);
+                            }
+                        },
+                    }
+                }
+            }
+            None => NodeGetHelper::new(None, None, false),
+        }
+    }
+
+    // Sorted insert of node into the tree (outer).
+    // Re-balances the tree if necessary.
+    //
+    // Returns the old value, if any, and the index of the new node in the arena.
+    pub(crate) fn internal_balancing_insert<U: Default + Copy + Ord + Sub + SmallUnsigned>(
+        &mut self,
+        key: K,
+        val: V,
+    ) -> (Option<V>, usize) {
+        let mut path: ArrayVec<[U; N]> = Arena::<K, V, U, N>::new_idx_vec();
+        let (opt_val, ngh) = self.priv_insert(&mut path, key, val);
+        self.priv_insert_rebalance(&path, opt_val.is_none());
+
+        debug_assert!(ngh.node_idx().is_some());
+        let new_node_idx = ngh.node_idx().expect("Inserted node index must be `Some`");
+        (opt_val, new_node_idx)
+    }
+
+    // Iterative search that, unlike `internal_get`, keeps the traversal on a miss.
+    // If key found, returns node idx and `path` holds its parents (same as `internal_get`).
+    // If key not found, node idx is `None`, parent idx and side are where the key would be linked,
+    // and `path` holds every node visited - exactly what `internal_vacant_insert` needs to skip a second traversal.
+    pub(crate) fn internal_entry_search<U: SmallUnsigned + Default + Copy>(
+        &self,
+        path: &mut ArrayVec<[U; N]>,
+        key: &K,
+    ) -> NodeGetHelper<U> {
+        let mut opt_curr_idx = self.opt_root_idx;
+        let mut opt_parent_idx = None;
+        let mut is_right_child = false;
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            match key.cmp(node.key()) {
+                Ordering::Less => {
+                    opt_curr_idx = node.left_idx();
+                    is_right_child = false;
+                }
+                Ordering::Equal => {
+                    return NodeGetHelper::new(Some(curr_idx), opt_parent_idx, is_right_child);
+                }
+                Ordering::Greater => {
+                    opt_curr_idx = node.right_idx();
+                    is_right_child = true;
+                }
+            }
+
+            path.push(U::checked_from(curr_idx));
+            opt_parent_idx = Some(curr_idx);
+        }
+
+        NodeGetHelper::new(None, opt_parent_idx, is_right_child)
+    }
+
+    // Insert a key known to be absent, linking it at the position found by `internal_entry_search`.
+    // `path` and `ngh` must come from that search, with the tree unmodified since.
+    // Re-balances the tree if necessary.
+    //
+    // Returns the index of the new node in the arena.
+    pub(crate) fn internal_vacant_insert<U: Default + Copy + Ord + Sub + SmallUnsigned>(
+        &mut self,
+        path: &ArrayVec<[U; N]>,
+        ngh: &NodeGetHelper<U>,
+        key: K,
+        val: V,
+    ) -> usize {
+        debug_assert!(ngh.node_idx().is_none());
+        debug_assert_eq!(path.last().map(|idx| idx.usize()), ngh.parent_idx());
+
+        let new_node_idx = self.arena.add(key, val);
+        match ngh.parent_idx() {
+            Some(parent_idx) => {
+                // Only a left child of the min (right child of the max) can be a new min (max)
+                if ngh.is_right_child() {
+                    self.arena[parent_idx].set_right_idx(Some(new_node_idx));
+                    if parent_idx == self.max_idx {
+                        self.max_idx = new_node_idx;
+                    }
+                } else {
+                    self.arena[parent_idx].set_left_idx(Some(new_node_idx));
+                    if parent_idx == self.min_idx {
+                        self.min_idx = new_node_idx;
+                    }
+                }
+            }
+            None => {
+                debug_assert_eq!(self.curr_size, 0);
+                self.opt_root_idx = Some(new_node_idx);
+                self.max_idx = new_node_idx;
+                self.min_idx = new_node_idx;
+            }
+        }
+
+        self.curr_size += 1;
+        self.max_size += 1;
+        self.priv_insert_rebalance(path, true);
+
+        new_node_idx
+    }
+
+    // Insert every pair from `iter`, deferring rebalancing until the batch completes.
+    // Keys already in the tree are updated in-place. New pairs are staged in the arena, unlinked, then sorted and
+    // merged with the tree's in-order nodes: at most one rebuild (of the whole tree), and no search walks a
+    // degenerate path, regardless of input order.
+    //
+    // Stops at the first new key that doesn't fit, returning `Err` (pairs before it remain inserted).
+    // Otherwise returns the number of new keys added.
+    pub(crate) fn try_insert_deferred<I>(&mut self, iter: I) -> Result<usize, SgError>
+    where
+        K: Ord,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        // Staged (arena index, arrival order) pairs, sorted and deduplicated up to `sorted_len`
+        let mut staged = ArrayVec::<[(Idx, usize); N]>::new();
+        let mut sorted_len = 0;
+        let mut res = Ok(());
+
+        for (seq, (key, val)) in iter.into_iter().enumerate() {
+            let ngh: NodeGetHelper<Idx> = self.internal_get(None, &key);
+            if let Some(idx) = ngh.node_idx() {
+                let node = &mut self.arena[idx];
+                node.set_key(key);
+                node.replace_val(val);
+                continue;
+            }
+
+            if (self.len() + staged.len()) == self.capacity() {
+                // Staged duplicates may be holding slots, and the key may be one of them
+                if sorted_len < staged.len() {
+                    Self::priv_sort_staged(&mut self.arena, &mut staged);
+                    sorted_len = staged.len();
+                }
+
+                let arena = &self.arena;
+                if let Ok(pos) =
+                    staged.binary_search_by(|(idx, _)| arena[idx.usize()].key().cmp(&key))
+                {
+                    let node = &mut self.arena[staged[pos].0.usize()];
+                    node.set_key(key);
+                    node.replace_val(val);
+                    continue;
+                }
+
+                if (self.len() + staged.len()) == self.capacity() {
+                    res = Err(Self::capacity_err());
+                    break;
+                }
+            }
+
+            staged.push((Idx::checked_from(self.arena.add(key, val)), seq));
+        }
+
+        if sorted_len < staged.len() {
+            Self::priv_sort_staged(&mut self.arena, &mut staged);
+        }
+
+        let added_cnt = staged.len();
+        if added_cnt > 0 {
+            let existing_idxs = match self.opt_root_idx {
+                Some(root_idx) => self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx),
+                None => Arena::<K, V, Idx, N>::new_idx_vec(),
+            };
+
+            // Key sets are disjoint: staged keys were absent from the tree
+            let mut merged_idxs = Arena::<K, V, Idx, N>::new_idx_vec();
+            let mut staged_idxs = staged.iter().map(|(idx, _)| *idx).peekable();
+            for existing_idx in existing_idxs {
+                let existing_key = self.arena[existing_idx.usize()].key();
+                while let Some(staged_idx) =
+                    staged_idxs.next_if(|idx| self.arena[idx.usize()].key() < existing_key)
+                {
+                    merged_idxs.push(staged_idx);
+                }
+                merged_idxs.push(existing_idx);
+            }
+            merged_idxs.extend(staged_idxs);
+
+            self.curr_size += added_cnt;
+            self.priv_rebuild_from_sorted_idxs(&merged_idxs);
+        }
+
+        res.map(|_| added_cnt)
+    }
+
+    // Build a tree from pairs in strictly ascending key order, without searches and with at most one rebuild.
+    // Returns `Err` if the pairs don't fit.
+    pub(crate) fn try_from_sorted_iter<I>(iter: I) -> Result<Self, SgError>
+    where
+        K: Ord,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut sgt = Self::new();
+        let mut append_cnt = 0;
+
+        for (key, val) in iter {
+            if sgt.is_full() {
+                return Err(Self::capacity_err());
+            }
+
+            sgt.priv_append_max(key, val);
+            append_cnt += 1;
+        }
+
+        sgt.priv_append_max_finish(append_cnt);
+        Ok(sgt)
+    }
+
+    /// Constant-time membership hint: `false` only if `key` definitely isn't in the tree.
+    /// Only rejects keys outside `[min, max]`, not a Bloom-style filter: every miss inside that span returns `true`.
+    /// Uses the cached extremes, which every insert/remove already maintains, so no extra storage is needed.
+    #[cfg(feature = "membership_filter")]
+    pub(crate) fn priv_may_contain<Q>(&self, key: &Q) -> bool
     where
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
-        match self.opt_root_idx {
-            Some(root_idx) => {
-                let mut opt_parent_idx = None;
-                let mut curr_idx = root_idx;
-                let mut is_right_child = false;
-                loop {
-                    let node = &self.arena[curr_idx];
+        match self.is_empty() {
+            true => false,
+            false => {
+                key >= self.arena[self.min_idx].key().borrow()
+                    && key <= self.arena[self.max_idx].key().borrow()
+            }
+        }
+    }
 
-                    if let Some(ref mut path) = opt_path {
-                        path.push(U::checked_from(curr_idx));
-                    }
+    // Private API -----------------------------------------------------------------------------------------------------
 
-                    match key.cmp(node.key().borrow()) {
-                        Ordering::Less => match node.left_idx() {
-                            Some(lt_idx) => {
-                                opt_parent_idx = Some(curr_idx);
-                                curr_idx = lt_idx;
-                                is_right_child = false;
-                            }
-                            None => {
-                                if let Some(path) = opt_path {
-                                    path.clear(); // Find failed, clear path
-                                }
+    /// Count keys strictly less than `key`, or less than or equal to it if `inclusive`.
+    /// `O(log n)` descent using cached subtree sizes.
+    #[cfg(feature = "fast_rebalance")]
+    fn priv_count_below<Q>(&self, key: &Q, inclusive: bool) -> usize
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut cnt = 0;
+        let mut opt_curr_idx = self.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            let go_right = match key.cmp(node.key().borrow()) {
+                Ordering::Less => false,
+                Ordering::Equal => inclusive,
+                Ordering::Greater => true,
+            };
+
+            if go_right {
+                cnt += node
+                    .left_idx()
+                    .map_or(0, |left_idx| self.arena[left_idx].subtree_size())
+                    + 1;
+                opt_curr_idx = node.right_idx();
+            } else {
+                opt_curr_idx = node.left_idx();
+            }
+        }
 
-                                return NodeGetHelper::new(None, None, false);
-                            }
-                        },
-                        Ordering::Equal => {
-                            if let Some(path) = opt_path {
-                                path.pop(); // Only parents in path
-                            }
+        cnt
+    }
 
-                            return NodeGetHelper::new(
-                                Some(curr_idx),
-                                opt_parent_idx,
-                                is_right_child,
-                            );
-                        }
-                        Ordering::Greater => match node.right_idx() {
-                            Some(gt_idx) => {
-                                opt_parent_idx = Some(curr_idx);
-                                curr_idx = gt_idx;
-                                is_right_child = true;
-                            }
-                            None => {
-                                if let Some(path) = opt_path {
-                                    path.clear(); // Find failed, clear path
-                                }
+    // Iterative in-order traversal collecting arena indexes of keys in a contiguous span, pruning subtrees outside it.
+    // `left_in_span(key)`: smaller keys may be in the span. `right_in_span(key)`: larger keys may be in the span.
+    fn priv_pruned_search<L, R, C>(
+        &self,
+        left_in_span: L,
+        right_in_span: R,
+        in_span: C,
+    ) -> ArrayVec<[usize; N]>
+    where
+        L: Fn(&K) -> bool,
+        R: Fn(&K) -> bool,
+        C: Fn(&K) -> bool,
+    {
+        let mut node_idxs = ArrayVec::<[usize; N]>::new();
+        let mut path = Arena::<K, V, Idx, N>::new_idx_vec();
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        loop {
+            while let Some(curr_idx) = opt_curr_idx {
+                path.push(Idx::checked_from(curr_idx));
+                let node = &self.arena[curr_idx];
+                opt_curr_idx = match left_in_span(node.key()) {
+                    true => node.left_idx(),
+                    false => None,
+                };
+            }
 
-                                return NodeGetHelper::new(None, None, false);
-                            }
-                        },
+            match path.pop() {
+                Some(idx) => {
+                    let node = &self.arena[idx.usize()];
+                    if in_span(node.key()) {
+                        node_idxs.push(idx.usize());
                     }
+                    opt_curr_idx = match right_in_span(node.key()) {
+                        true => node.right_idx(),
+                        false => None,
+                    };
                 }
+                None => break,
             }
-            None => NodeGetHelper::new(None, None, false),
         }
+
+        node_idxs
     }
 
-    // Sorted insert of node into the tree (outer).
-    // Re-balances the tree if necessary.
-    //
-    // Returns the old value, if any, and the index of the new node in the arena.
-    pub(crate) fn internal_balancing_insert<U: Default + Copy + Ord + Sub + SmallUnsigned>(
+    // Post-insert bookkeeping shared by insert paths.
+    // `path` holds the parents of the inserted node, `added` is `false` if an existing node was updated in-place.
+    fn priv_insert_rebalance<U: Default + Copy + Ord + Sub + SmallUnsigned>(
         &mut self,
-        key: K,
-        val: V,
-    ) -> (Option<V>, usize) {
-        let mut path: ArrayVec<[U; N]> = Arena::<K, V, U, N>::new_idx_vec();
-        let (opt_val, ngh) = self.priv_insert(&mut path, key, val);
-
-        #[cfg(feature = "fast_rebalance")]
-        {
-            // Update subtree sizes
-            for parent_idx in &path {
-                let parent_node = &mut self.arena[(*parent_idx).usize()];
-                parent_node.set_subtree_size(parent_node.subtree_size() + 1);
-            }
-        }
+        path: &ArrayVec<[U; N]>,
+        added: bool,
+    ) {
+        self.priv_insert_update_sizes(path, added);
 
         // Potential rebalance
         if path.len() > self.alpha_balance_depth(self.max_size) {
-            if let Some(scapegoat_idx) = self.find_scapegoat(&path) {
-                self.rebuild::<U>(scapegoat_idx);
+            if let Some(scapegoat_idx) = self.find_scapegoat(path) {
+                self.rebuild(scapegoat_idx);
             }
         }
-
-        debug_assert!(ngh.node_idx().is_some());
-        let new_node_idx = ngh.node_idx().expect("Inserted node index must be `Some`");
-        (opt_val, new_node_idx)
     }
 
-    // Private API -----------------------------------------------------------------------------------------------------
+    // Update subtree sizes along `path`, unless an existing node was updated in-place.
+    #[allow(unused_variables)] // Only used when feature `fast_rebalance` is enabled
+    fn priv_insert_update_sizes<U: SmallUnsigned + Default + Copy>(
+        &mut self,
+        path: &ArrayVec<[U; N]>,
+        added: bool,
+    ) {
+        #[cfg(feature = "fast_rebalance")]
+        {
+            if added {
+                for parent_idx in path {
+                    let parent_node = &mut self.arena[(*parent_idx).usize()];
+                    parent_node.set_subtree_size(parent_node.subtree_size() + 1);
+                }
+            }
+        }
+    }
 
     // Sorted insert of node into the tree (inner).
     // Maintains a traversal path to avoid nodes needing to maintain a parent index.
@@ -743,8 +2046,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
                             curr_node.set_key(key);
 
                             // Replacing val necessary b/c it may be different
-                            opt_val = Some(curr_node.take_val());
-                            curr_node.set_val(val);
+                            opt_val = Some(curr_node.replace_val(val));
 
                             // Key/val updated "in-place": no need to update `curr_node`'s parent or children
                             ngh = NodeGetHelper::new(Some(curr_idx), None, false);
@@ -815,6 +2117,82 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    // Append a key greater than the current maximum, linking it as the max node's right child.
+    // Does not rebalance: caller must call `priv_append_max_finish` once done appending.
+    fn priv_append_max(&mut self, key: K, val: V) {
+        debug_assert!(self.last_key().filter(|max_key| &key <= *max_key).is_none());
+
+        let new_node_idx = self.arena.add(key, val);
+        match self.opt_root_idx {
+            Some(_) => self.arena[self.max_idx].set_right_idx(Some(new_node_idx)),
+            None => {
+                self.opt_root_idx = Some(new_node_idx);
+                self.min_idx = new_node_idx;
+            }
+        }
+
+        self.max_idx = new_node_idx;
+        self.curr_size += 1;
+        self.max_size += 1;
+    }
+
+    // If every key in `other` is greater than every key in `self` and both fit, move `other`'s nodes to `self`'s
+    // right spine in order (no searches) and rebalance at most once. Returns `false`, leaving both untouched, otherwise.
+    fn priv_splice_greater(&mut self, other: &mut SgTree<K, V, N>) -> bool {
+        if (other.first_key() <= self.last_key()) || !self.can_insert(other.len()) {
+            return false;
+        }
+
+        let append_cnt = other.len();
+        if let Some(other_root_idx) = other.opt_root_idx {
+            for idx in other.flatten_subtree_to_sorted_idxs::<Idx>(other_root_idx) {
+                let (key, val) = other.arena.hard_remove(idx.usize()).into_kv();
+                self.priv_append_max(key, val);
+            }
+        }
+
+        other.clear();
+        self.priv_append_max_finish(append_cnt);
+        true
+    }
+
+    // Restore balance after `append_cnt` calls to `priv_append_max`, rebuilding at most once.
+    #[allow(unused_variables)] // `append_cnt` only used when feature `fast_rebalance` is enabled
+    fn priv_append_max_finish(&mut self, append_cnt: usize) {
+        if append_cnt == 0 {
+            return;
+        }
+
+        // Right spine, root to max
+        let mut spine = Arena::<K, V, Idx, N>::new_idx_vec();
+        let mut opt_curr_idx = self.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            spine.push(Idx::checked_from(curr_idx));
+            opt_curr_idx = self.arena[curr_idx].right_idx();
+        }
+
+        // Same depth bound as a single insert
+        if (spine.len() - 1) > self.alpha_balance_depth(self.max_size) {
+            if let Some(root_idx) = self.opt_root_idx {
+                self.rebuild(root_idx);
+            }
+        } else {
+            // No rebuild, update subtree sizes: appended nodes form a left-child-less chain at the end of the spine
+            #[cfg(feature = "fast_rebalance")]
+            {
+                let chain_start = spine.len() - append_cnt;
+                for (i, idx) in spine.iter().enumerate() {
+                    let node = &mut self.arena[idx.usize()];
+                    if i < chain_start {
+                        node.set_subtree_size(node.subtree_size() + append_cnt);
+                    } else {
+                        node.set_subtree_size(spine.len() - i);
+                    }
+                }
+            }
+        }
+    }
+
     // Remove a node by key.
     #[cfg(not(feature = "fast_rebalance"))]
     fn priv_remove_by_key<Q>(&mut self, key: &Q) -> Option<(K, V)>
@@ -876,6 +2254,14 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
                             match min_node.left_idx() {
                                 // Continue search for min node
                                 Some(lt_idx) => {
+                                    // Min node will be unlinked from this subtree
+                                    #[cfg(feature = "fast_rebalance")]
+                                    {
+                                        let min_ancestor_node = &mut self.arena[min_idx];
+                                        min_ancestor_node
+                                            .set_subtree_size(min_ancestor_node.subtree_size() - 1);
+                                    }
+
                                     min_parent_idx = min_idx;
                                     min_idx = lt_idx;
                                 }
@@ -888,13 +2274,6 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
                                         } else {
                                             let min_parent_node = &mut self.arena[min_parent_idx];
                                             min_parent_node.set_left_idx(unlink_new_child);
-
-                                            #[cfg(feature = "fast_rebalance")]
-                                            {
-                                                min_parent_node.set_subtree_size(
-                                                    min_parent_node.subtree_size() - 1,
-                                                );
-                                            }
                                         }
                                         break;
                                     }
@@ -904,13 +2283,6 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
                                         } else {
                                             let min_parent_node = &mut self.arena[min_parent_idx];
                                             min_parent_node.set_left_idx(None);
-
-                                            #[cfg(feature = "fast_rebalance")]
-                                            {
-                                                min_parent_node.set_subtree_size(
-                                                    min_parent_node.subtree_size() - 1,
-                                                );
-                                            }
                                         }
                                         break;
                                     }
@@ -949,7 +2321,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
                 }
 
                 // Perform removal
-                let mut removed_node = self.arena.hard_remove(node_idx);
+                let removed_node = self.arena.hard_remove(node_idx);
                 self.curr_size -= 1;
 
                 // Update min/max
@@ -972,14 +2344,15 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
                     }
                 }
 
-                Some((removed_node.take_key(), removed_node.take_val()))
+                Some(removed_node.into_kv())
             }
             None => None,
         }
     }
 
     /// Temporary internal drain_filter() implementation. To be replaced/supplemented with a public implementation.
-    fn priv_drain_filter<Q, F>(&mut self, mut pred: F) -> Self
+    /// Visits entries in ascending key order, or descending if `rev` is set.
+    fn priv_drain_filter<Q, F>(&mut self, mut pred: F, rev: bool) -> Self
     where
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
@@ -996,27 +2369,32 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
 
         // TODO: this implementation is rather inefficient!
 
-        let mut key_idxs = Arena::<K, V, Idx, N>::new_idx_vec();
         let mut remove_idxs = Arena::<K, V, Idx, N>::new_idx_vec();
 
-        // Below iter_mut() will want to sort, require want consistent indexes, so do work up front
-        self.sort_arena();
+        // Visit nodes in key order by arena index, so the arena isn't physically reordered
+        if let Some(root_idx) = self.opt_root_idx {
+            let sorted_idxs = self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx);
+
+            // Filter arena index list to those matching predicate
+            let mut check = |idx: &Idx| {
+                let (k, v) = self.arena[idx.usize()].get_mut();
+                if pred(k.borrow(), v) {
+                    remove_idxs.push(*idx);
+                }
+            };
 
-        // Safely treat mutable ref as immutable, init list of node's arena indexes
-        for (k, _) in &(*self) {
-            let ngh: NodeGetHelper<Idx> = self.internal_get(None, k.borrow());
-            debug_assert!(ngh.node_idx().is_some());
-            key_idxs.push(Idx::checked_from(ngh.node_idx().unwrap()));
+            match rev {
+                true => sorted_idxs.iter().rev().for_each(&mut check),
+                false => sorted_idxs.iter().for_each(&mut check),
+            }
         }
 
-        // Filter arena index list to those not matching predicate
-        for (i, (k, v)) in self.iter_mut().enumerate() {
-            if pred(k.borrow(), v) {
-                remove_idxs.push(key_idxs[i]);
-            }
+        // Nothing to drain, leave the tree untouched
+        if remove_idxs.is_empty() {
+            return Self::new();
         }
 
-        // Drain non-matches
+        // Drain matches
         let mut drained_sgt = Self::new();
         for i in remove_idxs {
             if let Some((k, v)) = self.priv_remove_by_idx(i.usize()) {
@@ -1026,6 +2404,8 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             }
         }
 
+        self.priv_sparse_rebuild();
+
         drained_sgt
     }
 
@@ -1211,87 +2591,153 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         self.get_subtree_size::<U>(parent_idx)
     }
 
-    // Iterative in-place rebuild for balanced subtree
-    fn rebuild<U: Copy + Ord + Sub + SmallUnsigned + Default>(&mut self, idx: usize) {
-        let sorted_sub = self.flatten_subtree_to_sorted_idxs(idx);
-        self.rebalance_subtree_from_sorted_idxs::<U>(idx, &sorted_sub);
-        self.rebal_cnt = self.rebal_cnt.wrapping_add(1);
-    }
-
-    // Height re-balance of subtree (e.g. depth of the two subtrees of every node never differs by more than one).
-    // Adapted from public interview question: https://afteracademy.com/blog/sorted-array-to-balanced-bst
-    fn rebalance_subtree_from_sorted_idxs<U: Copy + Ord + Default + Sub + SmallUnsigned>(
-        &mut self,
-        old_subtree_root_idx: usize,
-        sorted_arena_idxs: &[usize],
+    // Flatten the subtree rooted at `idx` into `flattened`, sorted by key.
+    // Associated function over `arena` so callers can pass buffers that are themselves tree fields.
+    fn priv_flatten_subtree_into<U: SmallUnsigned + Default + Copy>(
+        arena: &Arena<K, V, Idx, N>,
+        idx: usize,
+        worklist: &mut ArrayVec<[U; N]>,
+        flattened: &mut ArrayVec<[U; N]>,
     ) {
-        if sorted_arena_idxs.len() <= 1 {
-            return;
-        }
+        worklist.clear();
+        flattened.clear();
 
-        debug_assert!(
-            self.opt_root_idx.is_some(),
-            "Internal invariant failed: rebalance of multi-node tree without root!"
-        );
+        worklist.push(U::checked_from(idx));
+        flattened.push(U::checked_from(idx));
 
-        let sorted_last_idx = sorted_arena_idxs.len() - 1;
-        let subtree_root_sorted_idx = sorted_last_idx / 2;
-        let subtree_root_arena_idx = sorted_arena_idxs[subtree_root_sorted_idx];
-        let mut subtree_worklist = ArrayVec::<[(U, NodeRebuildHelper<U>); N]>::default();
+        while let Some(idx) = worklist.pop() {
+            let node = &arena[idx.usize()];
 
-        // Init worklist with middle node (balanced subtree root)
-        subtree_worklist.push((
-            U::checked_from(subtree_root_sorted_idx),
-            NodeRebuildHelper::new(0, sorted_last_idx),
-        ));
+            if let Some(left_idx) = node.left_idx() {
+                let left = U::checked_from(left_idx);
+                worklist.push(left);
+                flattened.push(left);
+            }
 
-        // Update tree root or subtree parent
-        if let Some(root_idx) = self.opt_root_idx {
-            if sorted_arena_idxs.contains(&root_idx) {
-                self.opt_root_idx = Some(subtree_root_arena_idx);
-            } else {
-                let old_subtree_root = &self.arena[old_subtree_root_idx];
-                let ngh: NodeGetHelper<U> = self.internal_get(None, old_subtree_root.key());
+            if let Some(right_idx) = node.right_idx() {
+                let right = U::checked_from(right_idx);
+                worklist.push(right);
+                flattened.push(right);
+            }
+        }
+
+        // Sort by key
+        // Faster than sort_by() but may not preserve order of equal elements - OK b/c tree won't have equal nodes
+        flattened.sort_unstable_by(|a, b| arena[a.usize()].key().cmp(arena[b.usize()].key()));
+    }
+
+    // Iterative in-place rebuild for balanced subtree.
+    // With feature `fast_rebalance`, working buffers are the tree's own scratch space and reused across rebuilds.
+    fn rebuild(&mut self, idx: usize) {
+        // Locate the link to the subtree before rebalance changes it
+        let opt_parent = match self.opt_root_idx == Some(idx) {
+            true => None,
+            false => {
+                let ngh: NodeGetHelper<Idx> = self.internal_get(None, self.arena[idx].key());
                 debug_assert!(
                     ngh.parent_idx().is_some(),
                     "Internal invariant failed: rebalance of non-root parent-less node!"
                 );
-                if let Some(parent_idx) = ngh.parent_idx() {
-                    let parent_node = &mut self.arena[parent_idx];
-                    if ngh.is_right_child() {
-                        parent_node.set_right_idx(Some(subtree_root_arena_idx));
-                    } else {
-                        parent_node.set_left_idx(Some(subtree_root_arena_idx));
-                    }
+                ngh.parent_idx()
+                    .map(|parent_idx| (parent_idx, ngh.is_right_child()))
+            }
+        };
+
+        #[cfg(feature = "fast_rebalance")]
+        let scratch = &mut self.rebuild_scratch;
+
+        #[cfg(not(feature = "fast_rebalance"))]
+        let scratch = &mut NodeRebuildScratch::<Idx, N>::default();
+
+        Self::priv_flatten_subtree_into(
+            &self.arena,
+            idx,
+            &mut scratch.flatten_worklist,
+            &mut scratch.sorted_idxs,
+        );
+
+        let subtree_size = scratch.sorted_idxs.len();
+        if subtree_size > 1 {
+            let subtree_root_idx = Self::rebalance_subtree_from_sorted_idxs(
+                &mut self.arena,
+                &scratch.sorted_idxs,
+                &mut scratch.rebuild_worklist,
+            );
+
+            // Update tree root or subtree parent
+            match opt_parent {
+                Some((parent_idx, true)) => {
+                    self.arena[parent_idx].set_right_idx(Some(subtree_root_idx))
                 }
+                Some((parent_idx, false)) => {
+                    self.arena[parent_idx].set_left_idx(Some(subtree_root_idx))
+                }
+                None => self.opt_root_idx = Some(subtree_root_idx),
             }
+
+            debug_assert!(
+                self.get_subtree_size::<Idx>(subtree_root_idx) == subtree_size,
+                "Internal invariant failed: rebalance changed node count! {} -> {}",
+                self.get_subtree_size::<Idx>(subtree_root_idx),
+                subtree_size
+            );
+        }
+
+        self.rebal_cnt = self.rebal_cnt.wrapping_add(1);
+
+        #[cfg(feature = "profiling")]
+        if let Some(hook) = self.rebuild_hook {
+            hook(subtree_size);
         }
+    }
+
+    // Height re-balance of subtree (e.g. depth of the two subtrees of every node never differs by more than one).
+    // Adapted from public interview question: https://afteracademy.com/blog/sorted-array-to-balanced-bst
+    //
+    // Re-links the nodes at `sorted_arena_idxs` (at least 2) and returns the arena index of the new subtree root.
+    // Caller is responsible for linking that root to the subtree's parent.
+    fn rebalance_subtree_from_sorted_idxs(
+        arena: &mut Arena<K, V, Idx, N>,
+        sorted_arena_idxs: &[Idx],
+        subtree_worklist: &mut ArrayVec<[NodeRebuildHelper<Idx>; N]>,
+    ) -> usize {
+        debug_assert!(sorted_arena_idxs.len() > 1);
+
+        let sorted_last_idx = sorted_arena_idxs.len() - 1;
+        let subtree_root_sorted_idx = sorted_last_idx / 2;
+        let subtree_root_arena_idx = sorted_arena_idxs[subtree_root_sorted_idx].usize();
+
+        // Init worklist with middle node (balanced subtree root)
+        subtree_worklist.clear();
+        subtree_worklist.push(NodeRebuildHelper::new(0, sorted_last_idx));
 
         // Iteratively re-assign all children
-        while let Some((sorted_idx, parent_nrh)) = subtree_worklist.pop() {
-            let parent_node = &mut self.arena[sorted_arena_idxs[sorted_idx.usize()]];
+        while let Some(parent_nrh) = subtree_worklist.pop() {
+            let parent_node = &mut arena[sorted_arena_idxs[parent_nrh.mid_idx.usize()].usize()];
 
             parent_node.set_left_idx(None);
             parent_node.set_right_idx(None);
 
             // Set left child
             if parent_nrh.low_idx < parent_nrh.mid_idx {
-                let child_nrh: NodeRebuildHelper<U> = NodeRebuildHelper::new(
+                let child_nrh: NodeRebuildHelper<Idx> = NodeRebuildHelper::new(
                     parent_nrh.low_idx.usize(),
                     parent_nrh.mid_idx.usize() - 1,
                 );
-                parent_node.set_left_idx(Some(sorted_arena_idxs[child_nrh.mid_idx.usize()]));
-                subtree_worklist.push((child_nrh.mid_idx, child_nrh));
+                parent_node
+                    .set_left_idx(Some(sorted_arena_idxs[child_nrh.mid_idx.usize()].usize()));
+                subtree_worklist.push(child_nrh);
             }
 
             // Set right child
             if parent_nrh.mid_idx < parent_nrh.high_idx {
-                let child_nrh: NodeRebuildHelper<U> = NodeRebuildHelper::new(
+                let child_nrh: NodeRebuildHelper<Idx> = NodeRebuildHelper::new(
                     parent_nrh.mid_idx.usize() + 1,
                     parent_nrh.high_idx.usize(),
                 );
-                parent_node.set_right_idx(Some(sorted_arena_idxs[child_nrh.mid_idx.usize()]));
-                subtree_worklist.push((child_nrh.mid_idx, child_nrh));
+                parent_node
+                    .set_right_idx(Some(sorted_arena_idxs[child_nrh.mid_idx.usize()].usize()));
+                subtree_worklist.push(child_nrh);
             }
 
             // Set subtree size
@@ -1303,12 +2749,83 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             }
         }
 
-        debug_assert!(
-            self.get_subtree_size::<U>(subtree_root_arena_idx) == (sorted_arena_idxs.len()),
-            "Internal invariant failed: rebalance changed node count! {} -> {}",
-            self.get_subtree_size::<U>(subtree_root_arena_idx),
-            sorted_arena_idxs.len()
-        );
+        subtree_root_arena_idx
+    }
+
+    // Sort staged (arena index, arrival order) pairs by key, then free all but the latest arrival of each key.
+    fn priv_sort_staged(arena: &mut Arena<K, V, Idx, N>, staged: &mut ArrayVec<[(Idx, usize); N]>) {
+        staged.sort_unstable_by(|(a_idx, a_seq), (b_idx, b_seq)| {
+            arena[a_idx.usize()]
+                .key()
+                .cmp(arena[b_idx.usize()].key())
+                .then(a_seq.cmp(b_seq))
+        });
+
+        let mut kept_cnt = 0;
+        for i in 0..staged.len() {
+            let is_latest = match staged.get(i + 1) {
+                Some((next_idx, _)) => {
+                    arena[staged[i].0.usize()].key() != arena[next_idx.usize()].key()
+                }
+                None => true,
+            };
+
+            match is_latest {
+                true => {
+                    staged[kept_cnt] = staged[i];
+                    kept_cnt += 1;
+                }
+                false => {
+                    arena.hard_remove(staged[i].0.usize());
+                }
+            }
+        }
+
+        staged.truncate(kept_cnt);
+    }
+
+    // Re-link every node into a balanced tree, as a single rebuild of the whole tree.
+    // `sorted_idxs` must hold the arena index of every node in the tree, sorted by key.
+    fn priv_rebuild_from_sorted_idxs(&mut self, sorted_idxs: &[Idx]) {
+        debug_assert_eq!(sorted_idxs.len(), self.len());
+
+        let (first_idx, last_idx) = match (sorted_idxs.first(), sorted_idxs.last()) {
+            (Some(first_idx), Some(last_idx)) => (first_idx.usize(), last_idx.usize()),
+            _ => return,
+        };
+
+        let root_idx = match sorted_idxs.len() {
+            1 => {
+                let node = &mut self.arena[first_idx];
+                node.set_left_idx(None);
+                node.set_right_idx(None);
+
+                #[cfg(feature = "fast_rebalance")]
+                node.set_subtree_size(1);
+
+                first_idx
+            }
+            _ => {
+                #[cfg(feature = "fast_rebalance")]
+                let worklist = &mut self.rebuild_scratch.rebuild_worklist;
+
+                #[cfg(not(feature = "fast_rebalance"))]
+                let worklist = &mut ArrayVec::<[NodeRebuildHelper<Idx>; N]>::new();
+
+                Self::rebalance_subtree_from_sorted_idxs(&mut self.arena, sorted_idxs, worklist)
+            }
+        };
+
+        self.opt_root_idx = Some(root_idx);
+        self.min_idx = first_idx;
+        self.max_idx = last_idx;
+        self.max_size = self.curr_size;
+        self.rebal_cnt = self.rebal_cnt.wrapping_add(1);
+
+        #[cfg(feature = "profiling")]
+        if let Some(hook) = self.rebuild_hook {
+            hook(sorted_idxs.len());
+        }
     }
 
     // Alpha weight balance computation helper.
@@ -1323,8 +2840,8 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
 // Debug
 impl<K, V, const N: usize> Debug for SgTree<K, V, N>
 where
-    K: Ord + Debug + Default,
-    V: Debug + Default,
+    K: Ord + Debug,
+    V: Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_map().entries(self.iter()).finish()
@@ -1334,8 +2851,7 @@ where
 // Default
 impl<K, V, const N: usize> Default for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     fn default() -> Self {
         Self::new()
@@ -1345,8 +2861,7 @@ where
 // From array
 impl<K, V, const N: usize> From<[(K, V); N]> for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     fn from(arr: [(K, V); N]) -> Self {
         IntoIterator::into_iter(arr).collect()
@@ -1368,8 +2883,7 @@ See issue from 2018: https://github.com/rust-lang/rust/issues/50133#issuecomment
 // TryFrom array
 impl<K, V, const N: usize> TryFrom<[(K, V); N]> for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     type Error = SgError;
 
@@ -1385,9 +2899,8 @@ where
 // Indexing
 impl<K, V, Q, const N: usize> Index<&Q> for SgTree<K, V, N>
 where
-    K: Borrow<Q> + Ord + Default,
+    K: Borrow<Q> + Ord,
     Q: Ord + ?Sized,
-    V: Default,
 {
     type Output = V;
 
@@ -1404,22 +2917,52 @@ where
 // Extension from iterator.
 impl<K, V, const N: usize> Extend<(K, V)> for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
-        iter.into_iter().for_each(move |(k, v)| {
+        let iter = iter.into_iter();
+
+        // Batching only if the whole iterator is guaranteed to fit
+        let mut batching = match iter.size_hint() {
+            (_, Some(upper)) => upper <= (self.capacity() - self.len()),
+            (_, None) => false,
+        };
+        let mut batch_cnt = 0;
+
+        for (k, v) in iter {
+            let beyond_max = match self.last_key() {
+                Some(max_key) => &k > max_key,
+                None => true,
+            };
+
+            // Batched path: keys beyond current max are linked in without per-element rebalancing
+            if batching && beyond_max {
+                self.priv_append_max(k, v);
+                batch_cnt += 1;
+                continue;
+            }
+
+            // Fallback path: per-element insert, balance restored first
+            if batching {
+                self.priv_append_max_finish(batch_cnt);
+                batching = false;
+            }
+
             self.try_insert(k, v)
                 .expect("Stack-storage capacity exceeded!");
-        });
+        }
+
+        if batching {
+            self.priv_append_max_finish(batch_cnt);
+        }
     }
 }
 
 // Extension from reference iterator.
 impl<'a, K, V, const N: usize> Extend<(&'a K, &'a V)> for SgTree<K, V, N>
 where
-    K: Ord + Copy + Default,
-    V: Copy + Default,
+    K: Ord + Copy,
+    V: Copy,
 {
     fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
         self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
@@ -1429,8 +2972,8 @@ where
 // PartialEq
 impl<K, V, const N: usize> PartialEq for SgTree<K, V, N>
 where
-    K: Ord + PartialEq + Default,
-    V: PartialEq + Default,
+    K: Ord + PartialEq,
+    V: PartialEq,
 {
     fn eq(&self, other: &SgTree<K, V, N>) -> bool {
         self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
@@ -1440,16 +2983,16 @@ where
 // Eq
 impl<K, V, const N: usize> Eq for SgTree<K, V, N>
 where
-    K: Ord + Eq + Default,
-    V: Eq + Default,
+    K: Ord + Eq,
+    V: Eq,
 {
 }
 
 // PartialOrd
 impl<K, V, const N: usize> PartialOrd for SgTree<K, V, N>
 where
-    K: Ord + PartialOrd + Default,
-    V: PartialOrd + Default,
+    K: Ord + PartialOrd,
+    V: PartialOrd,
 {
     fn partial_cmp(&self, other: &SgTree<K, V, N>) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
@@ -1459,8 +3002,8 @@ where
 // Ord
 impl<K, V, const N: usize> Ord for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Ord + Default,
+    K: Ord,
+    V: Ord,
 {
     fn cmp(&self, other: &SgTree<K, V, N>) -> Ordering {
         self.iter().cmp(other.iter())
@@ -1470,10 +3013,11 @@ where
 // Hash
 impl<K, V, const N: usize> Hash for SgTree<K, V, N>
 where
-    K: Ord + Hash + Default,
-    V: Hash + Default,
+    K: Ord + Hash,
+    V: Hash,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
         for i in self {
             i.hash(state);
         }
@@ -1485,8 +3029,7 @@ where
 // Construct from iterator.
 impl<K, V, const N: usize> FromIterator<(K, V)> for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
         let mut sgt = SgTree::new();
@@ -1503,8 +3046,7 @@ where
 // Reference iterator, mutable
 impl<'a, K, V, const N: usize> IntoIterator for &'a mut SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     type Item = (&'a K, &'a mut V);
     type IntoIter = IterMut<'a, K, V, N>;
@@ -1517,8 +3059,7 @@ where
 // Reference iterator, immutable
 impl<'a, K, V, const N: usize> IntoIterator for &'a SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     type Item = (&'a K, &'a V);
     type IntoIter = Iter<'a, K, V, N>;
@@ -1531,8 +3072,7 @@ where
 // Consuming iterator
 impl<K, V, const N: usize> IntoIterator for SgTree<K, V, N>
 where
-    K: Ord + Default,
-    V: Default,
+    K: Ord,
 {
     type Item = (K, V);
     type IntoIter = IntoIter<K, V, N>;
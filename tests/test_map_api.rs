@@ -1,8 +1,14 @@
-use std::collections::BTreeMap;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::ops::Bound::{Excluded, Included};
+use std::ops::ControlFlow;
+use std::panic::{self, AssertUnwindSafe};
 
-use scapegoat::{sgmap, SgError, SgMap};
+use scapegoat::map_types::Entry;
+use scapegoat::{sgmap, SgError, SgMap, SgSet};
 
 use rand::Rng;
 
@@ -236,6 +242,21 @@ fn test_map_append() {
     );
 }
 
+#[test]
+fn test_map_append_iter() {
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, "1"), (5, "5")]);
+    let mut btm = BTreeMap::from_iter([(1, "1"), (5, "5")]);
+    let updates = [(3, "3"), (1, "1B"), (6, "6"), (3, "3B"), (2, "2")];
+
+    sgm.append_iter(updates);
+    btm.extend(updates);
+
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.len(), 5);
+    assert_eq!(sgm[&1], "1B");
+    assert_eq!(sgm[&3], "3B");
+}
+
 // Fallible APIs -------------------------------------------------------------------------------------------------------
 
 #[test]
@@ -250,6 +271,33 @@ fn test_map_insert_fallible() {
     assert_eq!(a.try_insert(4, "4"), Err(SgError::StackCapacityExceeded));
 }
 
+#[test]
+fn test_map_insert_fallible_matches_insert() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for _ in 0..(DEFAULT_CAPACITY * 20) {
+        let k = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+        let v = rng.gen();
+
+        if bt_map.contains_key(&k) || (bt_map.len() < DEFAULT_CAPACITY) {
+            // Overwrite and insert both match the infallible API
+            assert_eq!(sg_map.try_insert(k, v), Ok(bt_map.insert(k, v)));
+        } else {
+            // Overflow is reported, the map is left untouched
+            assert_eq!(sg_map.try_insert(k, v), Err(SgError::StackCapacityExceeded));
+        }
+
+        assert!(sg_map.iter().eq(bt_map.iter()));
+
+        if rng.gen_range(0, 4) == 0 {
+            let k = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+            assert_eq!(sg_map.remove(&k), bt_map.remove(&k));
+        }
+    }
+}
+
 #[test]
 fn test_map_append_fallible() {
     let mut a = SgMap::<_, _, 6>::new();
@@ -295,6 +343,23 @@ fn test_map_append_fallible() {
     );
 }
 
+#[test]
+fn test_map_append_iter_fallible() {
+    let mut a = SgMap::<_, _, 4>::from_iter([(1, "1"), (2, "2")]);
+
+    assert_eq!(a.try_append_iter([(2, "2B"), (3, "3"), (3, "3B")]), Ok(()));
+    assert_eq!(
+        a.try_append_iter([(4, "4"), (1, "1B"), (5, "5"), (2, "2C")]),
+        Err(SgError::StackCapacityExceeded)
+    );
+
+    // Pairs before the failure merged, rest of iterator not consumed
+    assert_eq!(
+        a.into_iter().collect::<Vec<(usize, &str)>>(),
+        vec![(1, "1B"), (2, "2B"), (3, "3B"), (4, "4")]
+    );
+}
+
 #[should_panic]
 #[test]
 fn test_map_insert_panic() {
@@ -343,6 +408,1952 @@ fn test_map_range_mut() {
     assert_eq!(map["e"], 10);
 }
 
+#[test]
+fn test_map_remove_range() {
+    const CAPACITY: usize = 500;
+    let mut sgm = SgMap::<u16, u16, CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..CAPACITY {
+        let k = rng.gen_range(0, 1_000);
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+
+    for _ in 0..20 {
+        let start = rng.gen_range(0, 1_000);
+        let end = rng.gen_range(start, 1_000);
+
+        let old_len = btm.len();
+        btm.retain(|k, _| !(start..end).contains(k));
+        assert_eq!(sgm.remove_range(start..end), old_len - btm.len());
+        assert!(sgm.iter().eq(btm.iter()));
+
+        let old_len = btm.len();
+        btm.retain(|k, _| !((start + 1)..=end).contains(k));
+        assert_eq!(
+            sgm.remove_range((Excluded(start), Included(end))),
+            old_len - btm.len()
+        );
+        assert!(sgm.iter().eq(btm.iter()));
+    }
+
+    assert_eq!(sgm.remove_range(..), btm.len());
+    assert!(sgm.is_empty());
+    assert_eq!(sgm.remove_range(..), 0);
+}
+
+#[test]
+fn test_map_from_iter_capped() {
+    let mut rng = rand::thread_rng();
+    let input: Vec<(usize, usize)> = (0..(DEFAULT_CAPACITY * 3))
+        .map(|_| (rng.gen_range(0, DEFAULT_CAPACITY * 2), rng.gen()))
+        .collect();
+
+    let (sgm, dropped) = SgMap::<usize, usize, DEFAULT_CAPACITY>::from_iter_capped(input.clone());
+
+    // Reference: fill a `BTreeMap` to capacity, updates to present keys are always kept
+    let mut btm = BTreeMap::new();
+    let mut btm_dropped = 0;
+    for (k, v) in input {
+        if btm.len() < DEFAULT_CAPACITY || btm.contains_key(&k) {
+            btm.insert(k, v);
+        } else {
+            btm_dropped += 1;
+        }
+    }
+
+    assert_eq!(dropped, btm_dropped);
+    assert!(sgm.iter().eq(btm.iter()));
+
+    let (sgm, dropped) = SgMap::<usize, usize, DEFAULT_CAPACITY>::from_iter_capped(vec![]);
+    assert!(sgm.is_empty());
+    assert_eq!(dropped, 0);
+}
+
+#[test]
+fn test_map_retain_mut() {
+    const CAPACITY: usize = 500;
+    let mut sgm = SgMap::<u16, u16, CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..CAPACITY {
+        let k = rng.gen_range(0, 1_000);
+        let v = rng.gen_range(0, 1_000);
+        sgm.insert(k, v);
+        btm.insert(k, v);
+    }
+
+    let pred = |k: &u16, v: &mut u16| {
+        *v = (*v ^ *k) / 2;
+        *v > 100
+    };
+
+    sgm.retain_mut(pred);
+    btm.retain(pred);
+    assert!(sgm.iter().eq(btm.iter()));
+
+    // Mutations to kept entries survive subsequent structural changes
+    let keys: Vec<u16> = btm.keys().step_by(2).copied().collect();
+    for k in keys {
+        assert_eq!(sgm.remove(&k), btm.remove(&k));
+    }
+    assert!(sgm.iter().eq(btm.iter()));
+}
+
+#[test]
+fn test_map_retain_mutations_persist() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    for i in 0..DEFAULT_CAPACITY {
+        sgm.insert(i, i);
+        btm.insert(i, i);
+    }
+
+    // Sparse arena, so kept entries aren't at their sorted positions
+    for i in (0..DEFAULT_CAPACITY).step_by(3) {
+        sgm.remove(&i);
+        btm.remove(&i);
+    }
+
+    // Keep everything
+    sgm.retain(|_, v| {
+        *v += 1;
+        true
+    });
+    btm.retain(|_, v| {
+        *v += 1;
+        true
+    });
+    assert!(sgm.iter().eq(btm.iter()));
+
+    // Keep some
+    let pred = |k: &usize, v: &mut usize| {
+        *v *= 10;
+        k % 2 == 0
+    };
+    sgm.retain(pred);
+    btm.retain(pred);
+    assert!(sgm.iter().eq(btm.iter()));
+}
+
+#[test]
+fn test_map_value_scan() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..DEFAULT_CAPACITY {
+        let k = rng.gen_range(0, 100);
+        let v = rng.gen_range(0, 10);
+        sgm.insert(k, v);
+        btm.insert(k, v);
+    }
+
+    for target in 0..10 {
+        assert_eq!(
+            sgm.contains_value(&target),
+            btm.values().any(|v| *v == target)
+        );
+        assert_eq!(
+            sgm.find_value(|v| *v >= target),
+            btm.iter().find(|(_, v)| **v >= target)
+        );
+    }
+}
+
+#[test]
+fn test_map_insert_multi() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..10 {
+        let mut batch = [(0, 0); 4];
+        for (k, v) in batch.iter_mut() {
+            *k = rng.gen_range(0, DEFAULT_CAPACITY);
+            *v = rng.gen();
+        }
+
+        let expected: Vec<Option<usize>> = batch.iter().map(|(k, v)| btm.insert(*k, *v)).collect();
+        assert_eq!(sgm.insert_multi(batch).to_vec(), expected);
+        assert!(sgm.iter().eq(btm.iter()));
+    }
+}
+
+#[test]
+fn test_map_insert_multi_fallible() {
+    let mut sgm = SgMap::<usize, usize, 4>::new();
+    assert_eq!(
+        sgm.try_insert_multi([(1, 1), (2, 2), (1, 3)]),
+        Ok([None, None, Some(1)])
+    );
+
+    // Updates to present keys never fail, new keys fail once full
+    assert_eq!(
+        sgm.try_insert_multi([(3, 3), (2, 4), (4, 4), (5, 5), (6, 6)]),
+        Err((3, SgError::StackCapacityExceeded))
+    );
+    assert!(sgm.into_iter().eq(vec![(1, 3), (2, 4), (3, 3), (4, 4)]));
+}
+
+#[test]
+fn test_map_range_values_mut() {
+    const CAPACITY: usize = 500;
+    let mut sgm = SgMap::<u16, u16, CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..CAPACITY {
+        let k = rng.gen_range(0, 1_000);
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+
+    for _ in 0..20 {
+        let start = rng.gen_range(0, 1_000);
+        let end = rng.gen_range(start, 1_000);
+
+        let sg_band = sgm.range_values_mut(start..end);
+        assert_eq!(sg_band.len(), btm.range(start..end).count());
+        sg_band.for_each(|v| *v = v.wrapping_add(1));
+        btm.range_mut(start..end)
+            .for_each(|(_, v)| *v = v.wrapping_add(1));
+
+        assert!(sgm.iter().eq(btm.iter()));
+    }
+
+    assert!(sgm
+        .range_values_mut(..)
+        .rev()
+        .map(|v| *v)
+        .eq(btm.values().rev().copied()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_map_eq_btree_map() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    assert_eq!(sgm, btm);
+
+    for k in 0..DEFAULT_CAPACITY {
+        sgm.insert(k, k);
+        btm.insert(k, k);
+        assert_eq!(sgm, btm);
+        assert_eq!(btm, sgm);
+    }
+
+    btm.insert(0, 1);
+    assert_ne!(sgm, btm);
+    assert_ne!(btm, sgm);
+
+    btm.insert(0, 0);
+    btm.insert(DEFAULT_CAPACITY, 0);
+    assert_ne!(sgm, btm);
+}
+
+// Neither the key nor the value type implement `Default`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoDefault {
+    On(u8),
+    Off,
+}
+
+#[test]
+fn test_map_non_default_types() {
+    use std::num::NonZeroU32;
+
+    let nz = |n: u32| NonZeroU32::new(n).unwrap();
+    let mut sgm = SgMap::<NonZeroU32, NoDefault, DEFAULT_CAPACITY>::default();
+    let mut btm = BTreeMap::new();
+
+    for n in (1..=DEFAULT_CAPACITY as u32).rev() {
+        let val = match n % 2 {
+            0 => NoDefault::Off,
+            _ => NoDefault::On(n as u8),
+        };
+        assert_eq!(sgm.insert(nz(n), val), btm.insert(nz(n), val));
+    }
+    assert!(sgm.iter().eq(btm.iter()));
+
+    assert_eq!(
+        sgm.insert(nz(1), NoDefault::Off),
+        btm.insert(nz(1), NoDefault::Off)
+    );
+    assert_eq!(sgm.remove(&nz(2)), btm.remove(&nz(2)));
+    assert_eq!(sgm.pop_first(), btm.pop_first());
+    assert_eq!(sgm.pop_last(), btm.pop_last());
+
+    sgm.retain(|_, v| *v != NoDefault::Off);
+    btm.retain(|_, v| *v != NoDefault::Off);
+    assert!(sgm.iter().eq(btm.iter()));
+
+    if let Entry::Occupied(o) = sgm.entry(nz(3)) {
+        assert_eq!(o.remove(), NoDefault::On(3));
+    }
+    btm.remove(&nz(3));
+
+    let mut other = SgMap::<NonZeroU32, NoDefault, DEFAULT_CAPACITY>::new();
+    other.insert(nz(100), NoDefault::Off);
+    sgm.append(&mut other);
+    btm.insert(nz(100), NoDefault::Off);
+
+    assert!(sgm.drain().eq(btm.into_iter()));
+    assert!(sgm.is_empty());
+}
+
+#[test]
+fn test_map_values_mut_double_ended() {
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for i in 0..DEFAULT_CAPACITY {
+        sg_map.insert(i, i);
+        bt_map.insert(i, i);
+    }
+
+    // Leave free slots in the arena
+    for i in [1, 4, 7].iter() {
+        sg_map.remove(i);
+        bt_map.remove(i);
+    }
+
+    // Reverse order, largest key first
+    let mut prev = None;
+    for (i, v) in sg_map.values_mut().rev().enumerate() {
+        if let Some(p) = prev {
+            assert!(*v < p);
+        }
+        prev = Some(*v);
+        *v += i * 1000;
+    }
+    for (i, v) in bt_map.values_mut().rev().enumerate() {
+        *v += i * 1000;
+    }
+    assert!(sg_map.iter().eq(bt_map.iter()));
+
+    // Alternate ends, each value must be visited exactly once
+    let mut sg_values_mut = sg_map.values_mut();
+    assert_eq!(sg_values_mut.len(), bt_map.len());
+
+    let mut visit_cnt = 0;
+    loop {
+        let next = match visit_cnt & 1 {
+            0 => sg_values_mut.next(),
+            _ => sg_values_mut.next_back(),
+        };
+        match next {
+            Some(v) => {
+                *v += 1;
+                visit_cnt += 1;
+            }
+            None => break,
+        }
+    }
+
+    assert_eq!(visit_cnt, bt_map.len());
+    bt_map.values_mut().for_each(|v| *v += 1);
+    assert!(sg_map.iter().eq(bt_map.iter()));
+}
+
+#[test]
+fn test_map_remove_keys() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for i in 0..DEFAULT_CAPACITY {
+        sg_map.insert(i, i);
+        bt_map.insert(i, i);
+    }
+
+    // Unsorted, with duplicates and absent keys
+    let keys: Vec<usize> = (0..DEFAULT_CAPACITY)
+        .map(|_| rng.gen_range(0, 2 * DEFAULT_CAPACITY))
+        .collect();
+
+    let bt_cnt = keys.iter().filter(|k| bt_map.remove(k).is_some()).count();
+    assert_eq!(sg_map.remove_keys(keys.clone()), bt_cnt);
+    assert!(sg_map.iter().eq(bt_map.iter()));
+
+    // Already removed
+    assert_eq!(sg_map.remove_keys(keys), 0);
+    assert_eq!(sg_map.len(), bt_map.len());
+}
+
+#[test]
+fn test_map_to_array_vec() {
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for i in (0..DEFAULT_CAPACITY).rev() {
+        sg_map.insert(i, i * 2);
+        bt_map.insert(i, i * 2);
+    }
+
+    // Exact fit
+    let pairs = sg_map.to_array_vec::<DEFAULT_CAPACITY>().unwrap();
+    assert!(pairs.iter().map(|(k, v)| (k, v)).eq(bt_map.iter()));
+
+    let keys = sg_map.keys_to_array_vec::<DEFAULT_CAPACITY>().unwrap();
+    assert!(keys.iter().eq(bt_map.keys()));
+
+    let values = sg_map.values_to_array_vec::<DEFAULT_CAPACITY>().unwrap();
+    assert!(values.iter().eq(bt_map.values()));
+
+    // Too small
+    assert_eq!(
+        sg_map
+            .to_array_vec::<{ DEFAULT_CAPACITY - 1 }>()
+            .unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+    assert_eq!(
+        sg_map
+            .keys_to_array_vec::<{ DEFAULT_CAPACITY - 1 }>()
+            .unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+    assert_eq!(
+        sg_map
+            .values_to_array_vec::<{ DEFAULT_CAPACITY - 1 }>()
+            .unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+
+    // Empty
+    sg_map.clear();
+    assert!(sg_map.to_array_vec::<0>().unwrap().is_empty());
+}
+
+#[test]
+fn test_map_retain_rev() {
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for i in 0..DEFAULT_CAPACITY {
+        sg_map.insert(i, i * 10);
+        bt_map.insert(i, i * 10);
+    }
+
+    // Visited exactly once each, in descending order
+    let mut visited = Vec::new();
+    sg_map.retain_rev(|k, _| {
+        visited.push(*k);
+        true
+    });
+    assert!(visited.iter().eq(bt_map.keys().rev()));
+    assert!(sg_map.iter().eq(bt_map.iter()));
+
+    // Stateful predicate: keep the 3 largest, mutating their values
+    let mut budget = 3;
+    sg_map.retain_rev(|_, v| {
+        *v += 1;
+        match budget {
+            0 => false,
+            _ => {
+                budget -= 1;
+                true
+            }
+        }
+    });
+
+    bt_map.retain(|k, _| *k >= DEFAULT_CAPACITY - 3);
+    bt_map.values_mut().for_each(|v| *v += 1);
+    assert!(sg_map.iter().eq(bt_map.iter()));
+}
+
+#[test]
+fn test_map_vacant_try_insert_with() {
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+
+    // Closure failure leaves the entry vacant
+    match sg_map.entry(1) {
+        Entry::Vacant(v) => {
+            assert_eq!(v.try_insert_with(|| Err("no resource")), Err("no resource"))
+        }
+        Entry::Occupied(_) => panic!("Entry must be vacant!"),
+    }
+    assert!(sg_map.is_empty());
+    assert!(matches!(sg_map.entry(1), Entry::Vacant(_)));
+
+    // Closure success inserts
+    match sg_map.entry(1) {
+        Entry::Vacant(v) => {
+            let val = v.try_insert_with(|| Ok::<_, &str>(10)).unwrap();
+            *val += 1;
+        }
+        Entry::Occupied(_) => panic!("Entry must be vacant!"),
+    }
+    assert_eq!(sg_map.get(&1), Some(&11));
+    assert_eq!(sg_map.len(), 1);
+}
+
+fn hash_of<T: Hash>(val: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    val.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_map_hash_capacity_independent() {
+    let mut rng = rand::thread_rng();
+    let mut keys: Vec<usize> = (0..DEFAULT_CAPACITY).collect();
+
+    // Ascending insertion, small capacity
+    let sg_map_small: SgMap<usize, usize, DEFAULT_CAPACITY> =
+        keys.iter().map(|k| (*k, *k * 2)).collect();
+
+    // Shuffled insertion with removals, large capacity
+    let mut sg_map_big = SgMap::<usize, usize, { DEFAULT_CAPACITY * 10 }>::new();
+    for k in (DEFAULT_CAPACITY..(DEFAULT_CAPACITY * 5)).rev() {
+        sg_map_big.insert(k, k);
+    }
+    for _ in 0..keys.len() {
+        let i = rng.gen_range(0, keys.len());
+        let j = rng.gen_range(0, keys.len());
+        keys.swap(i, j);
+    }
+    for k in &keys {
+        sg_map_big.insert(*k, *k * 2);
+    }
+    sg_map_big.remove_range(DEFAULT_CAPACITY..);
+
+    assert!(sg_map_small.iter().eq(sg_map_big.iter()));
+    assert_eq!(hash_of(&sg_map_small), hash_of(&sg_map_big));
+
+    // Matches std
+    let bt_map: BTreeMap<usize, usize> = sg_map_small.clone().into_iter().collect();
+    assert_eq!(hash_of(&sg_map_small), hash_of(&bt_map));
+
+    // Different contents
+    sg_map_big.insert(0, 1);
+    assert_ne!(hash_of(&sg_map_small), hash_of(&sg_map_big));
+}
+
+#[test]
+fn test_map_for_each_entry() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for i in (0..DEFAULT_CAPACITY).step_by(2) {
+        sg_map.insert(i, i);
+        bt_map.insert(i, i);
+    }
+
+    // Repeated keys, present and absent
+    let keys: Vec<usize> = (0..(2 * DEFAULT_CAPACITY))
+        .map(|_| rng.gen_range(0, DEFAULT_CAPACITY))
+        .collect();
+
+    let mut visit_cnt = 0;
+    sg_map.for_each_entry(keys.iter().copied(), |entry| {
+        visit_cnt += 1;
+        match entry {
+            Entry::Occupied(mut o) => *o.get_mut() += 100,
+            Entry::Vacant(v) => {
+                v.insert(0);
+            }
+        }
+    });
+
+    for k in &keys {
+        match bt_map.entry(*k) {
+            std::collections::btree_map::Entry::Occupied(mut o) => *o.get_mut() += 100,
+            std::collections::btree_map::Entry::Vacant(v) => {
+                v.insert(0);
+            }
+        }
+    }
+
+    assert_eq!(visit_cnt, keys.len());
+    assert!(sg_map.iter().eq(bt_map.iter()));
+}
+
+#[test]
+fn test_map_split_at_key() {
+    let mut rng = rand::thread_rng();
+    let sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> =
+        (0..DEFAULT_CAPACITY).map(|k| (k, k * 2)).collect();
+
+    for split_key in 0..=DEFAULT_CAPACITY {
+        let (lo, hi) = sg_map.clone().split_at_key(&split_key);
+
+        assert_eq!(lo.len() + hi.len(), sg_map.len());
+        assert!(lo.iter().chain(hi.iter()).eq(sg_map.iter()));
+        assert!(lo.keys().all(|k| *k < split_key));
+        assert!(hi.keys().all(|k| *k >= split_key));
+    }
+
+    // Absent split key
+    let mut sparse_map = sg_map.clone();
+    let absent_key = rng.gen_range(0, DEFAULT_CAPACITY);
+    sparse_map.remove(&absent_key);
+    let (lo, hi) = sparse_map.split_at_key(&absent_key);
+    assert_eq!(lo.len(), absent_key);
+    assert_eq!(hi.len(), DEFAULT_CAPACITY - absent_key - 1);
+}
+
+#[test]
+fn test_map_prefix_range() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<Vec<u8>, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    // Short keys over a tiny alphabet, so prefixes collide and 0xFF appears often
+    let alphabet = [0x00, 0x01, 0xFE, 0xFF];
+    while sg_map.len() < DEFAULT_CAPACITY {
+        let len = rng.gen_range(0, 4);
+        let key: Vec<u8> = (0..len)
+            .map(|_| alphabet[rng.gen_range(0, alphabet.len())])
+            .collect();
+        sg_map.insert(key.clone(), len);
+        bt_map.insert(key, len);
+    }
+
+    for _ in 0..100 {
+        let len = rng.gen_range(0, 4);
+        let prefix: Vec<u8> = (0..len)
+            .map(|_| alphabet[rng.gen_range(0, alphabet.len())])
+            .collect();
+
+        let expected: Vec<_> = bt_map
+            .iter()
+            .filter(|(k, _)| k.starts_with(&prefix))
+            .collect();
+        assert!(sg_map.prefix_range(&prefix).eq(expected.iter().copied()));
+        assert!(sg_map
+            .prefix_range(&prefix)
+            .rev()
+            .eq(expected.iter().rev().copied()));
+    }
+
+    // All 0xFF prefix extends to the end
+    let ff_expected: Vec<_> = bt_map.range(vec![0xFF]..).collect();
+    assert!(sg_map.prefix_range(&[0xFF]).eq(ff_expected.iter().copied()));
+}
+
+// Neither `Clone` nor `Copy` nor `Default`, can only be moved
+#[derive(Debug, PartialEq)]
+enum Stage {
+    Raw(String),
+    Parsed(usize),
+}
+
+impl Stage {
+    fn advance(self) -> Self {
+        match self {
+            Stage::Raw(s) => Stage::Parsed(s.len()),
+            Stage::Parsed(n) => Stage::Parsed(n + 1),
+        }
+    }
+}
+
+#[test]
+fn test_map_occupied_replace_with() {
+    let mut sg_map = SgMap::<usize, Stage, DEFAULT_CAPACITY>::new();
+    for k in 0..DEFAULT_CAPACITY {
+        sg_map.insert(k, Stage::Raw("x".repeat(k)));
+    }
+
+    for k in (0..DEFAULT_CAPACITY).step_by(2) {
+        match sg_map.entry(k) {
+            Entry::Occupied(o) => assert_eq!(*o.replace_with(Stage::advance), Stage::Parsed(k)),
+            Entry::Vacant(_) => panic!("Entry must be occupied!"),
+        }
+    }
+
+    if let Some(o) = sg_map.first_entry() {
+        o.replace_with(Stage::advance);
+    }
+
+    // Only targeted values changed, tree untouched
+    assert_eq!(sg_map.len(), DEFAULT_CAPACITY);
+    assert!(sg_map.keys().copied().eq(0..DEFAULT_CAPACITY));
+    for (k, v) in sg_map.iter() {
+        match (k, v) {
+            (0, v) => assert_eq!(*v, Stage::Parsed(1)),
+            (k, Stage::Parsed(n)) => assert!((*k == *n) && (k & 1 == 0)),
+            (k, Stage::Raw(s)) => assert!((*k == s.len()) && (k & 1 == 1)),
+        }
+    }
+}
+
+#[test]
+fn test_map_occupied_replace_with_panic() {
+    let mut sg_map = SgMap::<usize, Stage, DEFAULT_CAPACITY>::new();
+    for k in 0..DEFAULT_CAPACITY {
+        sg_map.insert(k, Stage::Raw("x".repeat(k)));
+    }
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        if let Entry::Occupied(o) = sg_map.entry(DEFAULT_CAPACITY / 2) {
+            o.replace_with(|_| panic!("Transform failed!"));
+        }
+    }));
+    assert!(res.is_err());
+
+    // Panicked entry is gone, the rest of the map is intact
+    assert_eq!(sg_map.verify(), Ok(()));
+    assert_eq!(sg_map.len(), DEFAULT_CAPACITY - 1);
+    assert_eq!(sg_map.get(&(DEFAULT_CAPACITY / 2)), None);
+    assert!(sg_map
+        .keys()
+        .copied()
+        .eq((0..DEFAULT_CAPACITY).filter(|k| *k != DEFAULT_CAPACITY / 2)));
+    for (k, v) in sg_map.iter() {
+        assert_eq!(*v, Stage::Raw("x".repeat(*k)));
+    }
+    assert_eq!(sg_map.first_key_value().map(|(k, _)| *k), Some(0));
+    assert_eq!(
+        sg_map.last_key_value().map(|(k, _)| *k),
+        Some(DEFAULT_CAPACITY - 1)
+    );
+
+    // Still usable
+    assert_eq!(sg_map.insert(DEFAULT_CAPACITY / 2, Stage::Parsed(0)), None);
+    assert_eq!(sg_map.len(), DEFAULT_CAPACITY);
+}
+
+#[test]
+fn test_map_capacity_zero() {
+    let mut sg_map = SgMap::<usize, usize, 0>::new();
+    assert_eq!(sg_map, SgMap::default());
+    assert_eq!(sg_map.capacity(), 0);
+    assert!(sg_map.is_empty());
+    assert!(sg_map.is_full());
+
+    // Every insert errors, distinctly
+    assert_eq!(sg_map.try_insert(1, 1), Err(SgError::CapacityZero));
+    assert_eq!(
+        sg_map.try_extend(vec![(1, 1)].into_iter()),
+        Err(SgError::CapacityZero)
+    );
+    assert_eq!(sg_map.ensure_capacity(1), Err(SgError::CapacityZero));
+    assert_eq!(sg_map.ensure_capacity(0), Ok(()));
+
+    let mut other = SgMap::<usize, usize, 0>::new();
+    assert_eq!(sg_map.try_append(&mut other), Ok(()));
+
+    // Reads see an empty map
+    assert_eq!(sg_map.get(&1), None);
+    assert!(!sg_map.contains_key(&1));
+    assert_eq!(sg_map.first_key_value(), None);
+    assert_eq!(sg_map.last_key_value(), None);
+    assert_eq!(sg_map.iter().count(), 0);
+    assert_eq!(sg_map.iter_mut().count(), 0);
+    assert_eq!(sg_map.range(..).count(), 0);
+    assert_eq!(sg_map.remove(&1), None);
+    assert_eq!(sg_map.pop_first(), None);
+    assert_eq!(sg_map.pop_last(), None);
+    assert!(matches!(sg_map.entry(1), Entry::Vacant(_)));
+
+    sg_map.retain(|_, _| true);
+    sg_map.clear();
+    assert!(sg_map.into_iter().next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_map_capacity_zero_insert_panic() {
+    let mut sg_map = SgMap::<usize, usize, 0>::new();
+    sg_map.insert(1, 1);
+}
+
+#[test]
+fn test_map_iter_from_until() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    while sg_map.len() < DEFAULT_CAPACITY {
+        let k = rng.gen_range(0, 4 * DEFAULT_CAPACITY);
+        sg_map.insert(k, k);
+        bt_map.insert(k, k);
+    }
+
+    for bound in 0..=(4 * DEFAULT_CAPACITY) {
+        assert!(sg_map.iter_from(&bound).eq(bt_map.range(bound..)));
+        assert!(sg_map.iter_until(&bound).eq(bt_map.range(..bound)));
+        assert!(sg_map
+            .iter_from(&bound)
+            .rev()
+            .eq(bt_map.range(bound..).rev()));
+        assert_eq!(
+            sg_map.iter_from(&bound).count() + sg_map.iter_until(&bound).count(),
+            sg_map.len()
+        );
+    }
+}
+
+#[test]
+fn test_map_replace_entry_at_min_max() {
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+
+    // Empty map falls back to insert
+    assert_eq!(sg_map.replace_entry_at_min(5, 50), Ok(None));
+    assert_eq!(sg_map.replace_entry_at_max(6, 60), Ok(Some((5, 50))));
+    assert_eq!(sg_map.len(), 1);
+
+    sg_map.clear();
+    let mut bt_map = BTreeMap::new();
+    for k in 100..(100 + DEFAULT_CAPACITY) {
+        sg_map.insert(k, k * 10);
+        bt_map.insert(k, k * 10);
+    }
+
+    // Ring-buffer-like reuse of the boundary slots
+    for i in 0..(DEFAULT_CAPACITY * 4) {
+        let max_key = *sg_map.last_key().unwrap();
+        let min_key = *sg_map.first_key().unwrap();
+
+        if i % 3 == 1 {
+            assert_eq!(
+                sg_map.replace_entry_at_min(min_key - 1, i),
+                Ok(bt_map.remove_entry(&min_key))
+            );
+            bt_map.insert(min_key - 1, i);
+        } else {
+            assert_eq!(
+                sg_map.replace_entry_at_max(max_key + 1, i),
+                Ok(bt_map.remove_entry(&max_key))
+            );
+            bt_map.insert(max_key + 1, i);
+        }
+
+        assert!(sg_map.iter().eq(bt_map.iter()));
+        assert_eq!(sg_map.first_key_value(), bt_map.iter().next());
+        assert_eq!(sg_map.last_key_value(), bt_map.iter().next_back());
+
+        // Lookups still traverse correctly
+        for (k, v) in bt_map.iter() {
+            assert_eq!(sg_map.get(k), Some(v));
+        }
+    }
+}
+
+#[test]
+fn test_map_replace_entry_at_min_max_rejected() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+    while sg_map.len() < DEFAULT_CAPACITY {
+        let k = rng.gen_range(0, 1_000);
+        sg_map.insert(k, k);
+        bt_map.insert(k, k);
+    }
+
+    let second_key = *bt_map.keys().nth(1).unwrap();
+    let penultimate_key = *bt_map.keys().rev().nth(1).unwrap();
+
+    // Equal to or past the neighboring key is rejected, leaving the map untouched
+    for key in [second_key, second_key + 1, penultimate_key] {
+        assert_eq!(sg_map.replace_entry_at_min(key, 0), Err((key, 0)));
+    }
+    for key in [penultimate_key, penultimate_key - 1, second_key] {
+        assert_eq!(sg_map.replace_entry_at_max(key, 0), Err((key, 0)));
+    }
+    assert!(sg_map.iter().eq(bt_map.iter()));
+    assert!(sg_map.verify().is_ok());
+
+    // Anything strictly between the boundary's old key and its neighbor is accepted
+    let min_key = *bt_map.keys().next().unwrap();
+    assert_eq!(
+        sg_map.replace_entry_at_min(second_key - 1, 1),
+        Ok(bt_map.remove_entry(&min_key))
+    );
+    bt_map.insert(second_key - 1, 1);
+
+    let max_key = *bt_map.keys().next_back().unwrap();
+    assert_eq!(
+        sg_map.replace_entry_at_max(penultimate_key + 1, 2),
+        Ok(bt_map.remove_entry(&max_key))
+    );
+    bt_map.insert(penultimate_key + 1, 2);
+
+    assert!(sg_map.iter().eq(bt_map.iter()));
+    assert!(sg_map.verify().is_ok());
+
+    // A single entry has no neighbor to conflict with
+    let mut sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> = [(5, 5)].iter().cloned().collect();
+    assert_eq!(sg_map.replace_entry_at_min(9, 9), Ok(Some((5, 5))));
+    assert_eq!(sg_map.replace_entry_at_max(1, 1), Ok(Some((9, 9))));
+}
+
+#[test]
+fn test_map_get_key_value_mut() {
+    let mut sgm = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    for id in 0..5 {
+        sgm.insert(TaggedKey { id, tag: 'a' }, id);
+    }
+
+    // Lookup key is equal to, but distinguishable from, the stored key
+    let (stored_key, val) = sgm
+        .get_key_value_mut(&TaggedKey { id: 3, tag: 'b' })
+        .unwrap();
+    assert_eq!(stored_key.tag, 'a');
+    *val *= 10;
+
+    assert_eq!(sgm.get(&TaggedKey { id: 3, tag: 'c' }), Some(&30));
+    assert!(sgm
+        .get_key_value_mut(&TaggedKey { id: 5, tag: 'a' })
+        .is_none());
+    assert!(sgm.iter().all(|(k, _)| k.tag == 'a'));
+    assert_eq!(sgm.values().sum::<usize>(), 1 + 2 + 30 + 4);
+}
+
+#[test]
+fn test_map_coalesce() {
+    let mut rng = rand::thread_rng();
+
+    // Merge overlapping or touching `start -> end` intervals
+    let merge = |_: &usize, end: &mut usize, next_start: &usize, next_end: &usize| {
+        if *next_start <= *end {
+            *end = (*end).max(*next_end);
+            true
+        } else {
+            false
+        }
+    };
+
+    for _ in 0..100 {
+        let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+        while sg_map.len() < DEFAULT_CAPACITY {
+            let start = rng.gen_range(0, DEFAULT_CAPACITY * 8);
+            sg_map.insert(start, start + rng.gen_range(0, 8));
+        }
+
+        // Reference merge
+        let mut expected: Vec<(usize, usize)> = Vec::new();
+        for (start, end) in sg_map.iter() {
+            match expected.last_mut() {
+                Some((_, last_end)) if *start <= *last_end => {
+                    *last_end = (*last_end).max(*end);
+                }
+                _ => expected.push((*start, *end)),
+            }
+        }
+
+        sg_map.coalesce(merge);
+        assert!(sg_map.iter().map(|(k, v)| (*k, *v)).eq(expected));
+
+        // Result is disjoint, so another pass is a no-op
+        let len = sg_map.len();
+        sg_map.coalesce(merge);
+        assert_eq!(sg_map.len(), len);
+
+        // Freed capacity is reusable
+        while sg_map.len() < DEFAULT_CAPACITY {
+            let start = rng.gen_range(0, DEFAULT_CAPACITY * 8);
+            sg_map.insert(start, start);
+        }
+    }
+}
+
+#[test]
+fn test_map_map_values() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+
+    while sg_map.len() < DEFAULT_CAPACITY {
+        let k = rng.gen_range(0, DEFAULT_CAPACITY * 4);
+        sg_map.insert(k, rng.gen_range(0, 100));
+    }
+
+    let mapped: SgMap<usize, String, DEFAULT_CAPACITY> = sg_map.map_values(|v| v.to_string());
+
+    assert_eq!(mapped.len(), sg_map.len());
+    assert!(mapped
+        .iter()
+        .map(|(k, v)| (*k, v.parse::<usize>().unwrap()))
+        .eq(sg_map.iter().map(|(k, v)| (*k, *v))));
+
+    // Source is untouched, result is independently usable
+    let mut mapped = mapped;
+    let k = *sg_map.first_key().unwrap();
+    assert!(mapped.remove(&k).is_some());
+    assert!(sg_map.contains_key(&k));
+    assert_eq!(mapped.insert(k, "new".to_string()), None);
+}
+
+#[test]
+fn test_map_retain_in_remove_in() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+        let mut sg_keys = SgSet::<usize, DEFAULT_CAPACITY>::new();
+
+        while sg_map.len() < DEFAULT_CAPACITY {
+            let k = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+            sg_map.insert(k, k * 10);
+        }
+
+        for _ in 0..rng.gen_range(0, DEFAULT_CAPACITY) {
+            sg_keys.insert(rng.gen_range(0, DEFAULT_CAPACITY * 2));
+        }
+
+        let bt_map: BTreeMap<usize, usize> = sg_map.iter().map(|(k, v)| (*k, *v)).collect();
+        let bt_keys: BTreeSet<usize> = sg_keys.iter().copied().collect();
+
+        let mut sg_retained = sg_map.clone();
+        sg_retained.retain_in(&sg_keys);
+        assert!(sg_retained
+            .iter()
+            .eq(bt_map.iter().filter(|(k, _)| bt_keys.contains(k))));
+
+        let mut sg_removed = sg_map.clone();
+        sg_removed.remove_in(&sg_keys);
+        assert!(sg_removed
+            .iter()
+            .eq(bt_map.iter().filter(|(k, _)| !bt_keys.contains(k))));
+
+        assert_eq!(sg_retained.len() + sg_removed.len(), sg_map.len());
+    }
+
+    // Differing capacities
+    let mut sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> =
+        (0..DEFAULT_CAPACITY).map(|k| (k, k)).collect();
+    let sg_keys: SgSet<usize, 2> = [0, DEFAULT_CAPACITY - 1].into();
+    sg_map.retain_in(&sg_keys);
+    assert!(sg_map.keys().eq(sg_keys.iter()));
+}
+
+#[test]
+fn test_map_range_borrowed_bounds() {
+    let words = ["ant", "bee", "cat", "cow", "dog", "eel", "fox", "gnu"];
+    let mut sg_map: SgMap<String, usize, DEFAULT_CAPACITY> =
+        words.iter().map(|w| (w.to_string(), w.len())).collect();
+    let mut bt_map: BTreeMap<String, usize> =
+        words.iter().map(|w| (w.to_string(), w.len())).collect();
+
+    let bounds = ["", "a", "ant", "c", "cow", "d", "fz", "z"];
+    for lo in bounds.iter() {
+        for hi in bounds.iter().filter(|hi| lo <= *hi) {
+            let range = (Included(*lo), Excluded(*hi));
+
+            assert!(sg_map
+                .range::<str, _>(range)
+                .eq(bt_map.range::<str, _>(range)));
+            assert!(sg_map
+                .range_mut::<str, _>(range)
+                .eq(bt_map.range_mut::<str, _>(range)));
+            assert!(sg_map
+                .range::<str, _>(range)
+                .rev()
+                .eq(bt_map.range::<str, _>(range).rev()));
+        }
+    }
+}
+
+#[test]
+fn test_map_first_last_entry_mut() {
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    assert_eq!(sg_map.first_entry_mut(), None);
+    assert_eq!(sg_map.last_entry_mut(), None);
+
+    for k in (0..DEFAULT_CAPACITY).rev() {
+        sg_map.insert(k * 2, 0);
+    }
+
+    // Scheduler-like ticks on both extremes
+    for tick in 1..=5 {
+        let (min_key, min_val) = sg_map.first_entry_mut().unwrap();
+        assert_eq!(*min_key, 0);
+        *min_val += tick;
+
+        let (max_key, max_val) = sg_map.last_entry_mut().unwrap();
+        assert_eq!(*max_key, (DEFAULT_CAPACITY - 1) * 2);
+        *max_val += tick * 10;
+    }
+
+    // Mutation persists
+    assert_eq!(sg_map[&0], 15);
+    assert_eq!(sg_map[&((DEFAULT_CAPACITY - 1) * 2)], 150);
+
+    // Ordering is unaffected
+    assert!(sg_map
+        .keys()
+        .copied()
+        .eq((0..DEFAULT_CAPACITY).map(|k| k * 2)));
+    assert_eq!(sg_map.values().filter(|v| **v != 0).count(), 2);
+
+    // Tracks updated extremes
+    sg_map.pop_first();
+    sg_map.insert(DEFAULT_CAPACITY * 2, 7);
+    assert_eq!(sg_map.first_entry_mut().map(|(k, _)| *k), Some(2));
+    assert_eq!(
+        sg_map.last_entry_mut().map(|(k, v)| (*k, *v)),
+        Some((DEFAULT_CAPACITY * 2, 7))
+    );
+}
+
+#[test]
+fn test_map_range_count() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for _ in 0..(DEFAULT_CAPACITY * 10) {
+        let k = rng.gen_range(0, DEFAULT_CAPACITY * 3);
+        if sg_map.len() < DEFAULT_CAPACITY {
+            sg_map.insert(k, k);
+            bt_map.insert(k, k);
+        } else {
+            sg_map.remove(&k);
+            bt_map.remove(&k);
+        }
+
+        let max = DEFAULT_CAPACITY * 3 + 1;
+        for lo in 0..max {
+            assert_eq!(sg_map.range_count(lo..), bt_map.range(lo..).count());
+            assert_eq!(sg_map.range_count(..lo), bt_map.range(..lo).count());
+            assert_eq!(sg_map.range_count(..=lo), bt_map.range(..=lo).count());
+
+            let hi = rng.gen_range(lo, max);
+            assert_eq!(sg_map.range_count(lo..hi), bt_map.range(lo..hi).count());
+            assert_eq!(sg_map.range_count(lo..=hi), bt_map.range(lo..=hi).count());
+            if lo < hi {
+                let range = (Excluded(lo), Included(hi));
+                assert_eq!(sg_map.range_count(range), bt_map.range(range).count());
+            }
+        }
+
+        assert_eq!(sg_map.range_count(..), sg_map.len());
+    }
+}
+
+#[test]
+fn test_map_lookup_outside_key_span() {
+    let mut sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> = (10..15).map(|k| (k, k)).collect();
+
+    // Below, within, and above the stored span
+    for k in [0, 9, 10, 12, 14, 15, 100].iter() {
+        let expected = (10..15).contains(k);
+        assert_eq!(sg_map.contains_key(k), expected);
+        assert_eq!(sg_map.get(k).is_some(), expected);
+        assert_eq!(sg_map.get_key_value(k).is_some(), expected);
+        assert_eq!(sg_map.get_mut(k).is_some(), expected);
+        assert_eq!(sg_map.get_key_value_mut(k).is_some(), expected);
+    }
+
+    // Span follows extremes as they change
+    sg_map.insert(100, 100);
+    assert_eq!(sg_map.get(&100), Some(&100));
+    sg_map.remove(&10);
+    assert!(!sg_map.contains_key(&10));
+    assert!(sg_map.contains_key(&11));
+
+    sg_map.clear();
+    assert!(!sg_map.contains_key(&11));
+}
+
+#[test]
+fn test_map_append_with() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        // Overlapping counter maps
+        let mut sg_a = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+        let mut sg_b = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+        for _ in 0..(DEFAULT_CAPACITY / 2) {
+            *sg_a.entry(rng.gen_range(0, DEFAULT_CAPACITY)).or_insert(0) += 1;
+            *sg_b.entry(rng.gen_range(0, DEFAULT_CAPACITY)).or_insert(0) += 1;
+        }
+
+        let mut expected: BTreeMap<usize, usize> = sg_a.iter().map(|(k, v)| (*k, *v)).collect();
+        for (k, v) in sg_b.iter() {
+            *expected.entry(*k).or_insert(0) += *v;
+        }
+
+        let mut conflicts = 0;
+        sg_a.append_with(&mut sg_b, |_, a_cnt, b_cnt| {
+            conflicts += 1;
+            a_cnt + b_cnt
+        });
+
+        assert!(sg_b.is_empty());
+        assert!(sg_a.iter().eq(expected.iter()));
+        assert_eq!(sg_a.values().sum::<usize>(), DEFAULT_CAPACITY);
+        assert!(conflicts <= DEFAULT_CAPACITY / 2);
+    }
+
+    // Panicking resolver leaves both maps valid, minus the conflicting key
+    let mut sg_a: SgMap<usize, usize, DEFAULT_CAPACITY> = (0..4).map(|k| (k, k)).collect();
+    let mut sg_b: SgMap<usize, usize, DEFAULT_CAPACITY> = (2..6).map(|k| (k, k)).collect();
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        sg_a.append_with(&mut sg_b, |k, a_val, b_val| match k {
+            3 => panic!("Resolve failed!"),
+            _ => a_val + b_val,
+        });
+    }));
+    assert!(res.is_err());
+
+    assert_eq!(sg_a.verify(), Ok(()));
+    assert_eq!(sg_b.verify(), Ok(()));
+    assert!(sg_a.into_iter().eq(vec![(0, 0), (1, 1), (2, 4)]));
+    assert!(sg_b.into_iter().eq(vec![(4, 4), (5, 5)]));
+
+    // Self's key is kept on conflict
+    let mut sg_a = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    let mut sg_b = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    sg_a.insert(TaggedKey { id: 1, tag: 'a' }, 1);
+    sg_b.insert(TaggedKey { id: 1, tag: 'b' }, 2);
+    sg_b.insert(TaggedKey { id: 2, tag: 'b' }, 3);
+
+    sg_a.append_with(&mut sg_b, |_, _, other_val| other_val);
+    assert!(sg_a
+        .iter()
+        .map(|(k, v)| (k.id, k.tag, *v))
+        .eq(vec![(1, 'a', 2), (2, 'b', 3)]));
+
+    // Empty `self` takes everything
+    let mut sg_empty = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    sg_empty.append_with(&mut sg_a, |_, _, _| unreachable!());
+    assert_eq!(sg_empty.len(), 2);
+    assert!(sg_a.is_empty());
+}
+
+#[test]
+fn test_map_entry_or_default_counting() {
+    // Deliberately not `Clone`
+    #[derive(Default, Debug, PartialEq)]
+    struct Counter(usize);
+
+    let mut rng = rand::thread_rng();
+    let mut sg_count = SgMap::<usize, Counter, DEFAULT_CAPACITY>::new();
+    let mut bt_count = BTreeMap::<usize, usize>::new();
+
+    for _ in 0..(DEFAULT_CAPACITY * 10) {
+        let key = rng.gen_range(0, DEFAULT_CAPACITY);
+        sg_count.entry(key).or_default().0 += 1;
+        *bt_count.entry(key).or_default() += 1;
+    }
+
+    assert_eq!(sg_count.len(), bt_count.len());
+    assert!(sg_count
+        .iter()
+        .map(|(k, c)| (*k, c.0))
+        .eq(bt_count.into_iter()));
+    assert_eq!(
+        sg_count.values().map(|c| c.0).sum::<usize>(),
+        DEFAULT_CAPACITY * 10
+    );
+}
+
+#[test]
+fn test_map_verify() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    assert_eq!(sg_map.verify(), Ok(()));
+
+    for _ in 0..(DEFAULT_CAPACITY * 10) {
+        let key = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+        match rng.gen_range(0, 3) {
+            0 => {
+                sg_map.remove(&key);
+            }
+            _ => {
+                let _ = sg_map.try_insert(key, key);
+            }
+        }
+        assert_eq!(sg_map.verify(), Ok(()));
+    }
+}
+
+#[test]
+fn test_map_into_sorted_array() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, String, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for _ in 0..(DEFAULT_CAPACITY / 2) {
+        let key = rng.gen_range(1, DEFAULT_CAPACITY * 2);
+        sg_map.insert(key, key.to_string());
+        bt_map.insert(key, key.to_string());
+    }
+
+    let (pairs, len) = sg_map.into_sorted_array();
+    assert_eq!(len, bt_map.len());
+    assert!(pairs[..len]
+        .iter()
+        .eq(bt_map.into_iter().collect::<Vec<_>>().iter()));
+    assert!(pairs[len..].iter().all(|(k, v)| (*k == 0) && v.is_empty()));
+
+    // Full and empty
+    let sg_full: SgMap<usize, usize, DEFAULT_CAPACITY> =
+        (0..DEFAULT_CAPACITY).map(|i| (i, i)).collect();
+    let (pairs, len) = sg_full.into_sorted_array();
+    assert_eq!(len, DEFAULT_CAPACITY);
+    assert!(pairs
+        .iter()
+        .enumerate()
+        .all(|(i, (k, v))| (i == *k) && (i == *v)));
+
+    let (pairs, len) = SgMap::<usize, usize, DEFAULT_CAPACITY>::new().into_sorted_array();
+    assert_eq!(len, 0);
+    assert_eq!(pairs, [(0, 0); DEFAULT_CAPACITY]);
+}
+
+#[test]
+fn test_map_iter_clone() {
+    // Deliberately not `Clone`
+    #[derive(Debug, PartialEq)]
+    struct Val(usize);
+
+    let sg_map: SgMap<usize, Val, DEFAULT_CAPACITY> =
+        (0..DEFAULT_CAPACITY).map(|i| (i, Val(i * 10))).collect();
+
+    // Both clones of a partially consumed range yield the same remainder
+    let mut range = sg_map.range(2..8);
+    assert_eq!(range.next(), Some((&2, &Val(20))));
+    let range_clone = range.clone();
+    assert!(range.eq(range_clone));
+    assert_eq!(sg_map.range(2..8).clone().next_back(), Some((&7, &Val(70))));
+
+    let mut iter = sg_map.iter();
+    iter.next();
+    let iter_clone = iter.clone();
+    assert_eq!(iter_clone.len(), DEFAULT_CAPACITY - 1);
+    assert!(iter.eq(iter_clone));
+
+    assert!(sg_map.keys().clone().eq(sg_map.keys()));
+    assert!(sg_map.values().clone().eq(sg_map.values()));
+}
+
+#[test]
+fn test_map_iter_nth() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for _ in 0..DEFAULT_CAPACITY {
+        let key = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+        sg_map.insert(key, key * 10);
+        bt_map.insert(key, key * 10);
+    }
+
+    for n in 0..(DEFAULT_CAPACITY + 1) {
+        // Subsequent `next` continues right after the nth element
+        let mut sg_iter = sg_map.iter();
+        let mut bt_iter = bt_map.iter();
+        assert_eq!(sg_iter.nth(n), bt_iter.nth(n));
+        assert_eq!(sg_iter.len(), bt_iter.len());
+        assert!(sg_iter.eq(bt_iter));
+
+        assert_eq!(sg_map.keys().nth(n), bt_map.keys().nth(n));
+        assert_eq!(sg_map.values().nth(n), bt_map.values().nth(n));
+        assert_eq!(
+            sg_map.range(2..(DEFAULT_CAPACITY * 2 - 2)).nth(n),
+            bt_map.range(2..(DEFAULT_CAPACITY * 2 - 2)).nth(n)
+        );
+
+        let mut sg_into_iter = sg_map.clone().into_iter();
+        let mut bt_into_iter = bt_map.clone().into_iter();
+        assert_eq!(sg_into_iter.nth(n), bt_into_iter.nth(n));
+        assert!(sg_into_iter.eq(bt_into_iter));
+    }
+}
+
+#[test]
+fn test_map_get_pair_mut() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> =
+        (0..DEFAULT_CAPACITY).map(|k| (k * 2, k * 20)).collect();
+
+    // Every ordered pair, including absent and equal keys
+    for _ in 0..100 {
+        let key_a = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+        let key_b = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+
+        let expected = match (sg_map.get(&key_a), sg_map.get(&key_b)) {
+            (Some(a), Some(b)) if key_a != key_b => Some((*a, *b)),
+            _ => None,
+        };
+
+        match sg_map.get_pair_mut(&key_a, &key_b) {
+            Some((a, b)) => {
+                assert_eq!(Some((*a, *b)), expected);
+                core::mem::swap(a, b);
+                assert_eq!(sg_map[&key_a], expected.unwrap().1);
+                assert_eq!(sg_map[&key_b], expected.unwrap().0);
+            }
+            None => assert!(expected.is_none()),
+        }
+    }
+
+    // Values are still a permutation
+    let mut vals: Vec<_> = sg_map.values().copied().collect();
+    vals.sort_unstable();
+    assert!(vals.into_iter().eq((0..DEFAULT_CAPACITY).map(|k| k * 20)));
+}
+
+#[test]
+fn test_map_try_clone() {
+    let mut sg_map = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    assert_eq!(sg_map.try_clone(), Ok(SgMap::new()));
+
+    for k in 0..DEFAULT_CAPACITY {
+        sg_map.insert(k, k.to_string());
+    }
+    sg_map.set_rebal_param(2.0, 3.0).unwrap();
+
+    let copy = sg_map.try_clone().unwrap();
+    assert_eq!(copy, sg_map);
+    assert_eq!(copy.rebal_param(), sg_map.rebal_param());
+
+    // Independent of the original
+    sg_map.clear();
+    assert_eq!(copy.len(), DEFAULT_CAPACITY);
+}
+
+#[test]
+fn test_map_extend_from_ref_iter() {
+    let mut sg_map = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    sg_map.insert(1, 'a');
+    sg_map.insert(2, 'b');
+
+    let mut other = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    other.insert(2, 'x');
+    other.insert(3, 'c');
+
+    // Borrowed view, overlapping key is overwritten
+    sg_map.extend(other.iter());
+    assert!(sg_map.into_iter().eq(vec![(1, 'a'), (2, 'x'), (3, 'c')]));
+    assert_eq!(other.len(), 2);
+
+    // Matches BTreeMap
+    let mut sg_map = SgMap::<i32, char, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::<i32, char>::new();
+    sg_map.extend(&other);
+    bt_map.extend(&other);
+    assert!(sg_map.iter().eq(bt_map.iter()));
+}
+
+#[test]
+fn test_map_entry_vacant_insert() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    // Vacant inserts at new min, new max, and interior positions
+    while sg_map.len() < DEFAULT_CAPACITY {
+        let key = rng.gen_range(0, DEFAULT_CAPACITY * 4);
+        *sg_map.entry(key).or_insert(0) += 1;
+        *bt_map.entry(key).or_insert(0) += 1;
+
+        assert!(sg_map.iter().eq(bt_map.iter()));
+        assert_eq!(sg_map.first_key_value(), bt_map.iter().next());
+        assert_eq!(sg_map.last_key_value(), bt_map.iter().next_back());
+        assert_eq!(sg_map.verify(), Ok(()));
+    }
+}
+
+#[test]
+fn test_map_keys_all_in_range() {
+    let mut sg_map = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    assert!(sg_map.keys_all_in_range((Included(5), Excluded(1))));
+
+    sg_map.insert(3, "c");
+    sg_map.insert(1, "a");
+    sg_map.insert(5, "e");
+
+    assert!(sg_map.keys_all_in_range(1..=5));
+    assert!(sg_map.keys_all_in_range(..));
+    assert!(sg_map.keys_all_in_range((Excluded(0), Included(5))));
+    assert!(!sg_map.keys_all_in_range(1..5));
+    assert!(!sg_map.keys_all_in_range(2..));
+    assert!(!sg_map.keys_all_in_range((Included(5), Excluded(1))));
+
+    // Interior keys aren't checked, only the extremes need to be
+    sg_map.remove(&3);
+    assert!(sg_map.keys_all_in_range(1..=5));
+}
+
+#[test]
+fn test_map_update_or_insert() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    // Matches the entry API equivalent
+    for _ in 0..(DEFAULT_CAPACITY * 10) {
+        let key = rng.gen_range(0, DEFAULT_CAPACITY);
+        let sg_val = *sg_map.update_or_insert(key, |v| *v *= 2, || key);
+        let bt_val = *bt_map.entry(key).and_modify(|v| *v *= 2).or_insert(key);
+        assert_eq!(sg_val, bt_val);
+    }
+    assert!(sg_map.iter().eq(bt_map.iter()));
+
+    // Full: update OK, insert fails without calling either closure
+    let mut sg_map: SgMap<_, _, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY).map(|k| (k, 0)).collect();
+    assert_eq!(
+        sg_map.try_update_or_insert(0, |v| *v += 1, || unreachable!()),
+        Ok(&mut 1)
+    );
+    assert_eq!(
+        sg_map.try_update_or_insert(DEFAULT_CAPACITY, |_| unreachable!(), || unreachable!()),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_eq!(sg_map.len(), DEFAULT_CAPACITY);
+    assert_eq!(sg_map.verify(), Ok(()));
+}
+
+#[test]
+fn test_map_fill() {
+    let sg_map: SgMap<usize, char, DEFAULT_CAPACITY> =
+        vec![(5, 'e'), (1, 'a'), (3, 'c')].into_iter().collect();
+
+    // Buffer longer than map: tail untouched
+    let mut keys = [&0; DEFAULT_CAPACITY];
+    assert_eq!(sg_map.fill_keys(&mut keys), 3);
+    assert_eq!(keys[..4], [&1, &3, &5, &0]);
+
+    // Buffer shorter than map: truncated, still in order
+    let mut vals = [&' '; 2];
+    assert_eq!(sg_map.fill_values(&mut vals), 2);
+    assert_eq!(vals, [&'a', &'c']);
+
+    // Empty buffer
+    assert_eq!(sg_map.fill_entries(&mut []), 0);
+
+    let mut entries = [(&0, &' '); DEFAULT_CAPACITY];
+    let len = sg_map.fill_entries(&mut entries);
+    assert!(entries[..len].iter().copied().eq(sg_map.iter()));
+}
+
+#[test]
+fn test_map_partition() {
+    let mut rng = rand::thread_rng();
+    let mut sg_map = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    sg_map.set_rebal_param(0.6, 1.0).unwrap();
+    while sg_map.len() < DEFAULT_CAPACITY {
+        let key = rng.gen_range(0, DEFAULT_CAPACITY * 4);
+        sg_map.insert(key, key * 10);
+    }
+    let expected = sg_map.clone();
+
+    let (even, odd) = sg_map.partition(|k, _| k % 2 == 0);
+
+    // Counts sum, halves are disjoint and ordered
+    assert_eq!(even.len() + odd.len(), DEFAULT_CAPACITY);
+    assert!(even.keys().all(|k| k % 2 == 0));
+    assert!(odd.keys().all(|k| k % 2 == 1));
+    assert!(even.iter().eq(expected.iter().filter(|(k, _)| *k % 2 == 0)));
+    assert!(odd.iter().eq(expected.iter().filter(|(k, _)| *k % 2 == 1)));
+
+    // Balanced, params kept, capacity free for reuse
+    for half in [even, odd].iter() {
+        assert_eq!(half.verify(), Ok(()));
+        assert_eq!(half.rebal_param(), (0.6, 1.0));
+        assert_eq!(half.capacity(), DEFAULT_CAPACITY);
+    }
+
+    // Degenerate splits
+    let (all, none) = expected.clone().partition(|_, _| true);
+    assert_eq!(all, expected);
+    assert!(none.is_empty());
+    let (none, all) = expected.clone().partition(|_, _| false);
+    assert!(none.is_empty());
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn test_map_retain_while() {
+    let mut sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> = (0..10).map(|x| (x, x)).collect();
+
+    // Visits ascending, count of kept elements is passed through, break keeps the tail
+    let mut visited = Vec::new();
+    sg_map.retain_while(|&k, v, kept| {
+        visited.push((k, kept));
+        if k == 6 {
+            return ControlFlow::Break(());
+        }
+        *v *= 10;
+        ControlFlow::Continue(k % 3 != 0)
+    });
+
+    assert_eq!(
+        visited,
+        vec![(0, 0), (1, 0), (2, 1), (3, 2), (4, 2), (5, 3), (6, 4)]
+    );
+    assert!(sg_map.iter().eq([
+        (1, 10),
+        (2, 20),
+        (4, 40),
+        (5, 50),
+        (6, 6),
+        (7, 7),
+        (8, 8),
+        (9, 9)
+    ]
+    .iter()
+    .map(|(k, v)| (k, v))));
+
+    // Never breaking is equivalent to `retain`
+    sg_map.retain_while(|&k, _, _| ControlFlow::Continue(k % 2 == 0));
+    assert!(sg_map.keys().eq([2, 4, 6, 8].iter()));
+
+    // Breaking immediately is a no-op
+    sg_map.retain_while(|_, _, _| ControlFlow::Break(()));
+    assert_eq!(sg_map.len(), 4);
+
+    // Empty map never calls the predicate
+    let mut empty: SgMap<usize, usize, DEFAULT_CAPACITY> = SgMap::new();
+    empty.retain_while(|_, _, _| unreachable!());
+    assert!(empty.is_empty());
+
+    // Removing everything
+    sg_map.retain_while(|_, _, _| ControlFlow::Continue(false));
+    assert!(sg_map.is_empty());
+}
+
+#[test]
+fn test_map_eq_by_cmp_by() {
+    let a: SgMap<usize, f64, DEFAULT_CAPACITY> =
+        vec![(1, 1.0), (2, 2.0), (3, 3.0)].into_iter().collect();
+    let b: SgMap<usize, f64, DEFAULT_CAPACITY> = vec![(1, 1.0 + 1e-12), (2, 2.0), (3, 3.0 - 1e-12)]
+        .into_iter()
+        .collect();
+    let c: SgMap<usize, f64, DEFAULT_CAPACITY> = vec![(1, 1.0), (2, 2.0)].into_iter().collect();
+    let empty = SgMap::<usize, f64, DEFAULT_CAPACITY>::new();
+
+    let approx_eq =
+        |(k1, v1): (&usize, &f64), (k2, v2): (&usize, &f64)| k1 == k2 && (v1 - v2).abs() < 1e-9;
+    let approx_cmp = |a: (&usize, &f64), b: (&usize, &f64)| match approx_eq(a, b) {
+        true => Ordering::Equal,
+        false => a.partial_cmp(&b).unwrap(),
+    };
+
+    // Derived equality is too strict, custom equivalence isn't
+    assert_ne!(a, b);
+    assert!(a.eq_by(&b, approx_eq));
+    assert!(b.eq_by(&a, approx_eq));
+    assert_eq!(a.cmp_by(&b, approx_cmp), Ordering::Equal);
+
+    // Length mismatch is never equal, prefix orders first
+    assert!(!a.eq_by(&c, approx_eq));
+    assert_eq!(a.cmp_by(&c, approx_cmp), Ordering::Greater);
+    assert_eq!(c.cmp_by(&a, approx_cmp), Ordering::Less);
+    assert_eq!(empty.cmp_by(&c, approx_cmp), Ordering::Less);
+    assert!(empty.eq_by(&empty, |_, _| unreachable!()));
+
+    // First differing entry decides
+    let mut d = a.clone();
+    d.insert(2, 1.5);
+    assert!(!a.eq_by(&d, approx_eq));
+    assert_eq!(a.cmp_by(&d, approx_cmp), Ordering::Greater);
+    assert_eq!(d.cmp_by(&a, approx_cmp), Ordering::Less);
+
+    // Agrees with the derived `Ord` for an exact comparison
+    let x: SgMap<usize, usize, DEFAULT_CAPACITY> = vec![(1, 1), (3, 3)].into_iter().collect();
+    let y: SgMap<usize, usize, DEFAULT_CAPACITY> = vec![(1, 1), (2, 9)].into_iter().collect();
+    assert_eq!(x.cmp_by(&y, |a, b| a.cmp(&b)), x.cmp(&y));
+    assert_eq!(y.cmp_by(&x, |a, b| a.cmp(&b)), y.cmp(&x));
+}
+
+#[test]
+fn test_map_common_key_count() {
+    let a: SgMap<usize, usize, DEFAULT_CAPACITY> = (0..6).map(|x| (x, x)).collect();
+    let b: SgMap<usize, usize, DEFAULT_CAPACITY> = (4..10).map(|x| (x, x * 10)).collect();
+    let empty = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+
+    // Values don't matter, only keys
+    assert_eq!(a.common_key_count(&b), 2);
+    assert_eq!(b.common_key_count(&a), 2);
+    assert_eq!(a.common_key_count(&a), a.len());
+    assert_eq!(a.common_key_count(&empty), 0);
+    assert_eq!(empty.common_key_count(&a), 0);
+
+    // Exact sizing for append
+    let mut a_append = a.clone();
+    let mut b_append = b.clone();
+    a_append.append(&mut b_append);
+    assert_eq!(a_append.len(), a.len() + b.len() - a.common_key_count(&b));
+}
+
+#[test]
+fn test_map_split_by() {
+    let mut sg_map: SgMap<usize, usize, DEFAULT_CAPACITY> = (0..10).map(|x| (x, x)).collect();
+    sg_map.set_rebal_param(0.6, 1.0).unwrap();
+
+    // Visits ascending, values can be updated
+    let mut visited = Vec::new();
+    let mut removed = sg_map.split_by(|&k, v| {
+        visited.push(k);
+        *v *= 10;
+        k % 3 == 0
+    });
+
+    assert!(visited.iter().eq((0..10).collect::<Vec<_>>().iter()));
+    assert!(removed.keys().eq([0, 3, 6, 9].iter()));
+    assert!(removed.values().eq([0, 30, 60, 90].iter()));
+    assert!(sg_map.keys().eq([1, 2, 4, 5, 7, 8].iter()));
+    assert!(sg_map.values().eq([10, 20, 40, 50, 70, 80].iter()));
+    assert_eq!(removed.rebal_param(), (0.6, 1.0));
+    assert_eq!(removed.verify(), Ok(()));
+    assert_eq!(sg_map.verify(), Ok(()));
+
+    // Removed map has full capacity
+    removed.extend((10..16).map(|x| (x, x)));
+    assert!(removed.is_full());
+
+    // No matches leaves `self` untouched
+    assert!(sg_map.split_by(|_, _| false).is_empty());
+    assert_eq!(sg_map.len(), 6);
+
+    // All matches empties `self`
+    assert_eq!(sg_map.split_by(|_, _| true).len(), 6);
+    assert!(sg_map.is_empty());
+}
+
+#[test]
+fn test_map_drain() {
+    const CAPACITY: usize = 500;
+    let mut sgm = SgMap::<u16, u16, CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..CAPACITY {
+        let k = rng.gen_range(0, 1_000);
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+
+    let expected: Vec<(u16, u16)> = btm.into_iter().collect();
+    assert_eq!(sgm.drain().len(), expected.len());
+    assert!(sgm.is_empty());
+
+    for (k, v) in &expected {
+        sgm.insert(*k, *v);
+    }
+    assert!(sgm.drain().eq(expected.iter().copied()));
+    assert!(sgm.is_empty());
+
+    // Partial consumption still empties the map, which stays usable
+    for (k, v) in &expected {
+        sgm.insert(*k, *v);
+    }
+    assert_eq!(sgm.drain().take(3).count(), 3);
+    assert!(sgm.is_empty());
+    assert_eq!(sgm.first_key_value(), None);
+
+    sgm.insert(1, 1);
+    assert_eq!(sgm.len(), 1);
+}
+
+#[test]
+fn test_evict_until() {
+    const CAPACITY: usize = 64;
+    let mut sgm = SgMap::<u16, u32, CAPACITY>::new();
+    let mut rng = rand::thread_rng();
+
+    while !sgm.is_full() {
+        sgm.insert(rng.gen(), rng.gen_range(0, 16));
+    }
+
+    // Reference: lowest scores (ties by key) are evicted
+    let mut expected: Vec<(u16, u32)> = sgm.iter().map(|(k, v)| (*k, *v)).collect();
+    expected.sort_by_key(|(k, v)| (*v, *k));
+    let mut expected: Vec<(u16, u32)> = expected.split_off(CAPACITY / 2);
+    expected.sort_unstable();
+
+    let mut score_cnt = 0;
+    let evicted = sgm.evict_until(CAPACITY / 2, |_, v| {
+        score_cnt += 1;
+        *v
+    });
+
+    assert_eq!(evicted, CAPACITY / 2);
+    assert_eq!(score_cnt, CAPACITY);
+    assert_eq!(sgm.len(), CAPACITY / 2);
+    assert!(sgm.iter().eq(expected.iter().map(|(k, v)| (k, v))));
+    assert_eq!(sgm.verify(), Ok(()));
+
+    // Already at or under target, nothing scored or evicted
+    assert_eq!(sgm.evict_until(CAPACITY / 2, |_, _| unreachable!()), 0);
+    assert_eq!(sgm.evict_until(CAPACITY, |_, _| unreachable!()), 0);
+    assert_eq!(sgm.len(), CAPACITY / 2);
+
+    // Target of zero empties the map
+    assert_eq!(sgm.evict_until(0, |_, v| *v), CAPACITY / 2);
+    assert!(sgm.is_empty());
+}
+
+// Compares by `id` only, so `tag` distinguishes equal-but-not-identical keys
+#[derive(Debug, Default, Clone, Copy)]
+struct TaggedKey {
+    id: usize,
+    tag: char,
+}
+
+impl PartialEq for TaggedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TaggedKey {}
+
+impl PartialOrd for TaggedKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TaggedKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+#[test]
+fn test_map_entry_replace() {
+    let mut sgm = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    for id in 0..5 {
+        sgm.insert(TaggedKey { id, tag: 'a' }, id);
+    }
+
+    match sgm.entry(TaggedKey { id: 2, tag: 'b' }) {
+        Entry::Occupied(o) => {
+            let (old_key, old_val) = o.replace_entry(20);
+            assert_eq!(old_key.tag, 'a');
+            assert_eq!(old_val, 2);
+        }
+        Entry::Vacant(_) => panic!("Expected occupied entry!"),
+    }
+    assert_eq!(
+        sgm.get_key_value(&TaggedKey { id: 2, tag: 'z' })
+            .map(|(k, v)| (k.tag, *v)),
+        Some(('b', 20))
+    );
+
+    let old_key = sgm
+        .last_entry()
+        .unwrap()
+        .replace_key(TaggedKey { id: 4, tag: 'c' });
+    assert_eq!(old_key.tag, 'a');
+    assert_eq!(
+        sgm.last_key_value().map(|(k, v)| (k.tag, *v)),
+        Some(('c', 4))
+    );
+
+    assert_eq!(sgm.len(), 5);
+    assert!(sgm.keys().map(|k| k.id).eq(0..5));
+
+    // Keys needn't be `Clone`
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Interned(usize);
+
+    let mut sgm = SgMap::<Interned, usize, DEFAULT_CAPACITY>::new();
+    sgm.insert(Interned(1), 1);
+    match sgm.entry(Interned(1)) {
+        Entry::Occupied(o) => assert_eq!(o.replace_entry(2), (Interned(1), 1)),
+        Entry::Vacant(_) => panic!("Expected occupied entry!"),
+    }
+    assert_eq!(sgm.get(&Interned(1)), Some(&2));
+}
+
+#[test]
+fn test_map_entry_key_value() {
+    let mut sgm = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    for id in 0..5 {
+        sgm.insert(TaggedKey { id, tag: 'a' }, id);
+    }
+
+    // Stored key is returned, not the lookup key
+    match sgm.entry(TaggedKey { id: 3, tag: 'b' }) {
+        Entry::Occupied(o) => {
+            let (k, v) = o.get_key_value();
+            assert_eq!((k.id, k.tag, *v), (3, 'a', 3));
+
+            let (k, v) = o.into_key_value();
+            assert_eq!(k.tag, 'a');
+            *v = 30;
+        }
+        Entry::Vacant(_) => panic!("Expected occupied entry!"),
+    }
+
+    assert_eq!(
+        sgm.get_key_value(&TaggedKey { id: 3, tag: 'z' })
+            .map(|(k, v)| (k.tag, *v)),
+        Some(('a', 30))
+    );
+    assert_eq!(sgm.len(), 5);
+}
+
+#[should_panic(expected = "API misuse: replacement key must compare equal to the entry's key!")]
+#[test]
+fn test_map_entry_replace_key_panic() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::from_iter([(1, 1), (2, 2)]);
+    sgm.first_entry().unwrap().replace_key(3);
+}
+
+#[test]
+fn test_map_entry_replace_entry_first_last() {
+    let mut sgm = SgMap::<TaggedKey, usize, DEFAULT_CAPACITY>::new();
+    for id in 0..5 {
+        sgm.insert(TaggedKey { id, tag: 'a' }, id);
+    }
+
+    // Key-less entries: replace key and value separately
+    let mut first = sgm.first_entry().unwrap();
+    assert_eq!(first.insert(10), 0);
+    assert_eq!(first.replace_key(TaggedKey { id: 0, tag: 'b' }).tag, 'a');
+
+    let mut last = sgm.last_entry().unwrap();
+    assert_eq!(last.insert(40), 4);
+    assert_eq!(last.replace_key(TaggedKey { id: 4, tag: 'b' }).tag, 'a');
+
+    assert_eq!(sgm.len(), 5);
+    assert!(sgm.verify().is_ok());
+    assert_eq!(
+        sgm.first_key_value().map(|(k, v)| (k.id, k.tag, *v)),
+        Some((0, 'b', 10))
+    );
+    assert_eq!(
+        sgm.last_key_value().map(|(k, v)| (k.id, k.tag, *v)),
+        Some((4, 'b', 40))
+    );
+}
+
+#[should_panic(
+    expected = "API misuse: `replace_entry` requires an entry created by `SgMap::entry`!"
+)]
+#[test]
+fn test_map_entry_replace_entry_panic() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::from_iter([(1, 1), (2, 2)]);
+    sgm.last_entry().unwrap().replace_entry(3);
+}
+
+#[test]
+fn test_map_memory_report() {
+    fn check<const N: usize>() -> usize {
+        let mut sgm = SgMap::<u64, u8, N>::new();
+        for i in 0..(N / 2) {
+            sgm.insert(i as u64, i as u8);
+        }
+
+        let report = sgm.memory_report();
+        assert_eq!(report.stack_bytes, std::mem::size_of::<SgMap<u64, u8, N>>());
+        assert!(report.node_bytes * N <= report.stack_bytes);
+        assert_eq!(report.capacity, N);
+        assert_eq!(report.used, N / 2);
+
+        report.node_bytes
+    }
+
+    // Index width, and therefore node size, grows with capacity
+    assert!(check::<1>() <= check::<10>());
+    assert!(check::<10>() <= check::<256>());
+    assert!(check::<256>() <= check::<1024>());
+}
+
 #[should_panic(expected = "range start is greater than range end in BTreeMap")]
 #[test]
 fn test_btree_map_range_panic_1() {
@@ -1,9 +1,14 @@
+use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::ops::Bound::{Excluded, Included};
+use std::ops::RangeBounds;
 
 use scapegoat::{sgset, SgError, SgSet};
 
+use rand::Rng;
+
 const DEFAULT_CAPACITY: usize = 10;
 
 // Normal APIs ---------------------------------------------------------------------------------------------------------
@@ -116,6 +121,96 @@ fn test_set_iter() {
     assert_eq!(sgs_iter.next(), None);
 }
 
+#[test]
+fn test_set_iter_double_ended() {
+    const CAPACITY: usize = 256;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..20 {
+        let mut sgs = SgSet::<usize, CAPACITY>::new();
+        let mut bts = BTreeSet::new();
+
+        for _ in 0..rng.gen_range(0, CAPACITY) {
+            let v = rng.gen_range(0, CAPACITY * 2);
+            sgs.insert(v);
+            bts.insert(v);
+        }
+
+        // Sparse arena, so iteration order differs from physical order
+        for _ in 0..rng.gen_range(0, CAPACITY / 4) {
+            let v = rng.gen_range(0, CAPACITY * 2);
+            assert_eq!(sgs.remove(&v), bts.remove(&v));
+        }
+
+        assert_eq!(sgs.iter().last(), bts.iter().last());
+        assert_eq!(sgs.iter().min(), bts.iter().min());
+        assert_eq!(sgs.iter().max(), bts.iter().max());
+        assert!(sgs.iter().rev().eq(bts.iter().rev()));
+
+        // Interleaved stepping from both ends never overlaps
+        let mut sgs_iter = sgs.iter();
+        let mut bts_iter = bts.iter();
+        let mut sgs_into_iter = sgs.clone().into_iter();
+        let mut bts_into_iter = bts.clone().into_iter();
+
+        loop {
+            let (sg_ref, bt_ref, sg_val, bt_val) = match rng.gen_range(0, 3) {
+                0 => (
+                    sgs_iter.next(),
+                    bts_iter.next(),
+                    sgs_into_iter.next(),
+                    bts_into_iter.next(),
+                ),
+                1 => (
+                    sgs_iter.next_back(),
+                    bts_iter.next_back(),
+                    sgs_into_iter.next_back(),
+                    bts_into_iter.next_back(),
+                ),
+                _ => {
+                    let n = rng.gen_range(0, 4);
+                    (
+                        sgs_iter.nth(n),
+                        bts_iter.nth(n),
+                        sgs_into_iter.nth(n),
+                        bts_into_iter.nth(n),
+                    )
+                }
+            };
+
+            assert_eq!(sg_ref, bt_ref);
+            assert_eq!(sg_val, bt_val);
+            assert_eq!(sgs_iter.len(), bts_iter.len());
+            assert_eq!(sgs_into_iter.len(), bts_into_iter.len());
+
+            if sg_ref.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(sgs_iter.next_back(), None);
+        assert_eq!(sgs_into_iter.next(), None);
+    }
+
+    // Back then front, partially consumed
+    let sgs: SgSet<_, 10> = (1..=5).collect();
+    let mut iter = sgs.iter();
+    assert_eq!(iter.next_back(), Some(&5));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.clone().last(), Some(&4));
+    assert_eq!(iter.clone().min(), Some(&2));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut into_iter = sgs.into_iter();
+    assert_eq!(into_iter.next_back(), Some(5));
+    assert_eq!(into_iter.next(), Some(1));
+    assert_eq!(into_iter.max(), Some(4));
+}
+
 #[test]
 fn test_set_append() {
     let mut a = SgSet::new();
@@ -198,6 +293,99 @@ fn test_set_union() {
     );
 }
 
+#[test]
+fn test_set_op_counts() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..20 {
+        let mut sgs_1 = SgSet::<usize, DEFAULT_CAPACITY>::new();
+        let mut sgs_2 = SgSet::<usize, DEFAULT_CAPACITY>::new();
+        let mut bts_1 = BTreeSet::new();
+        let mut bts_2 = BTreeSet::new();
+
+        for _ in 0..rng.gen_range(0, DEFAULT_CAPACITY) {
+            let v = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+            sgs_1.insert(v);
+            bts_1.insert(v);
+        }
+
+        for _ in 0..rng.gen_range(0, DEFAULT_CAPACITY) {
+            let v = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+            sgs_2.insert(v);
+            bts_2.insert(v);
+        }
+
+        assert_eq!(
+            sgs_1.intersection_count(&sgs_2),
+            bts_1.intersection(&bts_2).count()
+        );
+        assert_eq!(sgs_1.union_count(&sgs_2), bts_1.union(&bts_2).count());
+        assert_eq!(
+            sgs_1.difference_count(&sgs_2),
+            bts_1.difference(&bts_2).count()
+        );
+        assert_eq!(
+            sgs_2.difference_count(&sgs_1),
+            bts_2.difference(&bts_1).count()
+        );
+        assert_eq!(
+            sgs_1.symmetric_difference_count(&sgs_2),
+            bts_1.symmetric_difference(&bts_2).count()
+        );
+    }
+}
+
+#[test]
+fn test_set_ops_sized() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..20 {
+        let mut sgs_small = SgSet::<usize, 4>::new();
+        let mut sgs_large = SgSet::<usize, 8>::new();
+        let mut bts_small = BTreeSet::new();
+        let mut bts_large = BTreeSet::new();
+
+        while !sgs_small.is_full() {
+            let v = rng.gen_range(0, 16);
+            sgs_small.insert(v);
+            bts_small.insert(v);
+        }
+
+        while !sgs_large.is_full() {
+            let v = rng.gen_range(0, 16);
+            sgs_large.insert(v);
+            bts_large.insert(v);
+        }
+
+        let union: SgSet<usize, 16> = sgs_small.union_sized(&sgs_large).unwrap();
+        assert!(union.iter().eq(bts_small.union(&bts_large)));
+
+        let intersection: SgSet<usize, 16> = sgs_small.intersection_sized(&sgs_large).unwrap();
+        assert!(intersection.iter().eq(bts_small.intersection(&bts_large)));
+
+        let diff: SgSet<usize, 16> = sgs_large.difference_sized(&sgs_small).unwrap();
+        assert!(diff.iter().eq(bts_large.difference(&bts_small)));
+
+        let sym_diff: SgSet<usize, 16> = sgs_small.symmetric_difference_sized(&sgs_large).unwrap();
+        assert!(sym_diff
+            .iter()
+            .eq(bts_small.symmetric_difference(&bts_large)));
+    }
+
+    // Output capacity exceeded
+    let a = SgSet::<_, 4>::from([1, 2, 3, 4]);
+    let b = SgSet::<_, 8>::from([5, 6, 7, 8, 9, 10, 11, 12]);
+    assert_eq!(
+        a.union_sized::<8, 8>(&b),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_eq!(
+        a.symmetric_difference_sized::<8, 11>(&b),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_eq!(a.intersection_sized::<8, 0>(&b), Ok(SgSet::new()));
+}
+
 #[test]
 fn test_set_is_superset() {
     let a = SgSet::from_iter([1, 3, 5]);
@@ -241,6 +429,32 @@ fn test_set_insert_fallible() {
     assert_eq!(a.try_insert(4), Err(SgError::StackCapacityExceeded));
 }
 
+#[test]
+fn test_set_insert_fallible_matches_insert() {
+    let mut rng = rand::thread_rng();
+    let mut sg_set = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    let mut bt_set = BTreeSet::new();
+
+    for _ in 0..(DEFAULT_CAPACITY * 20) {
+        let v = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+
+        if bt_set.contains(&v) || (bt_set.len() < DEFAULT_CAPACITY) {
+            // Overwrite and insert both match the infallible API
+            assert_eq!(sg_set.try_insert(v), Ok(bt_set.insert(v)));
+        } else {
+            // Overflow is reported, the set is left untouched
+            assert_eq!(sg_set.try_insert(v), Err(SgError::StackCapacityExceeded));
+        }
+
+        assert!(sg_set.iter().eq(bt_set.iter()));
+
+        if rng.gen_range(0, 4) == 0 {
+            let v = rng.gen_range(0, DEFAULT_CAPACITY * 2);
+            assert_eq!(sg_set.remove(&v), bt_set.remove(&v));
+        }
+    }
+}
+
 #[test]
 fn test_set_append_fallible() {
     let mut a = SgSet::<_, 6>::new();
@@ -314,6 +528,545 @@ fn test_set_range() {
     assert!(keys.iter().all(|x| range.contains(*x)));
 }
 
+#[test]
+fn test_set_remove_range() {
+    let mut sgs = SgSet::<_, DEFAULT_CAPACITY>::from_iter(0..DEFAULT_CAPACITY);
+    let mut bts = BTreeSet::from_iter(0..DEFAULT_CAPACITY);
+
+    assert_eq!(sgs.remove_range(2..=4), 3);
+    bts.retain(|x| !(2..=4).contains(x));
+    assert!(sgs.iter().eq(bts.iter()));
+
+    assert_eq!(sgs.remove_range(..3), 2);
+    bts.retain(|x| !(..3).contains(x));
+    assert!(sgs.iter().eq(bts.iter()));
+
+    assert_eq!(sgs.remove_range((Excluded(5), Excluded(8))), 2);
+    bts.retain(|x| !(6..8).contains(x));
+    assert!(sgs.iter().eq(bts.iter()));
+
+    assert_eq!(sgs.remove_range(100..), 0);
+    assert!(sgs.iter().eq([5, 8, 9].iter()));
+}
+
+#[test]
+fn test_set_index_by_rank() {
+    let mut sgs = SgSet::<usize, 100>::new();
+    let mut bts = BTreeSet::new();
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let v = rng.gen_range(0, 1_000);
+        sgs.insert(v);
+        bts.insert(v);
+    }
+
+    for (i, v) in bts.iter().enumerate() {
+        assert_eq!(sgs[i], *v);
+    }
+}
+
+#[should_panic(expected = "Index out of bounds: the len is 3 but the index is 3")]
+#[test]
+fn test_set_index_by_rank_panic() {
+    let sgs = SgSet::from([1, 2, 3]);
+    let _ = sgs[3];
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_set_eq_btree_set() {
+    let mut sgs = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    let mut bts = BTreeSet::new();
+    assert_eq!(sgs, bts);
+
+    for v in 0..DEFAULT_CAPACITY {
+        sgs.insert(v);
+        bts.insert(v);
+        assert_eq!(sgs, bts);
+        assert_eq!(bts, sgs);
+    }
+
+    bts.insert(DEFAULT_CAPACITY);
+    assert_ne!(sgs, bts);
+    assert_ne!(bts, sgs);
+}
+
+#[test]
+fn test_set_non_default_types() {
+    use std::num::NonZeroU32;
+
+    let mut sgs: SgSet<NonZeroU32, DEFAULT_CAPACITY> =
+        (1..=5).filter_map(NonZeroU32::new).collect();
+    assert!(sgs.remove(&NonZeroU32::new(3).unwrap()));
+    assert_eq!(sgs.first().map(|n| n.get()), Some(1));
+    assert!(sgs.into_iter().map(|n| n.get()).eq(vec![1, 2, 4, 5]));
+}
+
+// Compares by `id` only, so `tag` distinguishes equal-but-not-identical elements
+#[derive(Debug, Default, Clone, Copy)]
+struct TaggedElem {
+    id: usize,
+    tag: char,
+}
+
+impl PartialEq for TaggedElem {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TaggedElem {}
+
+impl PartialOrd for TaggedElem {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TaggedElem {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+#[test]
+fn test_set_insert_ref() {
+    let mut sgs = SgSet::<TaggedElem, DEFAULT_CAPACITY>::new();
+
+    // Absent, inserted
+    for id in 0..(DEFAULT_CAPACITY - 1) {
+        let stored = sgs.insert_ref(TaggedElem { id, tag: 'a' });
+        assert_eq!((stored.id, stored.tag), (id, 'a'));
+    }
+
+    // Present, existing element kept
+    for id in 0..(DEFAULT_CAPACITY - 1) {
+        let stored = sgs.insert_ref(TaggedElem { id, tag: 'b' });
+        assert_eq!((stored.id, stored.tag), (id, 'a'));
+    }
+    assert_eq!(sgs.len(), DEFAULT_CAPACITY - 1);
+    assert!(sgs.iter().all(|e| e.tag == 'a'));
+
+    // Fill the last slot
+    let last = DEFAULT_CAPACITY - 1;
+    let stored = sgs
+        .try_insert_ref(TaggedElem { id: last, tag: 'c' })
+        .unwrap();
+    assert_eq!((stored.id, stored.tag), (last, 'c'));
+    assert!(sgs.is_full());
+
+    // Full, but present elements are still found
+    let stored = sgs.try_insert_ref(TaggedElem { id: 0, tag: 'd' }).unwrap();
+    assert_eq!((stored.id, stored.tag), (0, 'a'));
+    assert_eq!(
+        sgs.try_insert_ref(TaggedElem {
+            id: last + 1,
+            tag: 'd'
+        }),
+        Err(SgError::StackCapacityExceeded)
+    );
+}
+
+fn hash_of<T: Hash>(val: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    val.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_set_hash_capacity_independent() {
+    let sg_set_small: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY).collect();
+
+    // Reverse insertion with removals, large capacity
+    let mut sg_set_big = SgSet::<usize, { DEFAULT_CAPACITY * 10 }>::new();
+    for elem in (0..(DEFAULT_CAPACITY * 5)).rev() {
+        sg_set_big.insert(elem);
+    }
+    sg_set_big.retain(|e| *e < DEFAULT_CAPACITY);
+
+    assert!(sg_set_small.iter().eq(sg_set_big.iter()));
+    assert_eq!(hash_of(&sg_set_small), hash_of(&sg_set_big));
+
+    // Matches std
+    let bt_set: BTreeSet<usize> = sg_set_small.iter().copied().collect();
+    assert_eq!(hash_of(&sg_set_small), hash_of(&bt_set));
+
+    // Different contents
+    sg_set_big.remove(&0);
+    assert_ne!(hash_of(&sg_set_small), hash_of(&sg_set_big));
+}
+
+#[test]
+fn test_set_split_at_key() {
+    let sg_set: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY).collect();
+
+    for split_val in 0..=DEFAULT_CAPACITY {
+        let (lo, hi) = sg_set.clone().split_at_key(&split_val);
+
+        assert_eq!(lo.len() + hi.len(), sg_set.len());
+        assert!(lo.iter().chain(hi.iter()).eq(sg_set.iter()));
+        assert!(lo.iter().all(|v| *v < split_val));
+        assert!(hi.iter().all(|v| *v >= split_val));
+    }
+}
+
+#[test]
+fn test_set_capacity_zero() {
+    let mut sg_set = SgSet::<usize, 0>::new();
+    assert_eq!(sg_set.capacity(), 0);
+    assert!(sg_set.is_empty());
+
+    assert_eq!(sg_set.try_insert(1), Err(SgError::CapacityZero));
+    assert_eq!(sg_set.try_insert_ref(1), Err(SgError::CapacityZero));
+    assert_eq!(
+        sg_set.try_extend(vec![1].into_iter()),
+        Err(SgError::CapacityZero)
+    );
+
+    assert_eq!(sg_set.get(&1), None);
+    assert!(!sg_set.contains(&1));
+    assert_eq!(sg_set.first(), None);
+    assert_eq!(sg_set.iter().count(), 0);
+    assert_eq!(sg_set.range(..).count(), 0);
+    assert!(sg_set.is_subset(&SgSet::new()));
+}
+
+#[test]
+fn test_set_iter_from_until() {
+    let mut rng = rand::thread_rng();
+    let mut sg_set = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    let mut bt_set = BTreeSet::new();
+
+    while sg_set.len() < DEFAULT_CAPACITY {
+        let v = rng.gen_range(0, 4 * DEFAULT_CAPACITY);
+        sg_set.insert(v);
+        bt_set.insert(v);
+    }
+
+    for bound in 0..=(4 * DEFAULT_CAPACITY) {
+        assert!(sg_set.iter_from(&bound).eq(bt_set.range(bound..)));
+        assert!(sg_set.iter_until(&bound).eq(bt_set.range(..bound)));
+        assert!(sg_set
+            .iter_until(&bound)
+            .rev()
+            .eq(bt_set.range(..bound).rev()));
+    }
+}
+
+#[test]
+fn test_set_assign_ops() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let a: Vec<usize> = (0..(DEFAULT_CAPACITY / 2))
+            .map(|_| rng.gen_range(0, DEFAULT_CAPACITY))
+            .collect();
+        let b: Vec<usize> = (0..(DEFAULT_CAPACITY / 2))
+            .map(|_| rng.gen_range(0, DEFAULT_CAPACITY))
+            .collect();
+
+        let sg_a = SgSet::<_, DEFAULT_CAPACITY>::from_iter(a.iter().copied());
+        let sg_b = SgSet::<_, DEFAULT_CAPACITY>::from_iter(b.iter().copied());
+        let bt_a = BTreeSet::from_iter(a.iter().copied());
+        let bt_b = BTreeSet::from_iter(b.iter().copied());
+
+        let mut sg_or = sg_a.clone();
+        sg_or |= &sg_b;
+        assert!(sg_or.iter().eq((&bt_a | &bt_b).iter()));
+
+        let mut sg_and = sg_a.clone();
+        sg_and &= &sg_b;
+        assert!(sg_and.iter().eq((&bt_a & &bt_b).iter()));
+
+        let mut sg_sub = sg_a.clone();
+        sg_sub -= &sg_b;
+        assert!(sg_sub.iter().eq((&bt_a - &bt_b).iter()));
+
+        let mut sg_xor = sg_a.clone();
+        sg_xor ^= &sg_b;
+        assert!(sg_xor.iter().eq((&bt_a ^ &bt_b).iter()));
+        assert_eq!(sg_xor, &sg_a ^ &sg_b);
+    }
+}
+
+#[test]
+fn test_set_xor_assign_at_capacity() {
+    // Union would overflow, symmetric difference fits: common elements are removed first.
+    let mut a = SgSet::<_, DEFAULT_CAPACITY>::from_iter(0..DEFAULT_CAPACITY);
+    let b = SgSet::<_, DEFAULT_CAPACITY>::from_iter(1..(DEFAULT_CAPACITY + 1));
+
+    a ^= &b;
+    assert!(a.into_iter().eq([0, DEFAULT_CAPACITY]));
+}
+
+#[test]
+fn test_set_range_count() {
+    let mut rng = rand::thread_rng();
+    let mut sg_set = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    let mut bt_set = BTreeSet::new();
+
+    while sg_set.len() < DEFAULT_CAPACITY {
+        let v = rng.gen_range(0, DEFAULT_CAPACITY * 3);
+        sg_set.insert(v);
+        bt_set.insert(v);
+    }
+
+    for lo in 0..(DEFAULT_CAPACITY * 3) {
+        for hi in lo..(DEFAULT_CAPACITY * 3) {
+            assert_eq!(sg_set.range_count(lo..hi), bt_set.range(lo..hi).count());
+            assert_eq!(sg_set.range_count(lo..=hi), bt_set.range(lo..=hi).count());
+        }
+    }
+}
+
+#[test]
+fn test_set_apply_diff() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let mut sg_replica = SgSet::<usize, DEFAULT_CAPACITY>::new();
+        let mut sg_target = SgSet::<usize, DEFAULT_CAPACITY>::new();
+        for _ in 0..DEFAULT_CAPACITY {
+            sg_replica.insert(rng.gen_range(0, DEFAULT_CAPACITY * 2));
+            sg_target.insert(rng.gen_range(0, DEFAULT_CAPACITY * 2));
+        }
+
+        let removed_cnt = sg_replica.difference(&sg_target).count();
+        let added_cnt = sg_target.difference(&sg_replica).count();
+
+        assert_eq!(sg_replica.apply_diff(&sg_target), (removed_cnt, added_cnt));
+        assert_eq!(sg_replica, sg_target);
+
+        // Already equal
+        assert_eq!(sg_replica.apply_diff(&sg_target), (0, 0));
+    }
+
+    // Full to full, disjoint
+    let mut sg_replica: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY).collect();
+    let sg_target: SgSet<usize, DEFAULT_CAPACITY> =
+        (DEFAULT_CAPACITY..(DEFAULT_CAPACITY * 2)).collect();
+    assert_eq!(
+        sg_replica.apply_diff(&sg_target),
+        (DEFAULT_CAPACITY, DEFAULT_CAPACITY)
+    );
+    assert_eq!(sg_replica, sg_target);
+
+    // To empty
+    assert_eq!(sg_replica.apply_diff(&SgSet::new()), (DEFAULT_CAPACITY, 0));
+    assert!(sg_replica.is_empty());
+}
+
+#[test]
+fn test_set_iter_clone() {
+    let set_a: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY).step_by(2).collect();
+    let set_b: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY).step_by(3).collect();
+
+    let mut iter = set_a.iter();
+    iter.next();
+    assert!(iter.clone().eq(iter));
+
+    let mut range = set_a.range(1..7);
+    range.next();
+    assert!(range.clone().eq(range));
+
+    let mut intersection = set_a.intersection(&set_b);
+    intersection.next();
+    assert_eq!(intersection.clone().count(), intersection.count());
+
+    let mut difference = set_a.difference(&set_b);
+    difference.next();
+    assert!(difference.clone().eq(difference));
+
+    let mut sym_diff = set_a.symmetric_difference(&set_b);
+    sym_diff.next();
+    assert!(sym_diff.clone().eq(sym_diff));
+
+    let mut union = set_a.union(&set_b);
+    union.next();
+    assert!(union.clone().eq(union));
+}
+
+#[test]
+fn test_set_extend_from_ref_iter() {
+    let mut sg_set = SgSet::<_, DEFAULT_CAPACITY>::new();
+    sg_set.insert(1);
+    sg_set.insert(2);
+
+    let other: SgSet<_, DEFAULT_CAPACITY> = vec![2, 3].into_iter().collect();
+
+    sg_set.extend(other.iter());
+    assert!(sg_set.into_iter().eq(vec![1, 2, 3]));
+    assert_eq!(other.len(), 2);
+}
+
+#[test]
+fn test_set_all_any_in_range() {
+    let mut rng = rand::thread_rng();
+    let sg_set: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY)
+        .map(|_| rng.gen_range(0, DEFAULT_CAPACITY * 2))
+        .collect();
+
+    // Every pair of bounds, including inverted and empty ranges
+    for start in 0..(DEFAULT_CAPACITY * 2 + 1) {
+        for end in 0..(DEFAULT_CAPACITY * 2 + 1) {
+            for range in &[
+                (Included(start), Included(end)),
+                (Included(start), Excluded(end)),
+                (Excluded(start), Included(end)),
+                (Excluded(start), Excluded(end)),
+            ] {
+                assert_eq!(
+                    sg_set.all_in_range(*range),
+                    sg_set.iter().all(|v| range.contains(v))
+                );
+                assert_eq!(
+                    sg_set.any_in_range(*range),
+                    sg_set.iter().any(|v| range.contains(v))
+                );
+            }
+        }
+
+        assert_eq!(
+            sg_set.any_in_range(start..),
+            sg_set.range(start..).next().is_some()
+        );
+        assert_eq!(
+            sg_set.all_in_range(..start),
+            sg_set.iter().all(|v| *v < start)
+        );
+    }
+
+    // Empty set
+    let empty = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    assert!(empty.all_in_range((Included(1), Excluded(0))));
+    assert!(!empty.any_in_range(..));
+}
+
+#[test]
+fn test_set_partition() {
+    let mut rng = rand::thread_rng();
+    let mut sg_set = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    while sg_set.len() < DEFAULT_CAPACITY {
+        sg_set.insert(rng.gen_range(0, DEFAULT_CAPACITY * 4));
+    }
+    let expected = sg_set.clone();
+
+    let (lo, hi) = sg_set.partition(|x| *x < DEFAULT_CAPACITY * 2);
+
+    assert_eq!(lo.len() + hi.len(), DEFAULT_CAPACITY);
+    assert!(lo.iter().eq(expected.range(..DEFAULT_CAPACITY * 2)));
+    assert!(hi.iter().eq(expected.range(DEFAULT_CAPACITY * 2..)));
+    assert_eq!(lo.verify(), Ok(()));
+    assert_eq!(hi.verify(), Ok(()));
+}
+
+#[test]
+fn test_set_runs() {
+    let consecutive = |a: &usize, b: &usize| a + 1 == *b;
+
+    let empty = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.runs(consecutive).next(), None);
+
+    let sg_set: SgSet<usize, DEFAULT_CAPACITY> = vec![0, 1, 2, 4, 6, 7].into_iter().collect();
+    let mut runs = sg_set.runs(consecutive);
+    assert_eq!(runs.next(), Some((&0, &2)));
+    assert_eq!(runs.next(), Some((&4, &4)));
+    assert_eq!(runs.next(), Some((&6, &7)));
+    assert_eq!(runs.next(), None);
+    assert_eq!(runs.next(), None);
+
+    // Adjacency within a tolerance, every element covered exactly once
+    let mut rng = rand::thread_rng();
+    let sg_set: SgSet<usize, DEFAULT_CAPACITY> = (0..DEFAULT_CAPACITY)
+        .map(|_| rng.gen_range(0, DEFAULT_CAPACITY * 3))
+        .collect();
+    let mut covered = 0;
+    let mut prev_last: Option<usize> = None;
+    for (first, last) in sg_set.runs(|a, b| b - a <= 2) {
+        assert!(first <= last);
+        if let Some(prev_last) = prev_last {
+            assert!(first - prev_last > 2);
+        }
+        assert!(sg_set
+            .range(first..=last)
+            .zip(sg_set.range(first..=last).skip(1))
+            .all(|(a, b)| b - a <= 2));
+        covered += sg_set.range(first..=last).count();
+        prev_last = Some(*last);
+    }
+    assert_eq!(covered, sg_set.len());
+}
+
+#[test]
+fn test_set_drain() {
+    let mut sgs = SgSet::<usize, DEFAULT_CAPACITY>::from_iter(vec![4, 2, 3, 1, 5]);
+    assert!(sgs.drain().eq(1..=5));
+    assert!(sgs.is_empty());
+
+    sgs.extend(vec![9, 7, 8]);
+    {
+        let mut drain = sgs.drain();
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(7));
+    }
+    assert!(sgs.is_empty());
+    assert_eq!(sgs.first(), None);
+
+    sgs.insert(6);
+    assert!(sgs.iter().eq([6].iter()));
+}
+
+#[test]
+fn test_set_take_matching() {
+    const CAPACITY: usize = 200;
+    let mut sgs = SgSet::<usize, CAPACITY>::new();
+    let mut bts = BTreeSet::new();
+    let mut rng = rand::thread_rng();
+
+    while !sgs.is_full() {
+        let v = rng.gen_range(0, 1_000);
+        sgs.insert(v);
+        bts.insert(v);
+    }
+
+    // Partial consumption, remaining elements kept
+    let sg_taken: Vec<usize> = sgs.take_matching(|v| v % 3 == 0).take(5).collect();
+    let bt_taken: Vec<usize> = bts.iter().copied().filter(|v| v % 3 == 0).take(5).collect();
+    assert_eq!(sg_taken, bt_taken);
+    for v in &bt_taken {
+        bts.remove(v);
+    }
+    assert!(sgs.iter().eq(bts.iter()));
+    assert_eq!(sgs.verify(), Ok(()));
+
+    // Extremes removed, min/max recomputed
+    let (min, max) = (*bts.first().unwrap(), *bts.last().unwrap());
+    assert!(sgs
+        .take_matching(|v| (*v == min) || (*v == max))
+        .eq([min, max]));
+    bts.remove(&min);
+    bts.remove(&max);
+    assert_eq!(sgs.first(), bts.first());
+    assert_eq!(sgs.last(), bts.last());
+    assert_eq!(sgs.verify(), Ok(()));
+
+    // Full consumption
+    let sg_taken: Vec<usize> = sgs.take_matching(|v| v % 2 == 0).collect();
+    let bt_taken: Vec<usize> = bts.iter().copied().filter(|v| v % 2 == 0).collect();
+    assert_eq!(sg_taken, bt_taken);
+    bts.retain(|v| v % 2 != 0);
+    assert!(sgs.iter().eq(bts.iter()));
+    assert_eq!(sgs.first(), bts.first());
+    assert_eq!(sgs.last(), bts.last());
+    assert_eq!(sgs.verify(), Ok(()));
+
+    // Dropped without consumption, nothing removed
+    let len_old = sgs.len();
+    drop(sgs.take_matching(|_| true));
+    assert_eq!(sgs.len(), len_old);
+}
+
 #[should_panic]
 #[test]
 fn test_btree_set_range_panic_1() {
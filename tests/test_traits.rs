@@ -1,8 +1,18 @@
+use std::iter::FusedIterator;
+
 use scapegoat::{SgMap, SgSet};
 
 fn is_auto_trait_friendly<T: Sized + Send + Sync + Unpin>() {}
 fn is_default<T: Default>() {}
 
+// Exhaust, then check `next()` keeps returning `None`
+fn assert_fused<I: FusedIterator>(mut iter: I) {
+    for _ in iter.by_ref() {}
+    for _ in 0..3 {
+        assert!(iter.next().is_none());
+    }
+}
+
 #[test]
 fn test_auto_traits_map() {
     is_auto_trait_friendly::<SgMap<usize, usize, 10>>();
@@ -22,3 +32,38 @@ fn test_default_map() {
 fn test_default_set() {
     is_default::<SgSet<usize, 10>>();
 }
+
+#[test]
+fn test_fused_map() {
+    let mut map: SgMap<usize, usize, 10> = (0..5).map(|x| (x, x)).collect();
+
+    assert_fused(map.iter());
+    assert_fused(map.iter_mut());
+    assert_fused(map.keys());
+    assert_fused(map.values());
+    assert_fused(map.values_mut());
+    assert_fused(map.range(1..4));
+    assert_fused(map.range_mut(1..4));
+    assert_fused(map.range_values_mut(1..4));
+    assert_fused(map.clone().into_iter());
+    assert_fused(map.clone().into_keys());
+    assert_fused(map.clone().into_values());
+    assert_fused(map.drain());
+}
+
+#[test]
+fn test_fused_set() {
+    let mut set: SgSet<usize, 10> = (0..5).collect();
+    let other: SgSet<usize, 10> = (3..8).collect();
+
+    assert_fused(set.iter());
+    assert_fused(set.range(1..4));
+    assert_fused(set.runs(|a, b| a + 1 == *b));
+    assert_fused(set.intersection(&other));
+    assert_fused(set.difference(&other));
+    assert_fused(set.symmetric_difference(&other));
+    assert_fused(set.union(&other));
+    assert_fused(set.clone().into_iter());
+    assert_fused(set.take_matching(|x| x % 2 == 0));
+    assert_fused(set.drain());
+}